@@ -2,6 +2,9 @@ extern crate poseidon;
 use poseidon::system::application::Application;
 
 fn main() {
-    let mut app = Application::new();
-    app.execute();
+    let result = Application::new().and_then(|mut app| app.execute());
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
 }
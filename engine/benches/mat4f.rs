@@ -0,0 +1,52 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use poseidon::math::mat4f::Mat4f;
+use poseidon::math::quatf::Quatf;
+use poseidon::math::vec3::Vec3f;
+use poseidon::math::vec4::Vec4f;
+
+fn model(i: usize) -> Mat4f {
+    let i = i as f32;
+    Mat4f::transformation(
+        Vec3f::new(i, -i * 0.5, i * 2.0),
+        Quatf::from_yaw_pitch_roll(i * 0.1, i * 0.2, i * 0.3),
+        Vec3f::new(1.0, 2.0, 0.5))
+}
+
+fn view_projection() -> Mat4f {
+    Mat4f::persp_fov(f32::to_radians(90.0), 16.0 / 9.0, 0.1, 100.0) *
+    Mat4f::look_at(Vec3f::new(0.0, 5.0, -10.0), Vec3f::zero(), Vec3f::up())
+}
+
+fn bench_mat4f(c: &mut Criterion) {
+    let a = view_projection();
+    let b = model(3);
+    let v = Vec4f::new(1.0, 2.0, 3.0, 1.0);
+
+    c.bench_function("mat4f * mat4f", |bench| {
+        bench.iter(|| black_box(a) * black_box(b))
+    });
+
+    c.bench_function("mat4f * vec4f", |bench| {
+        bench.iter(|| black_box(a) * black_box(v))
+    });
+
+    c.bench_function("mat4f inverse", |bench| {
+        bench.iter(|| black_box(b).inverse())
+    });
+
+    let models: Vec<Mat4f> = (0..1000).map(model).collect();
+    let mut out = vec![Mat4f::identity(); models.len()];
+    c.bench_function("mat4f mul_batch 1000", |bench| {
+        bench.iter(|| a.mul_batch(black_box(&models), &mut out))
+    });
+
+    let vectors: Vec<Vec4f> = (0..1000).map(|i| Vec4f::new(i as f32, 1.0, -1.0, 1.0)).collect();
+    let mut out = vec![Vec4f::zero(); vectors.len()];
+    c.bench_function("mat4f transform_batch 1000", |bench| {
+        bench.iter(|| a.transform_batch(black_box(&vectors), &mut out))
+    });
+}
+
+criterion_group!(benches, bench_mat4f);
+criterion_main!(benches);
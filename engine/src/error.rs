@@ -0,0 +1,65 @@
+use std::fmt;
+
+use crate::graphics::shader::ShaderStage;
+
+/// Errors from creating engine resources
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// An image file could not be loaded
+    Image { path: String, message: String },
+    /// A shader stage failed to compile
+    ShaderCompile {
+        stage: ShaderStage,
+        /// The driver's info log
+        log: String,
+        /// The source lines the log reports errors on, ascending
+        lines: Vec<u32>
+    },
+    /// A shader program failed to link
+    ShaderLink { log: String },
+    /// The window system or OpenGL context could not be initialized
+    Context(String),
+    /// An image does not fit in a texture atlas of the maximum size
+    AtlasFull { name: String, max_size: u32 },
+    /// An image's data length does not match its size and format
+    ImageData { name: String, expected: usize, actual: usize },
+    /// A framebuffer's attachments are invalid or incomplete
+    Framebuffer(String),
+    /// A texture region is outside of its texture or has the wrong amount of data
+    TextureRegion(String),
+    /// A sprite sheet description is malformed or unsupported
+    SpriteSheet(String),
+    /// A font file is malformed or unsupported
+    Font(String)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Image { path, message } => write!(f, "Failed to load image '{}': {}", path, message),
+            Error::ShaderCompile { stage, log, lines } => {
+                write!(f, "{} shader failed to compile", stage)?;
+                if !lines.is_empty() {
+                    let lines: Vec<String> = lines.iter().map(u32::to_string).collect();
+                    write!(f, " on line {}", lines.join(", "))?;
+                }
+                write!(f, ": {}", log.trim_end())
+            },
+            Error::ShaderLink { log } => write!(f, "Shader program failed to link: {}", log.trim_end()),
+            Error::Context(message) => write!(f, "{}", message),
+            Error::AtlasFull { name, max_size } =>
+                write!(f, "Image '{}' does not fit in a {}x{} texture atlas", name, max_size, max_size),
+            Error::ImageData { name, expected, actual } =>
+                write!(f, "Image '{}' has {} bytes of data, its size and format need {}", name, actual, expected),
+            Error::Framebuffer(message) => write!(f, "Invalid framebuffer: {}", message),
+            Error::TextureRegion(message) => write!(f, "Invalid texture region: {}", message),
+            Error::SpriteSheet(message) => write!(f, "Invalid sprite sheet: {}", message),
+            Error::Font(message) => write!(f, "Invalid font: {}", message)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A `Result` with the engine's `Error`
+pub type Result<T> = std::result::Result<T, Error>;
\ No newline at end of file
@@ -1,5 +1,7 @@
 use std::ffi::c_void;
 
+use super::device::{self, BufferHandle, BufferKind, BufferUsage};
+
 /// Types of attributes in array buffers
 #[derive(Clone, Copy)]
 pub enum AttributeType {
@@ -28,17 +30,6 @@ impl AttributeType {
             AttributeType::Vec4f => 4
         }
     }
-
-    // Get the type as an opengl type
-    pub const fn opengl_type(&self) -> u32 {
-        match *self {
-            AttributeType::Int => gl::INT,
-            AttributeType::Float |
-            AttributeType::Vec2f |
-            AttributeType::Vec3f |
-            AttributeType::Vec4f => gl::FLOAT
-        }
-    }
 }
 
 /// Defines an attribute of a buffer
@@ -113,7 +104,7 @@ impl BufferLayout {
 
 /// A buffer of graphics data
 pub struct ArrayBuffer {
-    id: u32,
+    handle: BufferHandle,
     layout: BufferLayout
 }
 
@@ -126,18 +117,13 @@ impl ArrayBuffer {
     /// * `data` - A c style void pointer to the data
     /// * `size` - The size of the data 
     pub fn new_static(layout: BufferLayout, data: *const c_void, size: usize) -> Self {
-        let mut id = 0;
-        unsafe {
-            gl::GenBuffers(1, &mut id);
-            gl::BindBuffer(gl::ARRAY_BUFFER, id);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                size as isize,
-                data,
-                gl::STATIC_DRAW
-            );
-        }
-        ArrayBuffer { id, layout }
+        let data = unsafe { std::slice::from_raw_parts(data.cast::<u8>(), size) };
+        let handle = device::with_device(|device| {
+            let handle = device.create_buffer(BufferKind::Vertex);
+            device.set_buffer_data(handle, BufferUsage::Static, size, Some(data));
+            handle
+        });
+        ArrayBuffer { handle, layout }
     }
 
     /// Creates a new `ArrayBuffer`
@@ -147,18 +133,12 @@ impl ArrayBuffer {
     /// * `layout` - The layout of the buffer
     /// * `size` - The size of the buffer
     pub fn new_dynamic(layout: BufferLayout, size: usize) -> Self {
-        let mut id = 0;
-        unsafe {
-            gl::GenBuffers(1, &mut id);
-            gl::BindBuffer(gl::ARRAY_BUFFER, id);
-            gl::BufferData(
-                gl::ARRAY_BUFFER,
-                size as isize,
-                0 as *const _,
-                gl::DYNAMIC_DRAW
-            );
-        }
-        ArrayBuffer { id, layout }
+        let handle = device::with_device(|device| {
+            let handle = device.create_buffer(BufferKind::Vertex);
+            device.set_buffer_data(handle, BufferUsage::Dynamic, size, None);
+            handle
+        });
+        ArrayBuffer { handle, layout }
     }
 
     /// Get the buffer's layout
@@ -166,18 +146,9 @@ impl ArrayBuffer {
         &self.layout
     }
 
-    /// Make this buffer the active `ArrayBuffer`
-    pub fn bind(&self) {
-        unsafe {
-            gl::BindBuffer(gl::ARRAY_BUFFER, self.id);
-        }
-    }
-
-    /// Unbind the current `ArrayBuffer`
-    pub fn unbind() {
-        unsafe {
-            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
-        }
+    /// Get the buffer's device handle
+    pub const fn handle(&self) -> BufferHandle {
+        self.handle
     }
 
     /// Set `ArrayBuffer`'s data
@@ -187,22 +158,13 @@ impl ArrayBuffer {
     /// * `data` - A c style void pointer to the data
     /// * `size` - The size of the data
     pub fn set_data(&self, data: *const c_void, size: usize) {
-        self.bind();
-        unsafe {
-            gl::BufferSubData(
-                gl::ARRAY_BUFFER,
-                0,
-                size as isize,
-                data
-            );
-        }
+        let data = unsafe { std::slice::from_raw_parts(data.cast::<u8>(), size) };
+        device::with_device(|device| device.update_buffer(self.handle, 0, data));
     }
 }
 
 impl Drop for ArrayBuffer {
     fn drop(&mut self) {
-        unsafe {
-            gl::DeleteBuffers(1, &self.id)
-        }
+        device::with_device(|device| device.destroy_buffer(self.handle));
     }
 }
\ No newline at end of file
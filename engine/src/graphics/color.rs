@@ -0,0 +1,404 @@
+use core::fmt;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use crate::math::vec3::Vec3f;
+use crate::math::vec4::Vec4f;
+
+/// An RGBA color with f32 components, usually in the range [0, 1]
+///
+/// Colors are stored in sRGB space unless converted with `to_linear`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Color = Color::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Color = Color::rgb(1.0, 1.0, 1.0);
+    pub const GRAY: Color = Color::rgb(0.5, 0.5, 0.5);
+    pub const RED: Color = Color::rgb(1.0, 0.0, 0.0);
+    pub const GREEN: Color = Color::rgb(0.0, 1.0, 0.0);
+    pub const BLUE: Color = Color::rgb(0.0, 0.0, 1.0);
+    pub const YELLOW: Color = Color::rgb(1.0, 1.0, 0.0);
+    pub const CYAN: Color = Color::rgb(0.0, 1.0, 1.0);
+    pub const MAGENTA: Color = Color::rgb(1.0, 0.0, 1.0);
+    pub const ORANGE: Color = Color::rgb(1.0, 0.647_058_84, 0.0);
+    pub const PURPLE: Color = Color::rgb(0.501_960_8, 0.0, 0.501_960_8);
+    pub const CORNFLOWER_BLUE: Color = Color::rgb(0.392_156_87, 0.584_313_75, 0.929_411_77);
+
+    /// Creates a new `Color`
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The red component
+    /// * `g` - The green component
+    /// * `b` - The blue component
+    /// * `a` - The alpha component
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+
+    /// Creates an opaque color
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The red component
+    /// * `g` - The green component
+    /// * `b` - The blue component
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Color { r, g, b, a: 1.0 }
+    }
+
+    /// Creates a color from 8-bit components
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color {
+            r: r as f32 / 255.0,
+            g: g as f32 / 255.0,
+            b: b as f32 / 255.0,
+            a: a as f32 / 255.0
+        }
+    }
+
+    /// Get the color as 8-bit components, clamping to [0, 1]
+    pub fn to_rgba8(self) -> [u8; 4] {
+        let convert = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [convert(self.r), convert(self.g), convert(self.b), convert(self.a)]
+    }
+
+    /// Parse a hex color
+    ///
+    /// Accepts `RGB`, `RGBA`, `RRGGBB` and `RRGGBBAA` with an optional
+    /// leading `#`. Returns `None` if the string is not a valid color
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - The string to parse, e.g. `#FF8000FF`
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|v| v * 17);
+        let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        match hex.len() {
+            3 => Some(Color::from_rgba8(digit(0)?, digit(1)?, digit(2)?, 255)),
+            4 => Some(Color::from_rgba8(digit(0)?, digit(1)?, digit(2)?, digit(3)?)),
+            6 => Some(Color::from_rgba8(byte(0)?, byte(2)?, byte(4)?, 255)),
+            8 => Some(Color::from_rgba8(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+            _ => None
+        }
+    }
+
+    /// Get the color as a hex string: `#RRGGBBAA`
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.to_rgba8();
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    }
+
+    /// Creates a color from hue, saturation and value
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The hue in degrees, wrapped to [0, 360)
+    /// * `saturation` - The saturation in [0, 1]
+    /// * `value` - The value in [0, 1]
+    /// * `alpha` - The alpha component
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        let chroma = value * saturation;
+        Self::from_hue_chroma(hue, chroma, value - chroma, alpha)
+    }
+
+    /// Get the hue (degrees), saturation and value of the color
+    pub fn to_hsv(self) -> Vec3f {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        Vec3f::new(self.hue(max, chroma), saturation, max)
+    }
+
+    /// Creates a color from hue, saturation and lightness
+    ///
+    /// # Arguments
+    ///
+    /// * `hue` - The hue in degrees, wrapped to [0, 360)
+    /// * `saturation` - The saturation in [0, 1]
+    /// * `lightness` - The lightness in [0, 1]
+    /// * `alpha` - The alpha component
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Self::from_hue_chroma(hue, chroma, lightness - chroma * 0.5, alpha)
+    }
+
+    /// Get the hue (degrees), saturation and lightness of the color
+    pub fn to_hsl(self) -> Vec3f {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let lightness = (max + min) * 0.5;
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        Vec3f::new(self.hue(max, chroma), saturation, lightness)
+    }
+
+    fn from_hue_chroma(hue: f32, chroma: f32, offset: f32, alpha: f32) -> Self {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x)
+        };
+        Color::new(r + offset, g + offset, b + offset, alpha)
+    }
+
+    fn hue(self, max: f32, chroma: f32) -> f32 {
+        if chroma == 0.0 {
+            return 0.0;
+        }
+        let sector = if max == self.r {
+            ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / chroma + 2.0
+        } else {
+            (self.r - self.g) / chroma + 4.0
+        };
+        sector * 60.0
+    }
+
+    /// Convert from sRGB to linear space, alpha is unchanged
+    pub fn to_linear(self) -> Self {
+        let convert = |v: f32| {
+            if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) }
+        };
+        Color::new(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Convert from linear to sRGB space, alpha is unchanged
+    pub fn to_srgb(self) -> Self {
+        let convert = |v: f32| {
+            if v <= 0.0031308 { v * 12.92 } else { 1.055 * v.powf(1.0 / 2.4) - 0.055 }
+        };
+        Color::new(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Get this color with a different alpha
+    pub fn with_alpha(self, a: f32) -> Self {
+        Color { a, ..self }
+    }
+
+    /// Get this color with the color components multiplied by alpha
+    pub fn premultiplied(self) -> Self {
+        Color::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Get this color with premultiplied alpha removed
+    pub fn unpremultiplied(self) -> Self {
+        if self.a == 0.0 {
+            return Color::TRANSPARENT;
+        }
+        Color::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+    /// Composite a color over a background (source-over blending)
+    ///
+    /// Both colors use straight (not premultiplied) alpha
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The color on top
+    /// * `background` - The color underneath
+    pub fn blend(source: Self, background: Self) -> Self {
+        let source = source.premultiplied();
+        let background = background.premultiplied();
+        (source + background * (1.0 - source.a)).unpremultiplied()
+    }
+
+    /// Linearly interpolate between two colors
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The color at `t = 0`
+    /// * `to` - The color at `t = 1`
+    /// * `t` - The interpolation factor
+    pub fn lerp(from: Self, to: Self, t: f32) -> Self {
+        from + (to - from) * t
+    }
+
+    /// Get this color with each component clamped to [0, 1]
+    pub fn clamped(self) -> Self {
+        Color::new(
+            self.r.clamp(0.0, 1.0),
+            self.g.clamp(0.0, 1.0),
+            self.b.clamp(0.0, 1.0),
+            self.a.clamp(0.0, 1.0))
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::WHITE
+    }
+}
+
+impl From<Color> for Vec4f {
+    fn from(color: Color) -> Self {
+        Vec4f::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Vec4f> for Color {
+    fn from(v: Vec4f) -> Self {
+        Color::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from([r, g, b, a]: [f32; 4]) -> Self {
+        Color::new(r, g, b, a)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+impl_op_ex!(+ |a: &Color, b: &Color| -> Color {
+    Color::new(a.r + b.r, a.g + b.g, a.b + b.b, a.a + b.a)
+});
+
+impl_op_ex!(- |a: &Color, b: &Color| -> Color {
+    Color::new(a.r - b.r, a.g - b.g, a.b - b.b, a.a - b.a)
+});
+
+impl_op_ex!(* |a: &Color, b: &Color| -> Color {
+    Color::new(a.r * b.r, a.g * b.g, a.b * b.b, a.a * b.a)
+});
+
+impl_op_ex_commutative!(* |a: &Color, b: &f32| -> Color {
+    Color::new(a.r * b, a.g * b, a.b * b, a.a * b)
+});
+
+/// Serialized as `[r, g, b, a]`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.r, self.g, self.b, self.a].serialize(serializer)
+    }
+}
+
+/// Deserialized from `[r, g, b, a]`, or in human-readable formats
+/// also from a hex string such as `"#FF8000FF"`
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an [r, g, b, a] array or a hex color string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Color, E> {
+                Color::from_hex(value)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+                let mut components = [0.0; 4];
+                for (i, component) in components.iter_mut().enumerate() {
+                    *component = seq.next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(5, &self));
+                }
+                Ok(components.into())
+            }
+        }
+
+        // Only self-describing formats can tell a string from an array
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            deserializer.deserialize_tuple(4, ColorVisitor)
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: Color, b: Color) -> bool {
+        let d = a - b;
+        d.r.abs() < 1e-4 && d.g.abs() < 1e-4 && d.b.abs() < 1e-4 && d.a.abs() < 1e-4
+    }
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(Color::from_hex("#FF0000FF"), Some(Color::RED));
+        assert_eq!(Color::from_hex("00ffff"), Some(Color::CYAN));
+        assert_eq!(Color::from_hex("#fff"), Some(Color::WHITE));
+        assert_eq!(Color::from_hex("#0000"), Some(Color::TRANSPARENT));
+        assert_eq!(Color::from_hex("#6495EDFF"), Some(Color::CORNFLOWER_BLUE));
+        assert_eq!(Color::from_hex("#12345"), None);
+        assert_eq!(Color::from_hex("#GG0000"), None);
+        assert_eq!(Color::from_hex("#+1+1+1"), None);
+        assert_eq!(Color::ORANGE.to_hex(), "#FFA500FF");
+    }
+
+    #[test]
+    fn hsv_and_hsl_round_trip() {
+        let colors = [Color::RED, Color::CYAN, Color::ORANGE, Color::PURPLE, Color::CORNFLOWER_BLUE, Color::GRAY];
+        for color in colors {
+            let hsv = color.to_hsv();
+            assert!(approx_eq(Color::from_hsv(hsv.x, hsv.y, hsv.z, 1.0), color), "{}", color);
+            let hsl = color.to_hsl();
+            assert!(approx_eq(Color::from_hsl(hsl.x, hsl.y, hsl.z, 1.0), color), "{}", color);
+        }
+        assert_eq!(Color::from_hsv(120.0, 1.0, 1.0, 1.0), Color::GREEN);
+        assert_eq!(Color::from_hsl(240.0, 1.0, 0.5, 1.0), Color::BLUE);
+    }
+
+    #[test]
+    fn srgb_round_trip() {
+        let color = Color::new(0.2, 0.5, 0.8, 0.5);
+        assert!(approx_eq(color.to_linear().to_srgb(), color));
+        assert!((Color::GRAY.to_linear().r - 0.214_041).abs() < 1e-4);
+        assert_eq!(Color::WHITE.to_linear(), Color::WHITE);
+    }
+
+    #[test]
+    fn blends_over_background() {
+        assert_eq!(Color::blend(Color::RED, Color::BLUE), Color::RED);
+        assert_eq!(Color::blend(Color::TRANSPARENT, Color::BLUE), Color::BLUE);
+        let half = Color::blend(Color::RED.with_alpha(0.5), Color::BLUE);
+        assert!(approx_eq(half, Color::new(0.5, 0.0, 0.5, 1.0)));
+        assert_eq!(Color::RED.with_alpha(0.5).premultiplied(), Color::new(0.5, 0.0, 0.0, 0.5));
+        assert_eq!(Vec4f::from(Color::CYAN), Vec4f::new(0.0, 1.0, 1.0, 1.0));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,379 @@
+pub mod opengl;
+pub mod recording;
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+
+use crate::Result;
+use crate::math::{vec2::Vec2f, vec3::Vec3f, vec4::Vec4f, mat2f::Mat2f, mat3f::Mat3f, mat4f::Mat4f};
+use crate::math::geometry::frustum::Frustum;
+
+use super::array_buffer::BufferLayout;
+use super::texture::TextureDescriptor;
+use super::pipeline::{PipelineState, BlendMode, DepthState, StencilState, CullMode, FrontFace, ScissorRect, ColorMask};
+
+use self::opengl::GlDevice;
+
+/// Handle to a buffer owned by a `GraphicsDevice`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BufferHandle(pub(crate) u32);
+
+/// Handle to a vertex array owned by a `GraphicsDevice`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VertexArrayHandle(pub(crate) u32);
+
+/// Handle to a shader program owned by a `GraphicsDevice`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ShaderHandle(pub(crate) u32);
+
+/// Handle to a texture owned by a `GraphicsDevice`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct TextureHandle(pub(crate) u32);
+
+/// Handle to a renderbuffer owned by a `GraphicsDevice`.\
+/// Renderbuffers are render targets that can't be sampled, used for multisampling
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RenderbufferHandle(pub(crate) u32);
+
+/// Handle to a framebuffer owned by a `GraphicsDevice`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FramebufferHandle(pub(crate) u32);
+
+impl BufferHandle {
+    /// Get the backend id of the buffer
+    pub const fn id(self) -> u32 { self.0 }
+}
+
+impl VertexArrayHandle {
+    /// Get the backend id of the vertex array
+    pub const fn id(self) -> u32 { self.0 }
+}
+
+impl ShaderHandle {
+    /// Get the backend id of the shader
+    pub const fn id(self) -> u32 { self.0 }
+}
+
+impl TextureHandle {
+    /// Get the backend id of the texture
+    pub const fn id(self) -> u32 { self.0 }
+}
+
+impl RenderbufferHandle {
+    /// Get the backend id of the renderbuffer
+    pub const fn id(self) -> u32 { self.0 }
+}
+
+impl FramebufferHandle {
+    /// Get the backend id of the framebuffer
+    pub const fn id(self) -> u32 { self.0 }
+}
+
+/// What a buffer holds
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BufferKind {
+    /// Vertex data
+    Vertex,
+    /// `u32` indices into vertex data
+    Index
+}
+
+/// How often a buffer is expected to change
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BufferUsage {
+    /// Written once, drawn many times
+    Static,
+    /// Rewritten often, e.g. every frame
+    Dynamic
+}
+
+/// The pixel format of a texture or renderbuffer
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextureFormat {
+    /// 8 bit red
+    R8,
+    /// 8 bit red, green
+    Rg8,
+    /// 8 bit red, green, blue, alpha
+    Rgba8,
+    /// 8 bit sRGB encoded red, green, blue and linear alpha.\
+    /// Converted to linear when sampled
+    Srgba8,
+    /// 16 bit float red, green, blue, alpha
+    Rgba16f,
+    /// 32 bit float red, green, blue, alpha
+    Rgba32f,
+    /// 24 bit depth and 8 bit stencil
+    Depth24Stencil8,
+    /// 32 bit float depth
+    Depth32f
+}
+
+impl TextureFormat {
+    /// Get the size of a pixel (in bytes) when uploading data
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            TextureFormat::R8 => 1,
+            TextureFormat::Rg8 => 2,
+            TextureFormat::Rgba8 |
+            TextureFormat::Srgba8 |
+            TextureFormat::Depth24Stencil8 |
+            TextureFormat::Depth32f => 4,
+            TextureFormat::Rgba16f => 8,
+            TextureFormat::Rgba32f => 16
+        }
+    }
+
+    /// Get whether the format holds depth
+    pub const fn is_depth(self) -> bool {
+        matches!(self, TextureFormat::Depth24Stencil8 | TextureFormat::Depth32f)
+    }
+
+    /// Get whether the format holds stencil
+    pub const fn has_stencil(self) -> bool {
+        matches!(self, TextureFormat::Depth24Stencil8)
+    }
+}
+
+/// The image a framebuffer attachment renders to
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AttachmentTarget {
+    Texture(TextureHandle),
+    Renderbuffer(RenderbufferHandle)
+}
+
+/// An image attached to a framebuffer
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Attachment {
+    pub target: AttachmentTarget,
+    pub format: TextureFormat
+}
+
+/// Which buffers of a framebuffer to clear
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ClearMask {
+    pub color: bool,
+    pub depth: bool,
+    pub stencil: bool
+}
+
+impl ClearMask {
+    pub const ALL: ClearMask = ClearMask { color: true, depth: true, stencil: true };
+    pub const COLOR: ClearMask = ClearMask { color: true, depth: false, stencil: false };
+    pub const DEPTH: ClearMask = ClearMask { color: false, depth: true, stencil: false };
+    pub const STENCIL: ClearMask = ClearMask { color: false, depth: false, stencil: true };
+}
+
+/// A value to upload to a shader uniform
+#[derive(Clone, PartialEq, Debug)]
+pub enum UniformValue {
+    Int(i32),
+    IntArray(Vec<i32>),
+    Float(f32),
+    Vec2f(Vec2f),
+    Vec3f(Vec3f),
+    Vec4f(Vec4f),
+    Mat2f(Mat2f),
+    Mat3f(Mat3f),
+    Mat4f(Mat4f)
+}
+
+/// A graphics API backend.\
+/// Owns GPU resources and hands out handles to them
+///
+/// Resources are destroyed explicitly, the wrappers in `graphics`
+/// (`ArrayBuffer`, `Texture`, ...) do this when dropped
+pub trait GraphicsDevice {
+    /// Create an empty buffer
+    fn create_buffer(&mut self, kind: BufferKind) -> BufferHandle;
+    /// Allocate `size` bytes of storage for a buffer, optionally filled with `data`.\
+    /// Replaces any previous storage
+    fn set_buffer_data(&mut self, buffer: BufferHandle, usage: BufferUsage, size: usize, data: Option<&[u8]>);
+    /// Write `data` into a buffer's storage starting at `offset` bytes
+    fn update_buffer(&mut self, buffer: BufferHandle, offset: usize, data: &[u8]);
+    /// Destroy a buffer
+    fn destroy_buffer(&mut self, buffer: BufferHandle);
+
+    /// Create an empty vertex array
+    fn create_vertex_array(&mut self) -> VertexArrayHandle;
+    /// Attach a vertex buffer to a vertex array, using `layout` to describe its attributes
+    fn set_vertex_buffer(&mut self, vertex_array: VertexArrayHandle, buffer: BufferHandle, layout: &BufferLayout);
+    /// Attach an index buffer to a vertex array
+    fn set_index_buffer(&mut self, vertex_array: VertexArrayHandle, buffer: BufferHandle);
+    /// Destroy a vertex array
+    fn destroy_vertex_array(&mut self, vertex_array: VertexArrayHandle);
+
+    /// Compile and link a shader program.\
+    /// Fails if either stage fails to compile or the program fails to link
+    fn create_shader(&mut self, vertex_source: &str, fragment_source: &str) -> Result<ShaderHandle>;
+    /// Make a shader the active shader, or unbind with `None`
+    fn bind_shader(&mut self, shader: Option<ShaderHandle>);
+    /// Set a uniform of a shader, binding the shader
+    fn set_uniform(&mut self, shader: ShaderHandle, name: &CStr, value: UniformValue);
+    /// Destroy a shader program
+    fn destroy_shader(&mut self, shader: ShaderHandle);
+
+    /// Create a texture, optionally filled with `data` laid out as the descriptor's format
+    fn create_texture(&mut self, width: u32, height: u32, descriptor: &TextureDescriptor, data: Option<&[u8]>) -> TextureHandle;
+    /// Replace a region of a texture with `data` laid out as `format`
+    #[allow(clippy::too_many_arguments)]
+    fn update_texture(&mut self, texture: TextureHandle, x: u32, y: u32, width: u32, height: u32, format: TextureFormat, data: &[u8]);
+    /// Regenerate a texture's mipmaps from its first level
+    fn generate_mipmaps(&mut self, texture: TextureHandle);
+    /// Read a texture's first level, laid out as `format`
+    fn read_texture(&mut self, texture: TextureHandle, width: u32, height: u32, format: TextureFormat) -> Vec<u8>;
+    /// Bind a texture to a slot, or unbind the slot with `None`
+    fn bind_texture(&mut self, slot: u32, texture: Option<TextureHandle>);
+    /// Destroy a texture
+    fn destroy_texture(&mut self, texture: TextureHandle);
+
+    /// Create a renderbuffer with `samples` samples per pixel
+    fn create_renderbuffer(&mut self, width: u32, height: u32, format: TextureFormat, samples: u32) -> RenderbufferHandle;
+    /// Destroy a renderbuffer
+    fn destroy_renderbuffer(&mut self, renderbuffer: RenderbufferHandle);
+
+    /// Create a framebuffer rendering to the color attachments, in order, and a depth attachment.\
+    /// Fails if the attachments don't make a complete framebuffer
+    fn create_framebuffer(&mut self, colors: &[Attachment], depth: Option<Attachment>) -> Result<FramebufferHandle>;
+    /// Render to a framebuffer, or the default framebuffer with `None`
+    fn bind_framebuffer(&mut self, framebuffer: Option<FramebufferHandle>);
+    /// Copy the first `color_count` color attachments, and optionally depth,
+    /// from one framebuffer to another. Resolves multisampled framebuffers
+    fn blit_framebuffer(&mut self, source: FramebufferHandle, destination: FramebufferHandle, width: u32, height: u32, color_count: u32, depth: bool);
+    /// Destroy a framebuffer, leaving its attachments
+    fn destroy_framebuffer(&mut self, framebuffer: FramebufferHandle);
+
+    /// Set how new colors are combined with the framebuffer
+    fn set_blend_mode(&mut self, blend: BlendMode);
+    /// Set depth testing and writing
+    fn set_depth_state(&mut self, depth: &DepthState);
+    /// Set stencil testing and writing
+    fn set_stencil_state(&mut self, stencil: &StencilState);
+    /// Set which faces are culled
+    fn set_cull_mode(&mut self, cull_mode: CullMode);
+    /// Set the winding order of front faces
+    fn set_front_face(&mut self, front_face: FrontFace);
+    /// Limit drawing to a region, or disable with `None`
+    fn set_scissor(&mut self, scissor: Option<ScissorRect>);
+    /// Set which color channels are written
+    fn set_color_mask(&mut self, color_mask: ColorMask);
+
+    /// Set the rendering viewport
+    fn set_viewport(&mut self, x: u32, y: u32, width: u32, height: u32);
+    /// Set the color to clear with
+    fn set_clear_color(&mut self, color: Vec4f);
+    /// Set the depth to clear with
+    fn set_clear_depth(&mut self, depth: f32);
+    /// Set the stencil value to clear with
+    fn set_clear_stencil(&mut self, stencil: u8);
+    /// Clear buffers of the bound framebuffer.\
+    /// Ignores the color mask and depth and stencil write masks, but not the scissor
+    fn clear(&mut self, mask: ClearMask);
+    /// Read RGBA bytes from the bound framebuffer's first color attachment, rows ordered bottom to top
+    fn read_pixels(&mut self, x: u32, y: u32, width: u32, height: u32) -> Vec<u8>;
+
+    /// Draw `count` indices of a vertex array as triangles
+    fn draw_indexed(&mut self, vertex_array: VertexArrayHandle, count: u32);
+}
+
+/// The view draws are culled against and the number of draws culled
+#[derive(Clone, Copy, Default, Debug)]
+pub(crate) struct Culling {
+    pub frustum: Option<Frustum>,
+    pub culled_count: u32
+}
+
+/// The device of a thread, the pipeline state last sent to it and its culling state
+struct CurrentDevice {
+    device: Box<dyn GraphicsDevice>,
+    pipeline_state: Option<PipelineState>,
+    culling: Culling
+}
+
+thread_local! {
+    static DEVICE: RefCell<CurrentDevice> = RefCell::new(CurrentDevice {
+        device: Box::new(GlDevice::new()),
+        pipeline_state: None,
+        culling: Culling::default()
+    });
+}
+
+/// Run a function with the current thread's `GraphicsDevice`.\
+/// Defaults to `GlDevice`, graphics contexts are per thread like OpenGL's
+///
+/// # Arguments
+///
+/// * `f` - The function to run
+pub fn with_device<R>(f: impl FnOnce(&mut dyn GraphicsDevice) -> R) -> R {
+    DEVICE.with(|current| f(current.borrow_mut().device.as_mut()))
+}
+
+/// Replace the current thread's `GraphicsDevice`, returning the previous one.\
+/// Resources created with the previous device must not be used afterwards
+///
+/// # Arguments
+///
+/// * `device` - The device to use
+pub fn set_device(device: Box<dyn GraphicsDevice>) -> Box<dyn GraphicsDevice> {
+    DEVICE.with(|current| {
+        let mut current = current.borrow_mut();
+        current.pipeline_state = None;
+        std::mem::replace(&mut current.device, device)
+    })
+}
+
+/// Set the pipeline state of the current thread's `GraphicsDevice`.\
+/// Only the parts that differ from the last state set are sent to the device
+///
+/// # Arguments
+///
+/// * `state` - The state to draw with
+pub fn set_pipeline_state(state: &PipelineState) {
+    DEVICE.with(|current| {
+        let mut current = current.borrow_mut();
+        let previous = current.pipeline_state.replace(*state);
+        let device = current.device.as_mut();
+
+        if previous.is_none_or(|previous| previous.blend != state.blend) {
+            device.set_blend_mode(state.blend);
+        }
+        if previous.is_none_or(|previous| previous.depth != state.depth) {
+            device.set_depth_state(&state.depth);
+        }
+        if previous.is_none_or(|previous| previous.stencil != state.stencil) {
+            device.set_stencil_state(&state.stencil);
+        }
+        if previous.is_none_or(|previous| previous.cull_mode != state.cull_mode) {
+            device.set_cull_mode(state.cull_mode);
+        }
+        if previous.is_none_or(|previous| previous.front_face != state.front_face) {
+            device.set_front_face(state.front_face);
+        }
+        if previous.is_none_or(|previous| previous.scissor != state.scissor) {
+            device.set_scissor(state.scissor);
+        }
+        if previous.is_none_or(|previous| previous.color_mask != state.color_mask) {
+            device.set_color_mask(state.color_mask);
+        }
+    })
+}
+
+/// Get the pipeline state last set on the current thread's `GraphicsDevice`, if any
+pub fn pipeline_state() -> Option<PipelineState> {
+    DEVICE.with(|current| current.borrow().pipeline_state)
+}
+
+/// Forget the pipeline state last set, so the next state is sent in full.\
+/// Needed after changing device state outside of `GraphicsDevice`
+pub fn invalidate_pipeline_state() {
+    DEVICE.with(|current| current.borrow_mut().pipeline_state = None);
+}
+
+/// Run a function with the current thread's culling state
+///
+/// # Arguments
+///
+/// * `f` - The function to run
+pub(crate) fn with_culling<R>(f: impl FnOnce(&mut Culling) -> R) -> R {
+    DEVICE.with(|current| f(&mut current.borrow_mut().culling))
+}
\ No newline at end of file
@@ -0,0 +1,709 @@
+use std::ffi::CStr;
+
+use crate::{Error, Result};
+use crate::math::vec4::Vec4f;
+use crate::graphics::array_buffer::{AttributeType, BufferLayout};
+
+use super::{GraphicsDevice, BufferHandle, BufferKind, BufferUsage, VertexArrayHandle, ShaderHandle, TextureHandle, UniformValue};
+use super::{TextureFormat, RenderbufferHandle, FramebufferHandle, Attachment, AttachmentTarget, ClearMask};
+use crate::graphics::shader::{self, ShaderStage};
+use crate::graphics::texture::{TextureDescriptor, TextureFilter, TextureWrap};
+use crate::graphics::pipeline::{BlendMode, DepthState, StencilState, StencilOperation, CompareFunction, CullMode, FrontFace, ScissorRect, ColorMask};
+
+// From the anisotropic filtering extension, core since OpenGL 4.6
+const TEXTURE_MAX_ANISOTROPY: u32 = 0x84FE;
+const MAX_TEXTURE_MAX_ANISOTROPY: u32 = 0x84FF;
+
+/// OpenGL 3.3 core `GraphicsDevice`.\
+/// Handles are OpenGL object names
+///
+/// Needs a current OpenGL context with loaded functions,
+/// e.g. from `Window` or `HeadlessContext`
+pub struct GlDevice {
+    // Write masks, which also apply to clears
+    depth_write: bool,
+    stencil_write_mask: u8,
+    color_mask: ColorMask,
+    // Queried when first needed, 1 when unsupported
+    max_anisotropy: Option<f32>
+}
+
+impl GlDevice {
+    /// Creates a new `GlDevice`
+    pub fn new() -> Self {
+        // OpenGL's initial state
+        GlDevice { depth_write: true, stencil_write_mask: 0xFF, color_mask: ColorMask::ALL, max_anisotropy: None }
+    }
+
+    /// Get the maximum supported anisotropy
+    fn max_anisotropy(&mut self) -> f32 {
+        *self.max_anisotropy.get_or_insert_with(|| unsafe {
+            let mut count = 0;
+            gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count);
+            let supported = (0..count as u32).any(|i| {
+                let name = std::ffi::CStr::from_ptr(gl::GetStringi(gl::EXTENSIONS, i).cast());
+                matches!(name.to_bytes(), b"GL_EXT_texture_filter_anisotropic" | b"GL_ARB_texture_filter_anisotropic")
+            });
+            if !supported {
+                return 1.0;
+            }
+            let mut max = 1.0;
+            gl::GetFloatv(MAX_TEXTURE_MAX_ANISOTROPY, &mut max);
+            max
+        })
+    }
+
+    /// Get the OpenGL minifying filter
+    const fn min_filter(filter: TextureFilter, mipmaps: bool) -> u32 {
+        match (filter, mipmaps) {
+            (TextureFilter::Nearest, false) => gl::NEAREST,
+            (TextureFilter::Linear, false) => gl::LINEAR,
+            (TextureFilter::Nearest, true) => gl::NEAREST_MIPMAP_NEAREST,
+            (TextureFilter::Linear, true) => gl::LINEAR_MIPMAP_LINEAR
+        }
+    }
+
+    /// Get the OpenGL magnifying filter
+    const fn mag_filter(filter: TextureFilter) -> u32 {
+        match filter {
+            TextureFilter::Nearest => gl::NEAREST,
+            TextureFilter::Linear => gl::LINEAR
+        }
+    }
+
+    /// Get the OpenGL wrap mode
+    const fn wrap(wrap: TextureWrap) -> u32 {
+        match wrap {
+            TextureWrap::Repeat => gl::REPEAT,
+            TextureWrap::MirroredRepeat => gl::MIRRORED_REPEAT,
+            TextureWrap::ClampToEdge => gl::CLAMP_TO_EDGE
+        }
+    }
+
+    /// Get the OpenGL comparison function
+    const fn compare_function(compare: CompareFunction) -> u32 {
+        match compare {
+            CompareFunction::Never => gl::NEVER,
+            CompareFunction::Less => gl::LESS,
+            CompareFunction::Equal => gl::EQUAL,
+            CompareFunction::LessEqual => gl::LEQUAL,
+            CompareFunction::Greater => gl::GREATER,
+            CompareFunction::NotEqual => gl::NOTEQUAL,
+            CompareFunction::GreaterEqual => gl::GEQUAL,
+            CompareFunction::Always => gl::ALWAYS
+        }
+    }
+
+    /// Get the OpenGL stencil operation
+    const fn stencil_operation(operation: StencilOperation) -> u32 {
+        match operation {
+            StencilOperation::Keep => gl::KEEP,
+            StencilOperation::Zero => gl::ZERO,
+            StencilOperation::Replace => gl::REPLACE,
+            StencilOperation::Increment => gl::INCR,
+            StencilOperation::IncrementWrap => gl::INCR_WRAP,
+            StencilOperation::Decrement => gl::DECR,
+            StencilOperation::DecrementWrap => gl::DECR_WRAP,
+            StencilOperation::Invert => gl::INVERT
+        }
+    }
+
+    /// Set the OpenGL write masks
+    unsafe fn set_write_masks(depth_write: bool, stencil_write_mask: u8, color_mask: ColorMask) {
+        gl::DepthMask(if depth_write { gl::TRUE } else { gl::FALSE });
+        gl::StencilMask(stencil_write_mask as u32);
+        gl::ColorMask(
+            color_mask.red as u8,
+            color_mask.green as u8,
+            color_mask.blue as u8,
+            color_mask.alpha as u8);
+    }
+
+    /// Get the OpenGL component type of an attribute
+    const fn component_type(attribute_type: AttributeType) -> u32 {
+        match attribute_type {
+            AttributeType::Int => gl::INT,
+            AttributeType::Float |
+            AttributeType::Vec2f |
+            AttributeType::Vec3f |
+            AttributeType::Vec4f => gl::FLOAT
+        }
+    }
+
+    /// Get the OpenGL internal format, pixel format and pixel type of a texture format
+    const fn pixel_format(format: TextureFormat) -> (u32, u32, u32) {
+        match format {
+            TextureFormat::R8 => (gl::R8, gl::RED, gl::UNSIGNED_BYTE),
+            TextureFormat::Rg8 => (gl::RG8, gl::RG, gl::UNSIGNED_BYTE),
+            TextureFormat::Rgba8 => (gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE),
+            TextureFormat::Srgba8 => (gl::SRGB8_ALPHA8, gl::RGBA, gl::UNSIGNED_BYTE),
+            TextureFormat::Rgba16f => (gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT),
+            TextureFormat::Rgba32f => (gl::RGBA32F, gl::RGBA, gl::FLOAT),
+            TextureFormat::Depth24Stencil8 => (gl::DEPTH24_STENCIL8, gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8),
+            TextureFormat::Depth32f => (gl::DEPTH_COMPONENT32F, gl::DEPTH_COMPONENT, gl::FLOAT)
+        }
+    }
+
+    /// Get the framebuffer attachment point of a depth format
+    const fn depth_attachment_point(format: TextureFormat) -> u32 {
+        if format.has_stencil() { gl::DEPTH_STENCIL_ATTACHMENT } else { gl::DEPTH_ATTACHMENT }
+    }
+
+    /// Attach an image to the bound framebuffer
+    unsafe fn attach(point: u32, attachment: &Attachment) {
+        match attachment.target {
+            AttachmentTarget::Texture(texture) =>
+                gl::FramebufferTexture2D(gl::FRAMEBUFFER, point, gl::TEXTURE_2D, texture.0, 0),
+            AttachmentTarget::Renderbuffer(renderbuffer) =>
+                gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, point, gl::RENDERBUFFER, renderbuffer.0)
+        }
+    }
+
+    /// Compile a shader stage, failing with the info log
+    unsafe fn compile_stage(stage: ShaderStage, source: &str) -> Result<u32> {
+        let gl_stage = match stage {
+            ShaderStage::Vertex => gl::VERTEX_SHADER,
+            ShaderStage::Fragment => gl::FRAGMENT_SHADER
+        };
+        let shader = gl::CreateShader(gl_stage);
+        assert_ne!(shader, 0);
+        gl::ShaderSource(
+            shader,
+            1,
+            &(source.as_bytes().as_ptr().cast()),
+            &(source.len().try_into().unwrap())
+        );
+        gl::CompileShader(shader);
+
+        let mut success = 0;
+        gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
+        if success == 0 {
+            let mut log_len = 0;
+            gl::GetShaderiv(shader, gl::INFO_LOG_LENGTH, &mut log_len);
+            let mut v = vec![0u8; log_len.max(1) as usize];
+            gl::GetShaderInfoLog(shader, v.len() as i32, &mut log_len, v.as_mut_ptr().cast());
+            v.truncate(log_len as usize);
+            gl::DeleteShader(shader);
+
+            let log = String::from_utf8_lossy(&v).into_owned();
+            let lines = shader::error_lines(&log);
+            return Err(Error::ShaderCompile { stage, log, lines });
+        }
+        Ok(shader)
+    }
+}
+
+impl Default for GlDevice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphicsDevice for GlDevice {
+    fn create_buffer(&mut self, _kind: BufferKind) -> BufferHandle {
+        let mut id = 0;
+        unsafe {
+            gl::GenBuffers(1, &mut id);
+        }
+        BufferHandle(id)
+    }
+
+    fn set_buffer_data(&mut self, buffer: BufferHandle, usage: BufferUsage, size: usize, data: Option<&[u8]>) {
+        let usage = match usage {
+            BufferUsage::Static => gl::STATIC_DRAW,
+            BufferUsage::Dynamic => gl::DYNAMIC_DRAW
+        };
+        let data = data.map_or(std::ptr::null(), |data| {
+            assert!(data.len() >= size);
+            data.as_ptr().cast()
+        });
+        // Any buffer can be written through the array buffer target,
+        // index buffers are vertex array state so are only bound when attached
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, buffer.0);
+            gl::BufferData(gl::ARRAY_BUFFER, size as isize, data, usage);
+        }
+    }
+
+    fn update_buffer(&mut self, buffer: BufferHandle, offset: usize, data: &[u8]) {
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, buffer.0);
+            gl::BufferSubData(gl::ARRAY_BUFFER, offset as isize, data.len() as isize, data.as_ptr().cast());
+        }
+    }
+
+    fn destroy_buffer(&mut self, buffer: BufferHandle) {
+        unsafe {
+            gl::DeleteBuffers(1, &buffer.0);
+        }
+    }
+
+    fn create_vertex_array(&mut self) -> VertexArrayHandle {
+        let mut id = 0;
+        unsafe {
+            gl::GenVertexArrays(1, &mut id);
+        }
+        VertexArrayHandle(id)
+    }
+
+    fn set_vertex_buffer(&mut self, vertex_array: VertexArrayHandle, buffer: BufferHandle, layout: &BufferLayout) {
+        let attributes = layout.attributes();
+        let offsets = layout.offsets();
+
+        unsafe {
+            gl::BindVertexArray(vertex_array.0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, buffer.0);
+
+            for i in 0..attributes.len() {
+                let attribute_type = attributes[i].attribute_type();
+                gl::EnableVertexAttribArray(i as u32);
+                match attribute_type {
+                    AttributeType::Int => {
+                        gl::VertexAttribIPointer(
+                            i as u32,
+                            attribute_type.component_count() as i32,
+                            Self::component_type(attribute_type),
+                            layout.stride() as i32,
+                            offsets[i] as *const _
+                        );
+                    }
+                    AttributeType::Float |
+                    AttributeType::Vec2f |
+                    AttributeType::Vec3f |
+                    AttributeType::Vec4f => {
+                        gl::VertexAttribPointer(
+                            i as u32,
+                            attribute_type.component_count() as i32,
+                            Self::component_type(attribute_type),
+                            if attributes[i].normalized() { gl::TRUE } else { gl::FALSE },
+                            layout.stride() as i32,
+                            offsets[i] as *const _
+                        );
+                    }
+                }
+            }
+            gl::BindVertexArray(0);
+        }
+    }
+
+    fn set_index_buffer(&mut self, vertex_array: VertexArrayHandle, buffer: BufferHandle) {
+        unsafe {
+            gl::BindVertexArray(vertex_array.0);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, buffer.0);
+            gl::BindVertexArray(0);
+        }
+    }
+
+    fn destroy_vertex_array(&mut self, vertex_array: VertexArrayHandle) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &vertex_array.0);
+        }
+    }
+
+    fn create_shader(&mut self, vertex_source: &str, fragment_source: &str) -> Result<ShaderHandle> {
+        unsafe {
+            let vertex_shader = Self::compile_stage(ShaderStage::Vertex, vertex_source)?;
+            let fragment_shader = match Self::compile_stage(ShaderStage::Fragment, fragment_source) {
+                Ok(fragment_shader) => fragment_shader,
+                Err(error) => {
+                    gl::DeleteShader(vertex_shader);
+                    return Err(error);
+                }
+            };
+
+            let program = gl::CreateProgram();
+            assert_ne!(program, 0);
+            gl::AttachShader(program, vertex_shader);
+            gl::AttachShader(program, fragment_shader);
+            gl::LinkProgram(program);
+            gl::DeleteShader(vertex_shader);
+            gl::DeleteShader(fragment_shader);
+
+            let mut success = 0;
+            gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
+            if success == 0 {
+                let mut log_len = 0;
+                gl::GetProgramiv(program, gl::INFO_LOG_LENGTH, &mut log_len);
+                let mut v = vec![0u8; log_len.max(1) as usize];
+                gl::GetProgramInfoLog(program, v.len() as i32, &mut log_len, v.as_mut_ptr().cast());
+                v.truncate(log_len as usize);
+                gl::DeleteProgram(program);
+                return Err(Error::ShaderLink { log: String::from_utf8_lossy(&v).into_owned() });
+            }
+            Ok(ShaderHandle(program))
+        }
+    }
+
+    fn bind_shader(&mut self, shader: Option<ShaderHandle>) {
+        unsafe {
+            gl::UseProgram(shader.map_or(0, |shader| shader.0));
+        }
+    }
+
+    fn set_uniform(&mut self, shader: ShaderHandle, name: &CStr, value: UniformValue) {
+        unsafe {
+            gl::UseProgram(shader.0);
+            let location = gl::GetUniformLocation(shader.0, name.as_ptr());
+            match value {
+                UniformValue::Int(val) => gl::Uniform1i(location, val),
+                UniformValue::IntArray(vals) => gl::Uniform1iv(location, vals.len() as i32, vals.as_ptr()),
+                UniformValue::Float(val) => gl::Uniform1f(location, val),
+                UniformValue::Vec2f(val) => gl::Uniform2f(location, val.x, val.y),
+                UniformValue::Vec3f(val) => gl::Uniform3f(location, val.x, val.y, val.z),
+                UniformValue::Vec4f(val) => gl::Uniform4f(location, val.x, val.y, val.z, val.w),
+                UniformValue::Mat2f(val) => gl::UniformMatrix2fv(location, 1, gl::FALSE, val.values.as_ptr()),
+                UniformValue::Mat3f(val) => gl::UniformMatrix3fv(location, 1, gl::FALSE, val.values.as_ptr()),
+                UniformValue::Mat4f(val) => gl::UniformMatrix4fv(location, 1, gl::FALSE, val.values.as_ptr())
+            }
+        }
+    }
+
+    fn destroy_shader(&mut self, shader: ShaderHandle) {
+        unsafe {
+            gl::DeleteProgram(shader.0);
+        }
+    }
+
+    fn create_texture(&mut self, width: u32, height: u32, descriptor: &TextureDescriptor, data: Option<&[u8]>) -> TextureHandle {
+        let format = descriptor.format;
+        let data = data.map_or(std::ptr::null(), |data| {
+            assert_eq!(data.len() / format.bytes_per_pixel(), (width * height) as usize);
+            data.as_ptr().cast()
+        });
+        let (internal_format, pixel_format, pixel_type) = Self::pixel_format(format);
+        let anisotropy = descriptor.anisotropy.clamp(1.0, self.max_anisotropy());
+
+        let mut id = 0;
+        unsafe {
+            gl::GenTextures(1, &mut id);
+            gl::BindTexture(gl::TEXTURE_2D, id);
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                internal_format as i32,
+                width as i32,
+                height as i32,
+                0,
+                pixel_format,
+                pixel_type,
+                data);
+
+            let min_filter = Self::min_filter(descriptor.min_filter, descriptor.mipmaps);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, min_filter as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, Self::mag_filter(descriptor.mag_filter) as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, Self::wrap(descriptor.wrap_u) as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, Self::wrap(descriptor.wrap_v) as i32);
+            if anisotropy > 1.0 {
+                gl::TexParameterf(gl::TEXTURE_2D, TEXTURE_MAX_ANISOTROPY, anisotropy);
+            }
+            if descriptor.mipmaps {
+                gl::GenerateMipmap(gl::TEXTURE_2D);
+            }
+        }
+        TextureHandle(id)
+    }
+
+    fn update_texture(&mut self, texture: TextureHandle, x: u32, y: u32, width: u32, height: u32, format: TextureFormat, data: &[u8]) {
+        assert_eq!(data.len() / format.bytes_per_pixel(), (width * height) as usize);
+        let (_, pixel_format, pixel_type) = Self::pixel_format(format);
+        unsafe {
+            gl::BindTexture(gl::TEXTURE_2D, texture.0);
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+            gl::TexSubImage2D(
+                gl::TEXTURE_2D,
+                0,
+                x as i32,
+                y as i32,
+                width as i32,
+                height as i32,
+                pixel_format,
+                pixel_type,
+                data.as_ptr().cast());
+        }
+    }
+
+    fn generate_mipmaps(&mut self, texture: TextureHandle) {
+        unsafe {
+            gl::BindTexture(gl::TEXTURE_2D, texture.0);
+            gl::GenerateMipmap(gl::TEXTURE_2D);
+        }
+    }
+
+    fn read_texture(&mut self, texture: TextureHandle, width: u32, height: u32, format: TextureFormat) -> Vec<u8> {
+        let (_, pixel_format, pixel_type) = Self::pixel_format(format);
+        let mut pixels = vec![0u8; width as usize * height as usize * format.bytes_per_pixel()];
+        unsafe {
+            gl::BindTexture(gl::TEXTURE_2D, texture.0);
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::GetTexImage(gl::TEXTURE_2D, 0, pixel_format, pixel_type, pixels.as_mut_ptr().cast());
+        }
+        pixels
+    }
+
+    fn bind_texture(&mut self, slot: u32, texture: Option<TextureHandle>) {
+        unsafe {
+            gl::ActiveTexture(gl::TEXTURE0 + slot);
+            gl::BindTexture(gl::TEXTURE_2D, texture.map_or(0, |texture| texture.0));
+        }
+    }
+
+    fn destroy_texture(&mut self, texture: TextureHandle) {
+        unsafe {
+            gl::DeleteTextures(1, &texture.0);
+        }
+    }
+
+    fn create_renderbuffer(&mut self, width: u32, height: u32, format: TextureFormat, samples: u32) -> RenderbufferHandle {
+        let (internal_format, _, _) = Self::pixel_format(format);
+        let mut id = 0;
+        unsafe {
+            gl::GenRenderbuffers(1, &mut id);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, id);
+            gl::RenderbufferStorageMultisample(
+                gl::RENDERBUFFER,
+                samples as i32,
+                internal_format,
+                width as i32,
+                height as i32);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+        }
+        RenderbufferHandle(id)
+    }
+
+    fn destroy_renderbuffer(&mut self, renderbuffer: RenderbufferHandle) {
+        unsafe {
+            gl::DeleteRenderbuffers(1, &renderbuffer.0);
+        }
+    }
+
+    fn create_framebuffer(&mut self, colors: &[Attachment], depth: Option<Attachment>) -> Result<FramebufferHandle> {
+        let mut id = 0;
+        unsafe {
+            gl::GenFramebuffers(1, &mut id);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, id);
+
+            let mut draw_buffers = Vec::with_capacity(colors.len());
+            for (i, color) in colors.iter().enumerate() {
+                let point = gl::COLOR_ATTACHMENT0 + i as u32;
+                Self::attach(point, color);
+                draw_buffers.push(point);
+            }
+            if let Some(depth) = depth {
+                Self::attach(Self::depth_attachment_point(depth.format), &depth);
+            }
+            if draw_buffers.is_empty() {
+                gl::DrawBuffer(gl::NONE);
+                gl::ReadBuffer(gl::NONE);
+            } else {
+                gl::DrawBuffers(draw_buffers.len() as i32, draw_buffers.as_ptr());
+            }
+
+            let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            if status != gl::FRAMEBUFFER_COMPLETE {
+                gl::DeleteFramebuffers(1, &id);
+                return Err(Error::Framebuffer(format!("Incomplete with status {:#x}", status)));
+            }
+        }
+        Ok(FramebufferHandle(id))
+    }
+
+    fn bind_framebuffer(&mut self, framebuffer: Option<FramebufferHandle>) {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer.map_or(0, |framebuffer| framebuffer.0));
+        }
+    }
+
+    fn blit_framebuffer(&mut self, source: FramebufferHandle, destination: FramebufferHandle, width: u32, height: u32, color_count: u32, depth: bool) {
+        let (width, height) = (width as i32, height as i32);
+        unsafe {
+            let mut bound = 0;
+            gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut bound);
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, source.0);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, destination.0);
+
+            // Blits copy one read buffer at a time, so copy each color attachment separately
+            for i in 0..color_count {
+                let point = gl::COLOR_ATTACHMENT0 + i;
+                gl::ReadBuffer(point);
+                gl::DrawBuffers(1, &point);
+                gl::BlitFramebuffer(0, 0, width, height, 0, 0, width, height, gl::COLOR_BUFFER_BIT, gl::NEAREST);
+            }
+            if depth {
+                gl::BlitFramebuffer(0, 0, width, height, 0, 0, width, height, gl::DEPTH_BUFFER_BIT, gl::NEAREST);
+            }
+
+            // Restore the source's read buffer and the destination's draw buffers
+            let draw_buffers: Vec<u32> = (0..color_count).map(|i| gl::COLOR_ATTACHMENT0 + i).collect();
+            if color_count > 0 {
+                gl::ReadBuffer(gl::COLOR_ATTACHMENT0);
+                gl::DrawBuffers(color_count as i32, draw_buffers.as_ptr());
+            }
+            gl::BindFramebuffer(gl::FRAMEBUFFER, bound as u32);
+        }
+    }
+
+    fn destroy_framebuffer(&mut self, framebuffer: FramebufferHandle) {
+        unsafe {
+            gl::DeleteFramebuffers(1, &framebuffer.0);
+        }
+    }
+
+    fn set_blend_mode(&mut self, blend: BlendMode) {
+        let factors = match blend {
+            BlendMode::Opaque => None,
+            BlendMode::Alpha => Some((gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA)),
+            BlendMode::Additive => Some((gl::SRC_ALPHA, gl::ONE)),
+            BlendMode::Multiply => Some((gl::DST_COLOR, gl::ZERO)),
+            BlendMode::Premultiplied => Some((gl::ONE, gl::ONE_MINUS_SRC_ALPHA))
+        };
+        unsafe {
+            match factors {
+                Some((source, destination)) => {
+                    gl::Enable(gl::BLEND);
+                    gl::BlendFunc(source, destination);
+                },
+                None => gl::Disable(gl::BLEND)
+            }
+        }
+    }
+
+    fn set_depth_state(&mut self, depth: &DepthState) {
+        self.depth_write = depth.write;
+        unsafe {
+            if depth.test {
+                gl::Enable(gl::DEPTH_TEST);
+            } else {
+                gl::Disable(gl::DEPTH_TEST);
+            }
+            gl::DepthFunc(Self::compare_function(depth.compare));
+            gl::DepthMask(if depth.write { gl::TRUE } else { gl::FALSE });
+        }
+    }
+
+    fn set_stencil_state(&mut self, stencil: &StencilState) {
+        self.stencil_write_mask = stencil.write_mask;
+        unsafe {
+            if stencil.enabled {
+                gl::Enable(gl::STENCIL_TEST);
+            } else {
+                gl::Disable(gl::STENCIL_TEST);
+            }
+            gl::StencilFunc(
+                Self::compare_function(stencil.compare),
+                stencil.reference as i32,
+                stencil.read_mask as u32);
+            gl::StencilOp(
+                Self::stencil_operation(stencil.fail),
+                Self::stencil_operation(stencil.depth_fail),
+                Self::stencil_operation(stencil.pass));
+            gl::StencilMask(stencil.write_mask as u32);
+        }
+    }
+
+    fn set_cull_mode(&mut self, cull_mode: CullMode) {
+        unsafe {
+            match cull_mode {
+                CullMode::None => gl::Disable(gl::CULL_FACE),
+                CullMode::Front => {
+                    gl::Enable(gl::CULL_FACE);
+                    gl::CullFace(gl::FRONT);
+                },
+                CullMode::Back => {
+                    gl::Enable(gl::CULL_FACE);
+                    gl::CullFace(gl::BACK);
+                }
+            }
+        }
+    }
+
+    fn set_front_face(&mut self, front_face: FrontFace) {
+        unsafe {
+            gl::FrontFace(match front_face {
+                FrontFace::Clockwise => gl::CW,
+                FrontFace::CounterClockwise => gl::CCW
+            });
+        }
+    }
+
+    fn set_scissor(&mut self, scissor: Option<ScissorRect>) {
+        unsafe {
+            match scissor {
+                Some(scissor) => {
+                    gl::Enable(gl::SCISSOR_TEST);
+                    gl::Scissor(scissor.x as i32, scissor.y as i32, scissor.width as i32, scissor.height as i32);
+                },
+                None => gl::Disable(gl::SCISSOR_TEST)
+            }
+        }
+    }
+
+    fn set_color_mask(&mut self, color_mask: ColorMask) {
+        self.color_mask = color_mask;
+        unsafe {
+            Self::set_write_masks(self.depth_write, self.stencil_write_mask, color_mask);
+        }
+    }
+
+    fn set_viewport(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        unsafe {
+            gl::Viewport(x as i32, y as i32, width as i32, height as i32);
+        }
+    }
+
+    fn set_clear_color(&mut self, color: Vec4f) {
+        unsafe {
+            gl::ClearColor(color.x, color.y, color.z, color.w);
+        }
+    }
+
+    fn set_clear_depth(&mut self, depth: f32) {
+        unsafe {
+            gl::ClearDepth(depth as f64);
+        }
+    }
+
+    fn set_clear_stencil(&mut self, stencil: u8) {
+        unsafe {
+            gl::ClearStencil(stencil as i32);
+        }
+    }
+
+    fn clear(&mut self, mask: ClearMask) {
+        let mut bits = 0;
+        if mask.color { bits |= gl::COLOR_BUFFER_BIT; }
+        if mask.depth { bits |= gl::DEPTH_BUFFER_BIT; }
+        if mask.stencil { bits |= gl::STENCIL_BUFFER_BIT; }
+        unsafe {
+            // Write masks apply to clears, open them up for the duration
+            Self::set_write_masks(true, 0xFF, ColorMask::ALL);
+            gl::Clear(bits);
+            Self::set_write_masks(self.depth_write, self.stencil_write_mask, self.color_mask);
+        }
+    }
+
+    fn read_pixels(&mut self, x: u32, y: u32, width: u32, height: u32) -> Vec<u8> {
+        let mut pixels = vec![0u8; (width * height * 4) as usize];
+        unsafe {
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(
+                x as i32,
+                y as i32,
+                width as i32,
+                height as i32,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                pixels.as_mut_ptr().cast());
+        }
+        pixels
+    }
+
+    fn draw_indexed(&mut self, vertex_array: VertexArrayHandle, count: u32) {
+        unsafe {
+            gl::BindVertexArray(vertex_array.0);
+            gl::DrawElements(gl::TRIANGLES, count as i32, gl::UNSIGNED_INT, std::ptr::null());
+            gl::BindVertexArray(0);
+        }
+    }
+}
\ No newline at end of file
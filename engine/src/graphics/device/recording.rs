@@ -0,0 +1,281 @@
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::rc::Rc;
+
+use crate::Result;
+use crate::math::vec4::Vec4f;
+use crate::graphics::array_buffer::BufferLayout;
+
+use super::{GraphicsDevice, BufferHandle, BufferKind, BufferUsage, VertexArrayHandle, ShaderHandle, TextureHandle, UniformValue};
+use super::{TextureFormat, RenderbufferHandle, FramebufferHandle, Attachment, ClearMask};
+use crate::graphics::texture::TextureDescriptor;
+use crate::graphics::pipeline::{BlendMode, DepthState, StencilState, CullMode, FrontFace, ScissorRect, ColorMask};
+
+/// A call made to a `RecordingDevice`
+#[derive(Clone, PartialEq, Debug)]
+pub enum Command {
+    CreateBuffer { buffer: BufferHandle, kind: BufferKind },
+    SetBufferData { buffer: BufferHandle, usage: BufferUsage, size: usize },
+    UpdateBuffer { buffer: BufferHandle, offset: usize, data: Vec<u8> },
+    DestroyBuffer(BufferHandle),
+    CreateVertexArray(VertexArrayHandle),
+    SetVertexBuffer { vertex_array: VertexArrayHandle, buffer: BufferHandle, stride: u32 },
+    SetIndexBuffer { vertex_array: VertexArrayHandle, buffer: BufferHandle },
+    DestroyVertexArray(VertexArrayHandle),
+    CreateShader(ShaderHandle),
+    BindShader(Option<ShaderHandle>),
+    SetUniform { shader: ShaderHandle, name: String, value: UniformValue },
+    DestroyShader(ShaderHandle),
+    CreateTexture { texture: TextureHandle, width: u32, height: u32, descriptor: TextureDescriptor },
+    UpdateTexture { texture: TextureHandle, x: u32, y: u32, width: u32, height: u32, data: Vec<u8> },
+    GenerateMipmaps(TextureHandle),
+    ReadTexture(TextureHandle),
+    BindTexture { slot: u32, texture: Option<TextureHandle> },
+    DestroyTexture(TextureHandle),
+    CreateRenderbuffer { renderbuffer: RenderbufferHandle, width: u32, height: u32, format: TextureFormat, samples: u32 },
+    DestroyRenderbuffer(RenderbufferHandle),
+    CreateFramebuffer { framebuffer: FramebufferHandle, colors: Vec<Attachment>, depth: Option<Attachment> },
+    BindFramebuffer(Option<FramebufferHandle>),
+    BlitFramebuffer { source: FramebufferHandle, destination: FramebufferHandle, width: u32, height: u32, color_count: u32, depth: bool },
+    DestroyFramebuffer(FramebufferHandle),
+    SetBlendMode(BlendMode),
+    SetDepthState(DepthState),
+    SetStencilState(StencilState),
+    SetCullMode(CullMode),
+    SetFrontFace(FrontFace),
+    SetScissor(Option<ScissorRect>),
+    SetColorMask(ColorMask),
+    SetViewport { x: u32, y: u32, width: u32, height: u32 },
+    SetClearColor(Vec4f),
+    SetClearDepth(f32),
+    SetClearStencil(u8),
+    Clear(ClearMask),
+    ReadPixels { x: u32, y: u32, width: u32, height: u32 },
+    DrawIndexed { vertex_array: VertexArrayHandle, count: u32 }
+}
+
+/// Shared list of the commands recorded by a `RecordingDevice`
+#[derive(Clone, Default)]
+pub struct CommandLog {
+    commands: Rc<RefCell<Vec<Command>>>
+}
+
+impl CommandLog {
+    /// Get a copy of the recorded commands
+    pub fn commands(&self) -> Vec<Command> {
+        self.commands.borrow().clone()
+    }
+
+    /// Get the recorded draw calls
+    pub fn draws(&self) -> Vec<Command> {
+        self.commands.borrow().iter()
+            .filter(|command| matches!(command, Command::DrawIndexed { .. }))
+            .cloned()
+            .collect()
+    }
+
+    /// Remove all recorded commands
+    pub fn clear(&self) {
+        self.commands.borrow_mut().clear();
+    }
+
+    fn push(&self, command: Command) {
+        self.commands.borrow_mut().push(command);
+    }
+}
+
+/// A `GraphicsDevice` that draws nothing and records every call.\
+/// Useful for testing rendering code without a graphics context
+///
+/// Handles are numbered from 1 in creation order, shared across resource types
+#[derive(Default)]
+pub struct RecordingDevice {
+    log: CommandLog,
+    next_id: u32
+}
+
+impl RecordingDevice {
+    /// Creates a new `RecordingDevice`
+    pub fn new() -> Self {
+        RecordingDevice { log: CommandLog::default(), next_id: 0 }
+    }
+
+    /// Get the log that commands are recorded to
+    pub fn log(&self) -> CommandLog {
+        self.log.clone()
+    }
+
+    fn next_id(&mut self) -> u32 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+impl GraphicsDevice for RecordingDevice {
+    fn create_buffer(&mut self, kind: BufferKind) -> BufferHandle {
+        let buffer = BufferHandle(self.next_id());
+        self.log.push(Command::CreateBuffer { buffer, kind });
+        buffer
+    }
+
+    fn set_buffer_data(&mut self, buffer: BufferHandle, usage: BufferUsage, size: usize, _data: Option<&[u8]>) {
+        self.log.push(Command::SetBufferData { buffer, usage, size });
+    }
+
+    fn update_buffer(&mut self, buffer: BufferHandle, offset: usize, data: &[u8]) {
+        self.log.push(Command::UpdateBuffer { buffer, offset, data: data.to_vec() });
+    }
+
+    fn destroy_buffer(&mut self, buffer: BufferHandle) {
+        self.log.push(Command::DestroyBuffer(buffer));
+    }
+
+    fn create_vertex_array(&mut self) -> VertexArrayHandle {
+        let vertex_array = VertexArrayHandle(self.next_id());
+        self.log.push(Command::CreateVertexArray(vertex_array));
+        vertex_array
+    }
+
+    fn set_vertex_buffer(&mut self, vertex_array: VertexArrayHandle, buffer: BufferHandle, layout: &BufferLayout) {
+        self.log.push(Command::SetVertexBuffer { vertex_array, buffer, stride: layout.stride() });
+    }
+
+    fn set_index_buffer(&mut self, vertex_array: VertexArrayHandle, buffer: BufferHandle) {
+        self.log.push(Command::SetIndexBuffer { vertex_array, buffer });
+    }
+
+    fn destroy_vertex_array(&mut self, vertex_array: VertexArrayHandle) {
+        self.log.push(Command::DestroyVertexArray(vertex_array));
+    }
+
+    fn create_shader(&mut self, _vertex_source: &str, _fragment_source: &str) -> Result<ShaderHandle> {
+        let shader = ShaderHandle(self.next_id());
+        self.log.push(Command::CreateShader(shader));
+        Ok(shader)
+    }
+
+    fn bind_shader(&mut self, shader: Option<ShaderHandle>) {
+        self.log.push(Command::BindShader(shader));
+    }
+
+    fn set_uniform(&mut self, shader: ShaderHandle, name: &CStr, value: UniformValue) {
+        let name = name.to_string_lossy().into_owned();
+        self.log.push(Command::SetUniform { shader, name, value });
+    }
+
+    fn destroy_shader(&mut self, shader: ShaderHandle) {
+        self.log.push(Command::DestroyShader(shader));
+    }
+
+    fn create_texture(&mut self, width: u32, height: u32, descriptor: &TextureDescriptor, _data: Option<&[u8]>) -> TextureHandle {
+        let texture = TextureHandle(self.next_id());
+        self.log.push(Command::CreateTexture { texture, width, height, descriptor: *descriptor });
+        texture
+    }
+
+    fn update_texture(&mut self, texture: TextureHandle, x: u32, y: u32, width: u32, height: u32, _format: TextureFormat, data: &[u8]) {
+        self.log.push(Command::UpdateTexture { texture, x, y, width, height, data: data.to_vec() });
+    }
+
+    fn generate_mipmaps(&mut self, texture: TextureHandle) {
+        self.log.push(Command::GenerateMipmaps(texture));
+    }
+
+    fn read_texture(&mut self, texture: TextureHandle, width: u32, height: u32, format: TextureFormat) -> Vec<u8> {
+        self.log.push(Command::ReadTexture(texture));
+        vec![0u8; width as usize * height as usize * format.bytes_per_pixel()]
+    }
+
+    fn bind_texture(&mut self, slot: u32, texture: Option<TextureHandle>) {
+        self.log.push(Command::BindTexture { slot, texture });
+    }
+
+    fn destroy_texture(&mut self, texture: TextureHandle) {
+        self.log.push(Command::DestroyTexture(texture));
+    }
+
+    fn create_renderbuffer(&mut self, width: u32, height: u32, format: TextureFormat, samples: u32) -> RenderbufferHandle {
+        let renderbuffer = RenderbufferHandle(self.next_id());
+        self.log.push(Command::CreateRenderbuffer { renderbuffer, width, height, format, samples });
+        renderbuffer
+    }
+
+    fn destroy_renderbuffer(&mut self, renderbuffer: RenderbufferHandle) {
+        self.log.push(Command::DestroyRenderbuffer(renderbuffer));
+    }
+
+    fn create_framebuffer(&mut self, colors: &[Attachment], depth: Option<Attachment>) -> Result<FramebufferHandle> {
+        let framebuffer = FramebufferHandle(self.next_id());
+        self.log.push(Command::CreateFramebuffer { framebuffer, colors: colors.to_vec(), depth });
+        Ok(framebuffer)
+    }
+
+    fn bind_framebuffer(&mut self, framebuffer: Option<FramebufferHandle>) {
+        self.log.push(Command::BindFramebuffer(framebuffer));
+    }
+
+    fn blit_framebuffer(&mut self, source: FramebufferHandle, destination: FramebufferHandle, width: u32, height: u32, color_count: u32, depth: bool) {
+        self.log.push(Command::BlitFramebuffer { source, destination, width, height, color_count, depth });
+    }
+
+    fn destroy_framebuffer(&mut self, framebuffer: FramebufferHandle) {
+        self.log.push(Command::DestroyFramebuffer(framebuffer));
+    }
+
+    fn set_blend_mode(&mut self, blend: BlendMode) {
+        self.log.push(Command::SetBlendMode(blend));
+    }
+
+    fn set_depth_state(&mut self, depth: &DepthState) {
+        self.log.push(Command::SetDepthState(*depth));
+    }
+
+    fn set_stencil_state(&mut self, stencil: &StencilState) {
+        self.log.push(Command::SetStencilState(*stencil));
+    }
+
+    fn set_cull_mode(&mut self, cull_mode: CullMode) {
+        self.log.push(Command::SetCullMode(cull_mode));
+    }
+
+    fn set_front_face(&mut self, front_face: FrontFace) {
+        self.log.push(Command::SetFrontFace(front_face));
+    }
+
+    fn set_scissor(&mut self, scissor: Option<ScissorRect>) {
+        self.log.push(Command::SetScissor(scissor));
+    }
+
+    fn set_color_mask(&mut self, color_mask: ColorMask) {
+        self.log.push(Command::SetColorMask(color_mask));
+    }
+
+    fn set_viewport(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        self.log.push(Command::SetViewport { x, y, width, height });
+    }
+
+    fn set_clear_color(&mut self, color: Vec4f) {
+        self.log.push(Command::SetClearColor(color));
+    }
+
+    fn set_clear_depth(&mut self, depth: f32) {
+        self.log.push(Command::SetClearDepth(depth));
+    }
+
+    fn set_clear_stencil(&mut self, stencil: u8) {
+        self.log.push(Command::SetClearStencil(stencil));
+    }
+
+    fn clear(&mut self, mask: ClearMask) {
+        self.log.push(Command::Clear(mask));
+    }
+
+    fn read_pixels(&mut self, x: u32, y: u32, width: u32, height: u32) -> Vec<u8> {
+        self.log.push(Command::ReadPixels { x, y, width, height });
+        vec![0u8; (width * height * 4) as usize]
+    }
+
+    fn draw_indexed(&mut self, vertex_array: VertexArrayHandle, count: u32) {
+        self.log.push(Command::DrawIndexed { vertex_array, count });
+    }
+}
\ No newline at end of file
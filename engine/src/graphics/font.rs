@@ -0,0 +1,616 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{Error, Result};
+use crate::math::vec2::Vec2f;
+use crate::math::geometry::rect2::Rect2;
+use super::texture::Texture;
+use super::texture_atlas::Sprite;
+#[cfg(feature = "ttf")]
+use super::device::TextureFormat;
+#[cfg(feature = "ttf")]
+use super::texture::TextureDescriptor;
+#[cfg(feature = "ttf")]
+use super::texture_atlas::{TextureAtlas, AtlasImage, AtlasDescriptor};
+
+/// Horizontal alignment of the lines of a text
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right
+}
+
+/// Describes how text is laid out
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextStyle {
+    /// Scale of the font's size, e.g. 2.0 draws a 16 pixel font 32 pixels high
+    pub scale: f32,
+    /// Alignment of each line within the width of the text, which is
+    /// `max_width` if set or the widest line otherwise
+    pub align: TextAlign,
+    /// The width to wrap lines at, breaking between words where possible
+    pub max_width: Option<f32>,
+    /// The distance between baselines, as a multiple of the font's line height
+    pub line_spacing: f32
+}
+
+impl TextStyle {
+    /// Creates a new `TextStyle` for left aligned text at the font's size, without wrapping
+    pub const fn new() -> Self {
+        TextStyle { scale: 1.0, align: TextAlign::Left, max_width: None, line_spacing: 1.0 }
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A character's image and placement in a `Font`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Glyph {
+    /// The region of the page the glyph is on, empty for glyphs like spaces
+    pub sprite: Sprite,
+    /// The index of the page the glyph is on
+    pub page: usize,
+    /// The bottom left of the sprite, from the pen on the baseline
+    pub offset: Vec2f,
+    /// How far the pen moves right after the glyph
+    pub advance: f32
+}
+
+/// A glyph placed by `Font::layout`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PositionedGlyph {
+    /// The character the glyph was placed for, its glyph may be the fallback
+    pub character: char,
+    /// The area the glyph's sprite covers
+    pub bounds: Rect2,
+    /// The bottom left texture coordinate
+    pub uv_min: Vec2f,
+    /// The top right texture coordinate
+    pub uv_max: Vec2f,
+    /// The index of the page the glyph is on
+    pub page: usize
+}
+
+/// Describes how a TrueType or OpenType font is rasterized
+#[cfg(feature = "ttf")]
+#[derive(Clone, PartialEq, Debug)]
+pub struct FontDescriptor {
+    /// The size to rasterize glyphs at, in pixels per em
+    pub size: f32,
+    /// The characters to rasterize, those the font doesn't have are skipped
+    pub characters: Vec<char>,
+    /// Drawn in place of characters without a glyph, `'?'` is used
+    /// if the font doesn't have it either
+    pub fallback: char,
+    /// Rasterize glyphs as signed distance fields, spreading this many pixels
+    /// past their edges, so they stay sharp when scaled up
+    pub sdf_spread: Option<u32>,
+    /// The maximum size, padding and sampling of the glyph atlas, which is always RGBA8
+    pub atlas: AtlasDescriptor
+}
+
+#[cfg(feature = "ttf")]
+impl FontDescriptor {
+    /// Creates a new `FontDescriptor` for printable ASCII, falling back to U+FFFD
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size to rasterize glyphs at, in pixels per em
+    pub fn new(size: f32) -> Self {
+        FontDescriptor {
+            size,
+            characters: (' '..='~').collect(),
+            fallback: char::REPLACEMENT_CHARACTER,
+            sdf_spread: None,
+            atlas: AtlasDescriptor::new()
+        }
+    }
+}
+
+/// Glyphs for drawing text, from one or more texture pages
+pub struct Font {
+    pages: Vec<Texture>,
+    glyphs: HashMap<char, Glyph>,
+    kerning: HashMap<(char, char), f32>,
+    size: f32,
+    line_height: f32,
+    ascent: f32,
+    fallback: Option<char>,
+    sdf: bool
+}
+
+impl Font {
+    /// Creates a new `Font` by rasterizing a TrueType or OpenType font into a glyph atlas
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The contents of the font file
+    /// * `descriptor` - The size, characters and atlas of the glyphs
+    ///
+    /// Returns an error if the font can't be parsed or its glyphs don't fit in the atlas
+    #[cfg(feature = "ttf")]
+    pub fn from_ttf(data: &[u8], descriptor: &FontDescriptor) -> Result<Self> {
+        let size = descriptor.size;
+        let font = fontdue::Font::from_bytes(data, fontdue::FontSettings { scale: size, ..Default::default() })
+            .map_err(|error| Error::Font(error.to_string()))?;
+        let line = font.horizontal_line_metrics(size)
+            .ok_or_else(|| Error::Font("The font has no horizontal line metrics".to_string()))?;
+
+        let fallback = [descriptor.fallback, '?'].into_iter().find(|&fallback| font.has_glyph(fallback));
+        let mut characters: Vec<char> = descriptor.characters.iter()
+            .copied()
+            .chain(fallback)
+            .filter(|&character| font.has_glyph(character) || character == ' ')
+            .collect();
+        characters.sort_unstable();
+        characters.dedup();
+
+        let mut images = Vec::with_capacity(characters.len());
+        let mut offsets = Vec::with_capacity(characters.len());
+        for &character in &characters {
+            let (metrics, coverage) = font.rasterize(character, size);
+            let (mut width, mut height) = (metrics.width, metrics.height);
+            let mut offset = Vec2f::new(metrics.xmin as f32, metrics.ymin as f32);
+            let alpha = match descriptor.sdf_spread {
+                Some(spread) if width > 0 && height > 0 => {
+                    let spread = spread.max(1) as usize;
+                    let field = signed_distance_field(&coverage, width, height, spread);
+                    width += spread * 2;
+                    height += spread * 2;
+                    offset -= Vec2f::splat(spread as f32);
+                    field
+                },
+                _ => coverage
+            };
+            // White glyphs so the tint colors them, rows flipped to bottom to top
+            let data = alpha.chunks(width.max(1))
+                .rev()
+                .flat_map(|row| row.iter().flat_map(|&alpha| [255, 255, 255, alpha]))
+                .collect();
+            images.push(AtlasImage::new(character.to_string(), data, width as u32, height as u32));
+            offsets.push((offset, metrics.advance_width));
+        }
+
+        let atlas = TextureAtlas::pack(&images, &AtlasDescriptor {
+            texture: TextureDescriptor { format: TextureFormat::Rgba8, ..descriptor.atlas.texture },
+            ..descriptor.atlas
+        })?;
+        let glyphs = characters.iter()
+            .zip(atlas.sprites().iter().zip(offsets))
+            .map(|(&character, (&sprite, (offset, advance)))| (character, Glyph { sprite, page: 0, offset, advance }))
+            .collect();
+
+        let mut kerning = HashMap::new();
+        for &left in &characters {
+            for &right in &characters {
+                match font.horizontal_kern(left, right, size) {
+                    Some(amount) if amount != 0.0 => { kerning.insert((left, right), amount); },
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(Font {
+            pages: vec![atlas.into_texture()],
+            glyphs,
+            kerning,
+            size,
+            line_height: line.new_line_size,
+            ascent: line.ascent,
+            fallback,
+            sdf: descriptor.sdf_spread.is_some()
+        })
+    }
+
+    /// Creates a new `Font` by rasterizing a TrueType or OpenType font file into a glyph atlas
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The font filepath
+    /// * `descriptor` - The size, characters and atlas of the glyphs
+    ///
+    /// Returns an error if the file can't be read or parsed, or its glyphs don't fit in the atlas
+    #[cfg(feature = "ttf")]
+    pub fn load_ttf(path: &str, descriptor: &FontDescriptor) -> Result<Self> {
+        let data = std::fs::read(path)
+            .map_err(|error| Error::Font(format!("Failed to read '{}': {}", path, error)))?;
+        Self::from_ttf(&data, descriptor)
+    }
+
+    /// Creates a new `Font` from an AngelCode BMFont description in the text format.\
+    /// Falls back to U+FFFD or `'?'` for missing characters, if the font has them
+    ///
+    /// # Arguments
+    ///
+    /// * `fnt` - The contents of the `.fnt` file
+    /// * `pages` - The page textures, in the order of their ids
+    ///
+    /// Returns an error if the description is malformed or its glyphs are outside their pages
+    pub fn from_bmfont(fnt: &str, pages: Vec<Texture>) -> Result<Self> {
+        let mut font = Font {
+            pages,
+            glyphs: HashMap::new(),
+            kerning: HashMap::new(),
+            size: 0.0,
+            line_height: 0.0,
+            ascent: 0.0,
+            fallback: None,
+            sdf: false
+        };
+        let mut has_common = false;
+        for line in fnt.lines() {
+            let (tag, attributes) = bmfont_attributes(line)?;
+            match tag {
+                "info" => font.size = bmfont_value::<f32>(&attributes, tag, "size")?.abs(),
+                "common" => {
+                    font.line_height = bmfont_value(&attributes, tag, "lineHeight")?;
+                    font.ascent = bmfont_value(&attributes, tag, "base")?;
+                    let pages: usize = bmfont_value(&attributes, tag, "pages")?;
+                    if pages != font.pages.len() {
+                        return Err(Error::Font(format!("The font has {} pages but {} were given", pages, font.pages.len())));
+                    }
+                    has_common = true;
+                },
+                "char" => {
+                    if !has_common {
+                        return Err(Error::Font("'char' comes before 'common'".to_string()));
+                    }
+                    // BMFont describes its own fallback glyph with the id -1
+                    let id: i64 = bmfont_value(&attributes, tag, "id")?;
+                    let Some(character) = u32::try_from(id).ok().and_then(char::from_u32) else {
+                        continue;
+                    };
+                    let page: usize = bmfont_value(&attributes, tag, "page")?;
+                    let texture = font.pages.get(page)
+                        .ok_or_else(|| Error::Font(format!("Character {} is on missing page {}", id, page)))?;
+                    let (page_width, page_height) = (texture.width(), texture.height());
+                    let x: u32 = bmfont_value(&attributes, tag, "x")?;
+                    let y: u32 = bmfont_value(&attributes, tag, "y")?;
+                    let width: u32 = bmfont_value(&attributes, tag, "width")?;
+                    let height: u32 = bmfont_value(&attributes, tag, "height")?;
+                    if x.checked_add(width).is_none_or(|right| right > page_width)
+                        || y.checked_add(height).is_none_or(|bottom| bottom > page_height) {
+                        return Err(Error::Font(format!("Character {} is outside of its page", id)));
+                    }
+                    let x_offset: f32 = bmfont_value(&attributes, tag, "xoffset")?;
+                    let y_offset: f32 = bmfont_value(&attributes, tag, "yoffset")?;
+                    // Positions and offsets are down from the top, texture rows are bottom to top
+                    let glyph = Glyph {
+                        sprite: Sprite::new(x, page_height - y - height, width, height, page_width, page_height),
+                        page,
+                        offset: Vec2f::new(x_offset, font.ascent - y_offset - height as f32),
+                        advance: bmfont_value(&attributes, tag, "xadvance")?
+                    };
+                    font.glyphs.insert(character, glyph);
+                },
+                "kerning" => {
+                    let first: u32 = bmfont_value(&attributes, tag, "first")?;
+                    let second: u32 = bmfont_value(&attributes, tag, "second")?;
+                    if let (Some(first), Some(second)) = (char::from_u32(first), char::from_u32(second)) {
+                        font.kerning.insert((first, second), bmfont_value(&attributes, tag, "amount")?);
+                    }
+                },
+                // Page files and counts are only needed to load the pages
+                _ => {}
+            }
+        }
+        if !has_common {
+            return Err(Error::Font("Missing the 'common' line, only the text format is supported".to_string()));
+        }
+        if font.size == 0.0 {
+            font.size = font.line_height;
+        }
+        font.fallback = [char::REPLACEMENT_CHARACTER, '?'].into_iter()
+            .find(|fallback| font.glyphs.contains_key(fallback));
+        Ok(font)
+    }
+
+    /// Creates a new `Font` from an AngelCode BMFont `.fnt` file in the text format,
+    /// loading its pages relative to it
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The `.fnt` filepath
+    /// * `descriptor` - The format and sampling of the pages, the format must be RGBA8 or sRGBA8
+    ///
+    /// Returns an error if any file can't be loaded or the description is malformed
+    #[cfg(feature = "window")]
+    pub fn load_bmfont(path: &str, descriptor: super::texture::TextureDescriptor) -> Result<Self> {
+        let fnt = std::fs::read_to_string(path)
+            .map_err(|error| Error::Font(format!("Failed to read '{}': {}", path, error)))?;
+        let mut files = Vec::new();
+        for line in fnt.lines() {
+            let (tag, attributes) = bmfont_attributes(line)?;
+            if tag == "page" {
+                let id: usize = bmfont_value(&attributes, tag, "id")?;
+                let file = attributes.get("file")
+                    .ok_or_else(|| Error::Font(format!("Page {} doesn't name its file", id)))?;
+                files.push((id, file.to_string()));
+            }
+        }
+        files.sort();
+
+        let pages = files.into_iter()
+            .map(|(_, file)| {
+                let page_path = std::path::Path::new(path).with_file_name(file);
+                Texture::from_file(&page_path.to_string_lossy(), descriptor)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_bmfont(&fnt, pages)
+    }
+
+    /// Get the textures the glyphs are on
+    pub fn pages(&self) -> &[Texture] {
+        &self.pages
+    }
+
+    /// Check if the pages hold signed distance fields rather than coverage
+    pub const fn is_sdf(&self) -> bool {
+        self.sdf
+    }
+
+    /// Get the size the font was made at, in pixels
+    pub const fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// Get the distance between baselines, in pixels
+    pub const fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Get the distance from the top of a line to its baseline, in pixels
+    pub const fn ascent(&self) -> f32 {
+        self.ascent
+    }
+
+    /// Get the character drawn in place of those without a glyph
+    pub const fn fallback(&self) -> Option<char> {
+        self.fallback
+    }
+
+    /// Set the character drawn in place of those without a glyph
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - The character to draw instead, or `None` to skip missing characters
+    pub fn set_fallback(&mut self, fallback: Option<char>) {
+        self.fallback = fallback;
+    }
+
+    /// Get the glyph drawn for a character.\
+    /// Whitespace without a glyph is drawn as a space, anything else as the fallback
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - The character to get the glyph of
+    pub fn glyph(&self, character: char) -> Option<&Glyph> {
+        self.glyphs.get(&character)
+            .or_else(|| character.is_whitespace().then(|| self.glyphs.get(&' ')).flatten())
+            .or_else(|| self.fallback.and_then(|fallback| self.glyphs.get(&fallback)))
+    }
+
+    /// Get the adjustment to the pen between two characters, in pixels
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The first character
+    /// * `right` - The character after it
+    pub fn kerning(&self, left: char, right: char) -> f32 {
+        self.kerning.get(&(left, right)).copied().unwrap_or(0.0)
+    }
+
+    /// Get the size of the area text covers when laid out
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to measure
+    /// * `style` - The scale, wrapping and line spacing of the text
+    pub fn measure(&self, text: &str, style: &TextStyle) -> Vec2f {
+        let lines = self.lines(text, style.max_width.map(|max_width| max_width / style.scale));
+        let width = lines.iter().map(|(_, width)| *width).fold(0.0, f32::max);
+        let height = self.line_height * (1.0 + (lines.len() - 1) as f32 * style.line_spacing);
+        Vec2f::new(width, height) * style.scale
+    }
+
+    /// Lay out text, getting the area and texture coordinates of each visible glyph
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to lay out, lines are broken at `'\n'`
+    /// * `position` - The top left of the text
+    /// * `style` - The scale, alignment, wrapping and line spacing of the text
+    pub fn layout(&self, text: &str, position: Vec2f, style: &TextStyle) -> Vec<PositionedGlyph> {
+        let scale = style.scale;
+        let lines = self.lines(text, style.max_width.map(|max_width| max_width / scale));
+        let width = style.max_width
+            .unwrap_or_else(|| lines.iter().map(|(_, width)| *width).fold(0.0, f32::max) * scale);
+
+        let mut glyphs = Vec::with_capacity(text.len());
+        for (index, (characters, line_width)) in lines.iter().enumerate() {
+            let mut pen = position.x + match style.align {
+                TextAlign::Left => 0.0,
+                TextAlign::Center => (width - line_width * scale) / 2.0,
+                TextAlign::Right => width - line_width * scale
+            };
+            let baseline = position.y - (self.ascent + index as f32 * self.line_height * style.line_spacing) * scale;
+            let mut previous = None;
+            for &character in characters {
+                let Some(glyph) = self.glyph(character) else { continue; };
+                if let Some(previous) = previous {
+                    pen += self.kerning(previous, character) * scale;
+                }
+                if glyph.sprite.width > 0 && glyph.sprite.height > 0 {
+                    let min = Vec2f::new(pen, baseline) + glyph.offset * scale;
+                    glyphs.push(PositionedGlyph {
+                        character,
+                        bounds: Rect2::new(min, min + glyph.sprite.size() * scale),
+                        uv_min: glyph.sprite.uv_min,
+                        uv_max: glyph.sprite.uv_max,
+                        page: glyph.page
+                    });
+                }
+                pen += glyph.advance * scale;
+                previous = Some(character);
+            }
+        }
+        glyphs
+    }
+
+    /// Get how far the pen moves for a character, 0 if it has no glyph
+    fn advance(&self, character: char) -> f32 {
+        self.glyph(character).map_or(0.0, |glyph| glyph.advance)
+    }
+
+    /// Get the width of a line of characters, unscaled
+    fn width(&self, characters: &[char]) -> f32 {
+        let kerning: f32 = characters.windows(2).map(|pair| self.kerning(pair[0], pair[1])).sum();
+        kerning + characters.iter().map(|&character| self.advance(character)).sum::<f32>()
+    }
+
+    /// Break text into lines at `'\n'`, and between words when wider than the maximum width.\
+    /// Words wider than the maximum are broken between characters
+    ///
+    /// Returns the characters and unscaled width of each line
+    fn lines(&self, text: &str, max_width: Option<f32>) -> Vec<(Vec<char>, f32)> {
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let characters: Vec<char> = paragraph.chars().filter(|&character| character != '\r').collect();
+            let mut start = 0;
+            loop {
+                let mut end = start;
+                let mut width = 0.0;
+                // The last whitespace the line can be broken at
+                let mut space = None;
+                while end < characters.len() {
+                    let character = characters[end];
+                    let mut advance = self.advance(character);
+                    if end > start {
+                        advance += self.kerning(characters[end - 1], character);
+                    }
+                    if character.is_whitespace() {
+                        space = Some(end);
+                    } else if max_width.is_some_and(|max_width| end > start && width + advance > max_width) {
+                        break;
+                    }
+                    width += advance;
+                    end += 1;
+                }
+
+                if end == characters.len() {
+                    let line = characters[start..].to_vec();
+                    let width = self.width(&line);
+                    lines.push((line, width));
+                    break;
+                }
+                // Wrapped lines don't keep the whitespace they were broken at
+                let mut line_end = space.unwrap_or(end);
+                while line_end > start && characters[line_end - 1].is_whitespace() {
+                    line_end -= 1;
+                }
+                let line = characters[start..line_end].to_vec();
+                let width = self.width(&line);
+                lines.push((line, width));
+                start = space.map_or(end, |space| space + 1);
+                while start < characters.len() && characters[start].is_whitespace() {
+                    start += 1;
+                }
+            }
+        }
+        lines
+    }
+}
+
+/// Split a line of a text BMFont into its tag and `key=value` attributes
+fn bmfont_attributes(line: &str) -> Result<(&str, HashMap<&str, &str>)> {
+    let line = line.trim();
+    let (tag, mut rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let mut attributes = HashMap::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let (key, value) = rest.split_once('=')
+            .ok_or_else(|| Error::Font(format!("Expected 'key=value' in '{}'", line)))?;
+        let (value, remaining) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"')
+                .ok_or_else(|| Error::Font(format!("Unterminated quote in '{}'", line)))?,
+            None => value.split_once(char::is_whitespace).unwrap_or((value, ""))
+        };
+        attributes.insert(key, value);
+        rest = remaining;
+    }
+    Ok((tag, attributes))
+}
+
+/// Parse a BMFont attribute
+fn bmfont_value<T: FromStr>(attributes: &HashMap<&str, &str>, tag: &str, key: &str) -> Result<T> {
+    let value = attributes.get(key)
+        .ok_or_else(|| Error::Font(format!("'{}' is missing '{}'", tag, key)))?;
+    value.parse()
+        .map_err(|_| Error::Font(format!("Invalid '{}' of '{}': '{}'", key, tag, value)))
+}
+
+/// Convert a coverage bitmap to a signed distance field, padded by the spread on each side.\
+/// The edge is at half alpha, reaching 0 and 255 at the spread outside and inside it
+#[cfg(feature = "ttf")]
+fn signed_distance_field(coverage: &[u8], width: usize, height: usize, spread: usize) -> Vec<u8> {
+    let inside = |x: isize, y: isize| {
+        x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height
+            && coverage[y as usize * width + x as usize] >= 128
+    };
+    let reach = spread as isize;
+    let mut field = Vec::with_capacity((width + spread * 2) * (height + spread * 2));
+    for y in -reach..height as isize + reach {
+        for x in -reach..width as isize + reach {
+            let is_inside = inside(x, y);
+            // Brute force is fine for glyph sized bitmaps
+            let mut nearest = f32::INFINITY;
+            for dy in -reach..=reach {
+                for dx in -reach..=reach {
+                    if inside(x + dx, y + dy) != is_inside {
+                        nearest = nearest.min((dx * dx + dy * dy) as f32);
+                    }
+                }
+            }
+            // The edge lies between pixel centers
+            let distance = (nearest.sqrt() - 0.5).min(spread as f32);
+            let signed = if is_inside { distance } else { -distance };
+            field.push(((0.5 + signed / (spread as f32 * 2.0)).clamp(0.0, 1.0) * 255.0).round() as u8);
+        }
+    }
+    field
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bmfont_attributes_are_split() {
+        let (tag, attributes) = bmfont_attributes(r#"info face="Pixel Sans" size=-16 bold=0"#).unwrap();
+        assert_eq!(tag, "info");
+        assert_eq!(attributes["face"], "Pixel Sans");
+        assert_eq!(bmfont_value::<f32>(&attributes, tag, "size").unwrap(), -16.0);
+        assert!(bmfont_value::<f32>(&attributes, tag, "face").is_err());
+        assert!(bmfont_attributes("page id=0 file=\"a.png").is_err());
+    }
+
+    #[cfg(feature = "ttf")]
+    #[test]
+    fn distance_fields_are_half_at_edges() {
+        // A 2x2 square, spread 2 pixels
+        let field = signed_distance_field(&[255; 4], 2, 2, 2);
+        assert_eq!(field.len(), 36);
+        let at = |x: usize, y: usize| field[y * 6 + x];
+        assert!(at(2, 2) > 128);
+        assert_eq!(at(1, 2), 96);
+        assert_eq!(at(0, 0), 0);
+    }
+}
\ No newline at end of file
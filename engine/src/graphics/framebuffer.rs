@@ -0,0 +1,240 @@
+use crate::{Error, Result};
+use super::device::{self, FramebufferHandle, RenderbufferHandle, TextureFormat, Attachment, AttachmentTarget};
+use super::texture::{Texture, TextureDescriptor, TextureFilter, TextureWrap};
+
+/// Describes the attachments of a `Framebuffer`
+#[derive(Clone, PartialEq, Debug)]
+pub struct FramebufferDescriptor {
+    /// The width of the attachments
+    pub width: u32,
+    /// The height of the attachments
+    pub height: u32,
+    /// The format of each color attachment, in attachment order
+    pub color_formats: Vec<TextureFormat>,
+    /// The format of the depth attachment, if any
+    pub depth_format: Option<TextureFormat>,
+    /// The number of samples per pixel, 1 disables multisampling
+    pub samples: u32
+}
+
+impl FramebufferDescriptor {
+    /// Creates a new `FramebufferDescriptor` with an RGBA8 color
+    /// attachment, a depth-stencil attachment and no multisampling
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the attachments
+    /// * `height` - The height of the attachments
+    pub fn new(width: u32, height: u32) -> Self {
+        FramebufferDescriptor {
+            width,
+            height,
+            color_formats: vec![TextureFormat::Rgba8],
+            depth_format: Some(TextureFormat::Depth24Stencil8),
+            samples: 1
+        }
+    }
+}
+
+/// A render target with color and depth attachments
+///
+/// Attachments are exposed as textures to sample. When multisampled, rendering
+/// goes to renderbuffers which `resolve` copies into the textures
+pub struct Framebuffer {
+    descriptor: FramebufferDescriptor,
+    handle: FramebufferHandle,
+    color_textures: Vec<Texture>,
+    depth_texture: Option<Texture>,
+    // Only when multisampled
+    resolve_handle: Option<FramebufferHandle>,
+    renderbuffers: Vec<RenderbufferHandle>
+}
+
+impl Framebuffer {
+    /// Creates a new `Framebuffer`
+    ///
+    /// # Arguments
+    ///
+    /// * `descriptor` - The attachments to create
+    ///
+    /// Returns an error if the size or samples are zero, an attachment has
+    /// the wrong kind of format, or the device can't render to the attachments
+    pub fn new(descriptor: FramebufferDescriptor) -> Result<Self> {
+        if descriptor.width == 0 || descriptor.height == 0 {
+            return Err(Error::Framebuffer("Size must be non-zero".to_string()));
+        }
+        if descriptor.samples == 0 {
+            return Err(Error::Framebuffer("Must have at least one sample".to_string()));
+        }
+        if descriptor.color_formats.iter().any(|format| format.is_depth()) {
+            return Err(Error::Framebuffer("Color attachments can't be depth".to_string()));
+        }
+        if descriptor.depth_format.is_some_and(|format| !format.is_depth()) {
+            return Err(Error::Framebuffer("Depth attachment must be depth".to_string()));
+        }
+
+        let (width, height) = (descriptor.width, descriptor.height);
+        let color_textures: Vec<Texture> = descriptor.color_formats.iter()
+            .map(|&format| Texture::empty(width, height, Self::attachment_descriptor(format)))
+            .collect();
+        let depth_texture = descriptor.depth_format
+            .map(|format| Texture::empty(width, height, Self::attachment_descriptor(format)));
+
+        let texture_colors: Vec<Attachment> = color_textures.iter()
+            .zip(&descriptor.color_formats)
+            .map(|(texture, &format)| Attachment { target: AttachmentTarget::Texture(texture.handle()), format })
+            .collect();
+        let texture_depth = depth_texture.as_ref()
+            .zip(descriptor.depth_format)
+            .map(|(texture, format)| Attachment { target: AttachmentTarget::Texture(texture.handle()), format });
+
+        let (handle, resolve_handle, renderbuffers) = device::with_device(|device| {
+            let texture_framebuffer = device.create_framebuffer(&texture_colors, texture_depth)?;
+            if descriptor.samples == 1 {
+                return Ok((texture_framebuffer, None, Vec::new()));
+            }
+
+            // Render to multisampled renderbuffers, resolving into the textures
+            let mut renderbuffers = Vec::new();
+            let mut attach = |format| {
+                let renderbuffer = device.create_renderbuffer(width, height, format, descriptor.samples);
+                renderbuffers.push(renderbuffer);
+                Attachment { target: AttachmentTarget::Renderbuffer(renderbuffer), format }
+            };
+            let colors: Vec<Attachment> = descriptor.color_formats.iter().map(|&format| attach(format)).collect();
+            let depth = descriptor.depth_format.map(attach);
+            match device.create_framebuffer(&colors, depth) {
+                Ok(multisample_framebuffer) => Ok((multisample_framebuffer, Some(texture_framebuffer), renderbuffers)),
+                Err(error) => {
+                    device.destroy_framebuffer(texture_framebuffer);
+                    for renderbuffer in renderbuffers {
+                        device.destroy_renderbuffer(renderbuffer);
+                    }
+                    Err(error)
+                }
+            }
+        })?;
+
+        Ok(Framebuffer { descriptor, handle, color_textures, depth_texture, resolve_handle, renderbuffers })
+    }
+
+    /// Get the descriptor of an attachment texture.\
+    /// Clamped to its edges, depth can't be meaningfully interpolated so is nearest filtered
+    fn attachment_descriptor(format: TextureFormat) -> TextureDescriptor {
+        let filter = if format.is_depth() { TextureFilter::Nearest } else { TextureFilter::Linear };
+        TextureDescriptor {
+            min_filter: filter,
+            mag_filter: filter,
+            wrap_u: TextureWrap::ClampToEdge,
+            wrap_v: TextureWrap::ClampToEdge,
+            ..TextureDescriptor::new(format)
+        }
+    }
+
+    /// Get the descriptor the framebuffer was created with
+    pub fn descriptor(&self) -> &FramebufferDescriptor {
+        &self.descriptor
+    }
+
+    /// Get the width of the framebuffer
+    pub fn width(&self) -> u32 {
+        self.descriptor.width
+    }
+
+    /// Get the height of the framebuffer
+    pub fn height(&self) -> u32 {
+        self.descriptor.height
+    }
+
+    /// Get the number of samples per pixel
+    pub fn samples(&self) -> u32 {
+        self.descriptor.samples
+    }
+
+    /// Get the framebuffer's device handle, the one rendered to
+    pub const fn handle(&self) -> FramebufferHandle {
+        self.handle
+    }
+
+    /// Get the number of color attachments
+    pub fn color_count(&self) -> usize {
+        self.color_textures.len()
+    }
+
+    /// Get a color attachment as a texture.\
+    /// When multisampled, holds the contents as of the last `resolve`
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the color attachment
+    pub fn color_texture(&self, index: usize) -> &Texture {
+        &self.color_textures[index]
+    }
+
+    /// Get the depth attachment as a texture, if any.\
+    /// When multisampled, holds the contents as of the last `resolve`
+    pub fn depth_texture(&self) -> Option<&Texture> {
+        self.depth_texture.as_ref()
+    }
+
+    /// Render to this framebuffer.\
+    /// Sets the viewport to cover the framebuffer
+    pub fn bind(&self) {
+        device::with_device(|device| {
+            device.bind_framebuffer(Some(self.handle));
+            device.set_viewport(0, 0, self.descriptor.width, self.descriptor.height);
+        });
+    }
+
+    /// Render to the default framebuffer.\
+    /// The viewport has to be restored with `Renderer::set_viewport`
+    pub fn unbind() {
+        device::with_device(|device| device.bind_framebuffer(None));
+    }
+
+    /// Copy multisampled attachments into the attachment textures.\
+    /// Does nothing when not multisampled
+    pub fn resolve(&self) {
+        if let Some(resolve_handle) = self.resolve_handle {
+            device::with_device(|device| device.blit_framebuffer(
+                self.handle,
+                resolve_handle,
+                self.descriptor.width,
+                self.descriptor.height,
+                self.color_textures.len() as u32,
+                self.depth_texture.is_some()));
+        }
+    }
+
+    /// Resize the framebuffer, recreating its attachments.\
+    /// Attachment contents are lost and previous texture handles are invalidated
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The new width
+    /// * `height` - The new height
+    ///
+    /// Returns an error if the new attachments can't be created, leaving the framebuffer unchanged
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<()> {
+        if width == self.descriptor.width && height == self.descriptor.height {
+            return Ok(());
+        }
+        let descriptor = FramebufferDescriptor { width, height, ..self.descriptor.clone() };
+        *self = Framebuffer::new(descriptor)?;
+        Ok(())
+    }
+}
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        device::with_device(|device| {
+            device.destroy_framebuffer(self.handle);
+            if let Some(resolve_handle) = self.resolve_handle {
+                device.destroy_framebuffer(resolve_handle);
+            }
+            for &renderbuffer in &self.renderbuffers {
+                device.destroy_renderbuffer(renderbuffer);
+            }
+        });
+    }
+}
\ No newline at end of file
@@ -1,57 +1,40 @@
-use std::mem::size_of;
+use std::mem::size_of_val;
+
+use super::device::{self, BufferHandle, BufferKind, BufferUsage};
 
 /// A buffer of index graphics data
 pub struct IndexBuffer {
-    id: u32
+    handle: BufferHandle
 }
 
 impl IndexBuffer {
     /// Creates a new `IndexBuffer`
     pub fn new() -> Self {
-        let mut id = 0;
-        unsafe {
-            gl::GenBuffers(1, &mut id);
-        }
-        IndexBuffer { id }
-    }
-
-    /// Make this buffer the active `IndexBuffer`
-    pub fn bind(&self) {
-        unsafe {
-            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.id);
-        }
+        let handle = device::with_device(|device| device.create_buffer(BufferKind::Index));
+        IndexBuffer { handle }
     }
 
-    /// Unbind the current `IndexBuffer`
-    pub fn unbind() {
-        unsafe {
-            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, 0);
-        }
+    /// Get the buffer's device handle
+    pub const fn handle(&self) -> BufferHandle {
+        self.handle
     }
 
     /// Set `IndexBuffer`'s data
     /// 
     /// # Arguments
     /// 
-    /// * `data` - A c style void pointer to the data
-    /// * `size` - The size of the data
+    /// * `data` - The indices
     pub fn set_data(&self, data: &Vec<u32>) {
-        self.bind();
-        unsafe {
-            gl::BufferData(
-                gl::ELEMENT_ARRAY_BUFFER,
-                (data.len() * size_of::<u32>()) as isize,
-                data.as_ptr().cast(),
-                gl::STATIC_DRAW
-            );
-        }
+        let size = size_of_val(data.as_slice());
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), size) };
+        device::with_device(|device| {
+            device.set_buffer_data(self.handle, BufferUsage::Static, size, Some(bytes))
+        });
     }
 }
 
 impl Drop for IndexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            gl::DeleteBuffers(1, &self.id)
-        }
+        device::with_device(|device| device.destroy_buffer(self.handle));
     }
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+pub mod device;
+
 pub mod array_buffer;
 pub mod index_buffer;
 pub mod vertex_array;
@@ -5,6 +7,12 @@ pub mod vertex_array;
 pub mod shader;
 
 pub mod texture;
+pub mod texture_atlas;
+pub mod font;
+pub mod framebuffer;
+pub mod color;
 
+pub mod pipeline;
 pub mod renderer;
-pub mod renderer_2d;
\ No newline at end of file
+pub mod renderer_2d;
+pub mod shape_2d;
\ No newline at end of file
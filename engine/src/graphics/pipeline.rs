@@ -0,0 +1,233 @@
+/// A comparison between a new value and a stored value
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompareFunction {
+    Never,
+    Less,
+    Equal,
+    LessEqual,
+    Greater,
+    NotEqual,
+    GreaterEqual,
+    Always
+}
+
+/// What happens to a stored stencil value
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StencilOperation {
+    Keep,
+    Zero,
+    /// Set to the reference value
+    Replace,
+    /// Increment, clamping at the maximum
+    Increment,
+    /// Increment, wrapping to zero
+    IncrementWrap,
+    /// Decrement, clamping at zero
+    Decrement,
+    /// Decrement, wrapping to the maximum
+    DecrementWrap,
+    /// Bitwise invert
+    Invert
+}
+
+/// Which faces of triangles are culled
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CullMode {
+    None,
+    Front,
+    Back
+}
+
+/// The winding order of front facing triangles, as seen on screen
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FrontFace {
+    Clockwise,
+    CounterClockwise
+}
+
+/// How new colors are combined with the framebuffer
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlendMode {
+    /// Replace the framebuffer color
+    Opaque,
+    /// Blend by the new color's alpha
+    Alpha,
+    /// Add the new color, weighted by its alpha
+    Additive,
+    /// Multiply the framebuffer color by the new color
+    Multiply,
+    /// Blend a new color that is already multiplied by its alpha
+    Premultiplied
+}
+
+/// Depth buffer testing and writing
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DepthState {
+    /// Whether fragments are tested against the depth buffer.\
+    /// The depth buffer is not written when disabled
+    pub test: bool,
+    /// Whether passing fragments write their depth
+    pub write: bool,
+    /// How a fragment's depth is compared to the stored depth
+    pub compare: CompareFunction
+}
+
+impl DepthState {
+    /// No depth testing or writing
+    pub const DISABLED: DepthState = DepthState { test: false, write: false, compare: CompareFunction::Always };
+    /// Keep the nearest fragments
+    pub const LESS: DepthState = DepthState { test: true, write: true, compare: CompareFunction::Less };
+    /// Test against the depth buffer without writing, e.g. for transparent geometry
+    pub const READ_ONLY: DepthState = DepthState { test: true, write: false, compare: CompareFunction::LessEqual };
+}
+
+/// Stencil buffer testing and writing, applied to both faces
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StencilState {
+    /// Whether fragments are tested against the stencil buffer
+    pub enabled: bool,
+    /// How the reference value is compared to the stored value
+    pub compare: CompareFunction,
+    /// The value to compare against and write with `StencilOperation::Replace`
+    pub reference: u8,
+    /// The bits compared
+    pub read_mask: u8,
+    /// The bits written
+    pub write_mask: u8,
+    /// What to do when the stencil test fails
+    pub fail: StencilOperation,
+    /// What to do when the stencil test passes but the depth test fails
+    pub depth_fail: StencilOperation,
+    /// What to do when both tests pass
+    pub pass: StencilOperation
+}
+
+impl StencilState {
+    /// No stencil testing
+    pub const DISABLED: StencilState = StencilState {
+        enabled: false,
+        compare: CompareFunction::Always,
+        reference: 0,
+        read_mask: 0xFF,
+        write_mask: 0xFF,
+        fail: StencilOperation::Keep,
+        depth_fail: StencilOperation::Keep,
+        pass: StencilOperation::Keep
+    };
+
+    /// Creates a stencil state that writes `reference` wherever something is drawn
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The value to write
+    pub const fn write(reference: u8) -> Self {
+        StencilState {
+            enabled: true,
+            compare: CompareFunction::Always,
+            reference,
+            pass: StencilOperation::Replace,
+            ..Self::DISABLED
+        }
+    }
+
+    /// Creates a stencil state that only draws where the stencil buffer matches `reference`
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - How `reference` is compared to the stored value
+    /// * `reference` - The value to compare against
+    pub const fn test(compare: CompareFunction, reference: u8) -> Self {
+        StencilState {
+            enabled: true,
+            compare,
+            reference,
+            ..Self::DISABLED
+        }
+    }
+}
+
+/// Which color channels are written
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ColorMask {
+    pub red: bool,
+    pub green: bool,
+    pub blue: bool,
+    pub alpha: bool
+}
+
+impl ColorMask {
+    pub const ALL: ColorMask = ColorMask { red: true, green: true, blue: true, alpha: true };
+    pub const NONE: ColorMask = ColorMask { red: false, green: false, blue: false, alpha: false };
+}
+
+/// A region of the framebuffer in pixels, from the bottom left
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScissorRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32
+}
+
+impl ScissorRect {
+    /// Creates a new `ScissorRect`
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The left of the region
+    /// * `y` - The bottom of the region
+    /// * `width` - The width of the region
+    /// * `height` - The height of the region
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        ScissorRect { x, y, width, height }
+    }
+}
+
+/// Fixed function state used when drawing
+///
+/// Set with `Renderer::set_pipeline_state`, which only sends
+/// the parts that changed since the last state to the device
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PipelineState {
+    pub depth: DepthState,
+    pub stencil: StencilState,
+    pub cull_mode: CullMode,
+    pub front_face: FrontFace,
+    pub blend: BlendMode,
+    /// Only draw inside this region, when set. Also limits clears
+    pub scissor: Option<ScissorRect>,
+    pub color_mask: ColorMask
+}
+
+impl PipelineState {
+    /// Creates a pipeline state for opaque 3D geometry.\
+    /// Depth tested and written, back faces culled
+    pub const fn opaque_3d() -> Self {
+        PipelineState {
+            depth: DepthState::LESS,
+            cull_mode: CullMode::Back,
+            blend: BlendMode::Opaque,
+            ..Self::alpha_2d()
+        }
+    }
+
+    /// Creates a pipeline state for alpha blended 2D drawing.\
+    /// No depth testing or culling
+    pub const fn alpha_2d() -> Self {
+        PipelineState {
+            depth: DepthState::DISABLED,
+            stencil: StencilState::DISABLED,
+            cull_mode: CullMode::None,
+            front_face: FrontFace::CounterClockwise,
+            blend: BlendMode::Alpha,
+            scissor: None,
+            color_mask: ColorMask::ALL
+        }
+    }
+}
+
+impl Default for PipelineState {
+    fn default() -> Self {
+        Self::alpha_2d()
+    }
+}
\ No newline at end of file
@@ -1,22 +1,34 @@
-use crate::math::vec4f::Vec4f;
+use crate::math::vec4::Vec4f;
+use crate::math::mat4f::Mat4f;
+use crate::math::geometry::{aabb::Aabb, frustum::Frustum};
 
+use super::device::{self, ClearMask};
+use super::pipeline::PipelineState;
 use super::vertex_array::VertexArray;
 
-static mut INITIALIZED: bool = false;
-
 pub struct Renderer {}
 
 impl Renderer {
-    /// Initialize renderer config
+    /// Initialize renderer config.\
+    /// Sets the default pipeline state, alpha blended without depth testing
     pub fn init() {
-        unsafe { 
-            if INITIALIZED { return; }
+        device::invalidate_pipeline_state();
+        Self::set_pipeline_state(&PipelineState::default());
+    }
 
-            gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+    /// Set the pipeline state to draw with.\
+    /// Only the parts that changed since the last call are applied
+    /// 
+    /// # Arguments
+    /// 
+    /// * `state` - The pipeline state
+    pub fn set_pipeline_state(state: &PipelineState) {
+        device::set_pipeline_state(state);
+    }
 
-            INITIALIZED = true;
-        }
+    /// Get the pipeline state last set, if any
+    pub fn pipeline_state() -> Option<PipelineState> {
+        device::pipeline_state()
     }
 
     /// Set the rendering viewport
@@ -28,9 +40,7 @@ impl Renderer {
     /// * `width` - The width of the viewport
     /// * `height` - The height of the viewport
     pub fn set_viewport(x: u32, y: u32, width: u32, height: u32) {
-        unsafe {
-            gl::Viewport(x as i32, y as i32, width as i32, height as i32);
-        }
+        device::with_device(|device| device.set_viewport(x, y, width, height));
     }
 
     /// Set the color to clear the screen with
@@ -38,18 +48,77 @@ impl Renderer {
     /// # Arguments
     /// 
     /// * `color` - The clear color (r, g, b ,a)
-    pub fn set_clear_color(color: Vec4f) {
-        unsafe {
-            gl::ClearColor(color.x, color.y, color.z, color.w);
-        }
+    pub fn set_clear_color(color: impl Into<Vec4f>) {
+        let color = color.into();
+        device::with_device(|device| device.set_clear_color(color));
     }
 
-    /// Clear the screen with the clear color
+    /// Set the depth to clear the depth buffer with, 1 by default
+    /// 
+    /// # Arguments
+    /// 
+    /// * `depth` - The clear depth
+    pub fn set_clear_depth(depth: f32) {
+        device::with_device(|device| device.set_clear_depth(depth));
+    }
+
+    /// Set the value to clear the stencil buffer with, 0 by default
+    /// 
+    /// # Arguments
+    /// 
+    /// * `stencil` - The clear stencil value
+    pub fn set_clear_stencil(stencil: u8) {
+        device::with_device(|device| device.set_clear_stencil(stencil));
+    }
+
+    /// Clear the screen with the clear color, depth and stencil value
     pub fn clear() {
-        unsafe {
-            gl::Clear(gl::COLOR_BUFFER_BIT);
-        }
+        Self::clear_buffers(ClearMask::ALL);
+    }
+
+    /// Clear some of the screen's buffers
+    /// 
+    /// # Arguments
+    /// 
+    /// * `mask` - The buffers to clear
+    pub fn clear_buffers(mask: ClearMask) {
+        device::with_device(|device| device.clear(mask));
     }
+
+    /// Read pixels from the bound framebuffer
+    /// 
+    /// Returns contiguous r, g, b, a bytes with rows ordered bottom to top
+    /// 
+    /// # Arguments
+    /// 
+    /// * `x` - The x position of the first pixel
+    /// * `y` - The y position of the first pixel
+    /// * `width` - The width of the region to read
+    /// * `height` - The height of the region to read
+    pub fn read_pixels(x: u32, y: u32, width: u32, height: u32) -> Vec<u8> {
+        device::with_device(|device| device.read_pixels(x, y, width, height))
+    }
+
+    /// Set the view-projection used to cull draws on the current thread
+    /// 
+    /// # Arguments
+    /// 
+    /// * `view_projection` - The view-projection matrix of the camera
+    pub fn set_culling_view_projection(view_projection: Mat4f) {
+        let frustum = Frustum::from_view_projection(&view_projection);
+        device::with_culling(|culling| culling.frustum = Some(frustum));
+    }
+
+    /// Get the number of draws culled on the current thread since the last reset
+    pub fn culled_count() -> u32 {
+        device::with_culling(|culling| culling.culled_count)
+    }
+
+    /// Reset the culled draw counter, typically once per frame
+    pub fn reset_culled_count() {
+        device::with_culling(|culling| culling.culled_count = 0);
+    }
+
     /// Draw number of indices from a vertex array
     /// 
     /// # Arguments
@@ -57,10 +126,31 @@ impl Renderer {
     /// * `vertex_array` - The vertex array to draw
     /// * `count` - The number of indices to draw
     pub fn draw_elements(vertex_array: &VertexArray, count: u32) {
-        vertex_array.bind();
-        unsafe {
-            gl::DrawElements(gl::TRIANGLES, count as i32, gl::UNSIGNED_INT, 0 as *const _)
+        device::with_device(|device| device.draw_indexed(vertex_array.handle(), count));
+    }
+
+    /// Draw number of indices from a vertex array,
+    /// skipping the draw if its bounds are outside the culling view.
+    /// 
+    /// Returns whether the vertex array was drawn
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vertex_array` - The vertex array to draw
+    /// * `count` - The number of indices to draw
+    /// * `bounds` - The world space bounds of the vertex array
+    pub fn draw_elements_culled(vertex_array: &VertexArray, count: u32, bounds: &Aabb) -> bool {
+        let culled = device::with_culling(|culling| {
+            let culled = culling.frustum.is_some_and(|frustum| !frustum.intersects_aabb(bounds));
+            if culled {
+                culling.culled_count += 1;
+            }
+            culled
+        });
+        if culled {
+            return false;
         }
-        VertexArray::unbind();
+        Self::draw_elements(vertex_array, count);
+        true
     }
 }
\ No newline at end of file
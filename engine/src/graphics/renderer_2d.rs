@@ -1,15 +1,23 @@
+use std::cmp::Ordering;
 use std::ffi::CString;
+use std::rc::Rc;
 use std::mem::{size_of_val, size_of};
 
 use crate::graphics::index_buffer::IndexBuffer;
 use crate::graphics::renderer::Renderer;
-use crate::math::{vec2f::Vec2f, vec3f::Vec3f, vec4f::Vec4f};
-use crate::math::mat4f::Mat4f;
+use crate::math::{vec2::Vec2f, vec3::Vec3f, vec4::Vec4f};
+use crate::math::{mat3f::Mat3f, mat4f::Mat4f};
+use crate::math::geometry::{rect2::Rect2, aabb::Aabb, frustum::Frustum};
 use super::array_buffer::{BufferLayout, BufferAttribute, AttributeType, ArrayBuffer};
-use super::texture::Texture;
+use super::device::{self, TextureHandle};
+use super::texture::{Texture, TextureResource};
+use super::font::{Font, TextStyle};
+use super::shape_2d::{self, Quad, Stroke};
 use super::{shader::Shader, vertex_array::VertexArray};
 
 /// Stores a rectangle
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pub position: Vec3f,
     pub size: Vec2f,
@@ -54,14 +62,141 @@ impl Rect {
     pub fn bounds(&self) -> (f32, f32, f32, f32) {
         (self.left(), self.right(), self.top(), self.bottom())
     }
+
+    /// Get bounds as a `Rect2`
+    pub fn rect2(&self) -> Rect2 {
+        Rect2::new(
+            Vec2f::new(self.left(), self.bottom()),
+            Vec2f::new(self.right(), self.top()))
+    }
+
+    /// Check if this rect overlaps another
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.rect2().overlaps(&other.rect2())
+    }
+
+    /// Get the corners, counter-clockwise from the bottom left
+    pub fn corners(&self) -> [Vec2f; 4] {
+        let (left, right, top, bottom) = self.bounds();
+        [
+            Vec2f::new(left, bottom),
+            Vec2f::new(right, bottom),
+            Vec2f::new(right, top),
+            Vec2f::new(left, top)
+        ]
+    }
+
+    /// Get bounds as a flat `Aabb` at the rect's depth
+    pub fn aabb(&self) -> Aabb {
+        let rect = self.rect2();
+        Aabb::new(
+            rect.min.extend(self.position.z),
+            rect.max.extend(self.position.z))
+    }
+}
+
+/// Rotation, scale and flipping of a `Rect` around its pivot
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RectTransform {
+    /// Angle of rotation (in radians), counter-clockwise
+    pub rotation: f32,
+    /// Scale of the rect's size, negative values mirror it
+    pub scale: Vec2f,
+    /// Mirror the texture horizontally
+    pub flip_x: bool,
+    /// Mirror the texture vertically
+    pub flip_y: bool,
+    /// Applied around the pivot after rotation and scale, e.g. to skew
+    pub matrix: Mat3f
+}
+
+impl RectTransform {
+    /// No rotation, scaling or flipping
+    pub const IDENTITY: RectTransform = RectTransform {
+        rotation: 0.0,
+        scale: Vec2f { x: 1.0, y: 1.0 },
+        flip_x: false,
+        flip_y: false,
+        matrix: Mat3f::identity()
+    };
+
+    /// Creates a new `RectTransform` without flipping
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rotation` - Angle of rotation (in radians), counter-clockwise
+    /// * `scale` - Scale of the rect's size
+    pub const fn new(rotation: f32, scale: Vec2f) -> Self {
+        RectTransform { rotation, scale, ..Self::IDENTITY }
+    }
+
+    /// Creates a new `RectTransform` that only rotates
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rotation` - Angle of rotation (in radians), counter-clockwise
+    pub const fn rotated(rotation: f32) -> Self {
+        RectTransform { rotation, ..Self::IDENTITY }
+    }
+
+    /// Creates a new `RectTransform` from an affine matrix applied around the pivot
+    /// 
+    /// # Arguments
+    /// 
+    /// * `matrix` - The 2D transformation
+    pub const fn from_matrix(matrix: Mat3f) -> Self {
+        RectTransform { matrix, ..Self::IDENTITY }
+    }
+
+    /// Get the transform as a matrix around the pivot, without flipping
+    pub fn to_matrix(&self) -> Mat3f {
+        self.matrix * Mat3f::rotate_2d(self.rotation) * Mat3f::scale_2d(self.scale)
+    }
+
+    /// Get the transformed corners of a rect, counter-clockwise from its bottom left
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The rect to transform
+    pub fn corners(&self, rect: &Rect) -> [Vec2f; 4] {
+        let matrix = self.to_matrix();
+        let pivot = rect.position.truncate();
+        rect.corners().map(|corner| pivot + matrix.transform_point_2d(corner - pivot))
+    }
+
+    /// Get the texture coordinates of a rect's corners after flipping
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The rect to get the coordinates of
+    pub fn uvs(&self, rect: &Rect) -> [Vec2f; 4] {
+        let (mut min, mut max) = (rect.uv_min, rect.uv_max);
+        if self.flip_x {
+            std::mem::swap(&mut min.x, &mut max.x);
+        }
+        if self.flip_y {
+            std::mem::swap(&mut min.y, &mut max.y);
+        }
+        [min, Vec2f::new(max.x, min.y), max, Vec2f::new(min.x, max.y)]
+    }
+}
+
+impl Default for RectTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
 }
 
 const MAX_RECTS_IN_BATCH: u32 = 512;
 const MAX_VERTS_IN_BATCH: u32 = MAX_RECTS_IN_BATCH * 4;
 const MAX_INDICES_IN_BATCH: u32 = MAX_RECTS_IN_BATCH * 6;
 const MAX_TEXTURE_SLOTS: u32 = 32;
+/// Added to a texture slot to sample it as a signed distance field
+const SDF_SLOT_FLAG: i32 = MAX_TEXTURE_SLOTS as i32;
 
 #[derive(Clone, Copy)]
+#[repr(C)]
 struct RectVertex {
     position: Vec3f,
     uv: Vec2f,
@@ -85,7 +220,8 @@ impl Default for RectVertex {
 struct RectBatch {
     vertices: [RectVertex; MAX_VERTS_IN_BATCH as usize],
     next_rect: usize,
-    next_texture_slot: usize,
+    // The texture bound to each slot after the default texture's slot 0
+    textures: Vec<TextureHandle>,
 
     vertex_array: VertexArray,
     vertex_buffer: ArrayBuffer,
@@ -127,7 +263,7 @@ impl RectBatch {
         RectBatch { 
             vertices,
             next_rect: 0,
-            next_texture_slot: 1,
+            textures: Vec::with_capacity(MAX_TEXTURE_SLOTS as usize - 1),
             vertex_array,
             vertex_buffer,
             index_buffer
@@ -137,7 +273,37 @@ impl RectBatch {
     /// Reset batch to empty state
     pub fn reset(&mut self) {
         self.next_rect = 0;
-        self.next_texture_slot = 1;
+        self.textures.clear();
+    }
+
+    /// Get the number of rects in the batch
+    pub fn len(&self) -> usize {
+        self.next_rect
+    }
+
+    /// Check if no more rects fit in the batch
+    pub fn is_full(&self) -> bool {
+        self.next_rect == MAX_RECTS_IN_BATCH as usize
+    }
+
+    /// Get the slot a texture is bound to in this batch, binding it to a new slot if needed
+    ///
+    /// Returns `None` if the texture isn't bound and every slot is taken
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The texture to find a slot for
+    pub fn texture_slot(&mut self, handle: TextureHandle) -> Option<i32> {
+        if let Some(index) = self.textures.iter().position(|&bound| bound == handle) {
+            return Some(index as i32 + 1);
+        }
+        if self.textures.len() + 1 == MAX_TEXTURE_SLOTS as usize {
+            return None;
+        }
+        self.textures.push(handle);
+        let slot = self.textures.len() as u32;
+        device::with_device(|device| device.bind_texture(slot, Some(handle)));
+        Some(slot as i32)
     }
 
     /// Draw the batched rectangles
@@ -149,59 +315,80 @@ impl RectBatch {
         Renderer::draw_elements(&self.vertex_array, (self.next_rect * 6) as u32)
     }
 
-    /// Add a rect to the batch
+    /// Add a quad to the batch
     /// 
     /// # Arguments
     /// 
-    /// * `rect` - The rectangle to draw
-    /// * `color` - The color of the rectangle
-    pub fn add_rect(&mut self, rect: Rect, color: Vec4f) {
-        let bounds = rect.bounds();
-
+    /// * `corners` - The corners, counter-clockwise from the bottom left
+    /// * `z` - The depth of the quad
+    /// * `uvs` - The texture coordinates of each corner
+    /// * `slot` - The slot of the quad's texture, from `texture_slot`, or 0 for none.\
+    ///   Offset by `SDF_SLOT_FLAG` for signed distance fields
+    /// * `color` - The color of the quad, tinting its texture
+    pub fn add_quad(&mut self, corners: [Vec2f; 4], z: f32, uvs: [Vec2f; 4], slot: i32, color: Vec4f) {
         let i = self.next_rect * 4;
-        self.vertices[i + 0] = RectVertex::new(
-            Vec3f::new(bounds.0, bounds.3, rect.position.z), rect.uv_min, color, 0);
-        self.vertices[i + 1] = RectVertex::new(
-            Vec3f::new(bounds.1, bounds.3, rect.position.z), Vec2f::new(rect.uv_max.x, rect.uv_min.y), color, 0);
-        self.vertices[i + 2] = RectVertex::new(
-            Vec3f::new(bounds.1, bounds.2, rect.position.z), rect.uv_max, color, 0);
-        self.vertices[i + 3] = RectVertex::new(
-            Vec3f::new(bounds.0, bounds.2, rect.position.z), Vec2f::new(rect.uv_min.x, rect.uv_max.y), color, 0);
+        for (vertex, (corner, uv)) in self.vertices[i..i + 4].iter_mut().zip(corners.into_iter().zip(uvs)) {
+            *vertex = RectVertex::new(corner.extend(z), uv, color, slot);
+        }
         self.next_rect += 1;
     }
+}
 
-    /// Add a textured rectangle to the batch
+/// Where draws are ordered in a `Renderer2D` batch, lower first
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct SortLayer {
+    /// The layer, e.g. background, characters and UI
+    pub layer: i32,
+    /// The order within the layer
+    pub order: i32
+}
+
+impl SortLayer {
+    /// Creates a new `SortLayer`
     /// 
     /// # Arguments
     /// 
-    /// * `rect` - The rectangle to draw
-    /// * `texture` - The texture of the rectangle
-    /// * `tint` - The tint color of the texture
-    pub fn add_textured_rect(&mut self, rect: Rect, texture: &Texture, tint: Vec4f) {
-        let bounds = rect.bounds();
-
-        let i = self.next_rect * 4;
-        let slot = self.next_texture_slot as i32;
-        self.vertices[i + 0] = RectVertex::new(
-            Vec3f::new(bounds.0, bounds.3, rect.position.z), rect.uv_min, tint, slot);
-        self.vertices[i + 1] = RectVertex::new(
-            Vec3f::new(bounds.1, bounds.3, rect.position.z), Vec2f::new(rect.uv_max.x, rect.uv_min.y), tint, slot);
-        self.vertices[i + 2] = RectVertex::new(
-            Vec3f::new(bounds.1, bounds.2, rect.position.z), rect.uv_max, tint, self.next_texture_slot as i32);
-        self.vertices[i + 3] = RectVertex::new(
-            Vec3f::new(bounds.0, bounds.2, rect.position.z), Vec2f::new(rect.uv_min.x, rect.uv_max.y), tint, slot);
-        self.next_rect += 1;
-        
-        texture.bind_to_slot(self.next_texture_slot as u32);
-        self.next_texture_slot += 1;
+    /// * `layer` - The layer, e.g. background, characters and UI
+    /// * `order` - The order within the layer
+    pub const fn new(layer: i32, order: i32) -> Self {
+        SortLayer { layer, order }
     }
 }
 
+/// A quad waiting in a batch to be sorted and drawn
+struct QueuedQuad {
+    layer: SortLayer,
+    // The bottom of the draw the quad is part of, for y sorting
+    y: f32,
+    // Shared so the texture outlives the quad even if its owner is dropped before the flush
+    texture: Option<Rc<TextureResource>>,
+    sdf: bool,
+    corners: Quad,
+    z: f32,
+    uvs: [Vec2f; 4],
+    color: Vec4f
+}
+
+/// Draw counts of a `Renderer2D`, since they were last reset
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Renderer2DStats {
+    /// The number of draw calls made
+    pub draw_calls: u32,
+    /// The number of rects drawn
+    pub quads: u32
+}
+
 /// Renderer for 2D graphics
 pub struct Renderer2D {
     default_shader: Shader,
     default_texture: Texture,
-    rect_batch: RectBatch
+    rect_batch: RectBatch,
+    queue: Vec<QueuedQuad>,
+    sort_layer: SortLayer,
+    y_sort: bool,
+    view_frustum: Frustum,
+    culled_count: u32,
+    stats: Renderer2DStats
 }
 
 impl Renderer2D {
@@ -240,16 +427,62 @@ impl Renderer2D {
         out vec4 f_out_color;
 
         uniform sampler2D u_textures[32];
+
+        // Sampler arrays may only be indexed by constants in GLSL 330
+        vec4 sample_slot(int slot, vec2 uv) {
+            switch (slot) {
+                case 0: return texture(u_textures[0], uv);
+                case 1: return texture(u_textures[1], uv);
+                case 2: return texture(u_textures[2], uv);
+                case 3: return texture(u_textures[3], uv);
+                case 4: return texture(u_textures[4], uv);
+                case 5: return texture(u_textures[5], uv);
+                case 6: return texture(u_textures[6], uv);
+                case 7: return texture(u_textures[7], uv);
+                case 8: return texture(u_textures[8], uv);
+                case 9: return texture(u_textures[9], uv);
+                case 10: return texture(u_textures[10], uv);
+                case 11: return texture(u_textures[11], uv);
+                case 12: return texture(u_textures[12], uv);
+                case 13: return texture(u_textures[13], uv);
+                case 14: return texture(u_textures[14], uv);
+                case 15: return texture(u_textures[15], uv);
+                case 16: return texture(u_textures[16], uv);
+                case 17: return texture(u_textures[17], uv);
+                case 18: return texture(u_textures[18], uv);
+                case 19: return texture(u_textures[19], uv);
+                case 20: return texture(u_textures[20], uv);
+                case 21: return texture(u_textures[21], uv);
+                case 22: return texture(u_textures[22], uv);
+                case 23: return texture(u_textures[23], uv);
+                case 24: return texture(u_textures[24], uv);
+                case 25: return texture(u_textures[25], uv);
+                case 26: return texture(u_textures[26], uv);
+                case 27: return texture(u_textures[27], uv);
+                case 28: return texture(u_textures[28], uv);
+                case 29: return texture(u_textures[29], uv);
+                case 30: return texture(u_textures[30], uv);
+                case 31: return texture(u_textures[31], uv);
+            }
+            return vec4(1.0);
+        }
     
         void main() {
-            f_out_color = texture(u_textures[v_out_tex_slot], v_out_uv) * v_out_color;
+            vec4 texel = sample_slot(v_out_tex_slot % 32, v_out_uv);
+            // Slots past the last hold signed distance fields, with the edge at half alpha
+            float smoothing = max(fwidth(texel.a) * 0.5, 0.0001);
+            if (v_out_tex_slot >= 32) {
+                texel = vec4(1.0, 1.0, 1.0, smoothstep(0.5 - smoothing, 0.5 + smoothing, texel.a));
+            }
+            f_out_color = texel * v_out_color;
         }
         "#;
     
+        // The built in shader failing is a bug, not something to recover from
         let default_shader = Shader::new(
             VERTEX_SHADER, 
             FRAGMENT_SHADER
-        );
+        ).unwrap_or_else(|error| panic!("{}", error));
         default_shader.bind();
         default_shader.set_mat4f(&CString::new("u_view_projection").unwrap(), view_projection);
         let texture_slots: Vec<i32> = (0..MAX_TEXTURE_SLOTS as i32).collect();
@@ -261,22 +494,142 @@ impl Renderer2D {
         Renderer2D {
             default_shader,
             default_texture,
-            rect_batch: RectBatch::new()
+            rect_batch: RectBatch::new(),
+            queue: Vec::new(),
+            sort_layer: SortLayer::default(),
+            y_sort: false,
+            view_frustum: Frustum::from_view_projection(&view_projection),
+            culled_count: 0,
+            stats: Renderer2DStats::default()
+        }
+    }
+
+    /// Set the view projection matrix
+    /// 
+    /// # Arguments
+    /// 
+    /// * `view_projection` - The view projection matrix to use
+    pub fn set_view_projection(&mut self, view_projection: Mat4f) {
+        self.default_shader.bind();
+        self.default_shader.set_mat4f(&CString::new("u_view_projection").unwrap(), view_projection);
+        self.view_frustum = Frustum::from_view_projection(&view_projection);
+    }
+
+    /// Get the number of rects and shapes culled in the current batch
+    pub fn culled_count(&self) -> u32 {
+        self.culled_count
+    }
+
+    /// Get the draw calls and rects drawn since the last `reset_stats`,
+    /// e.g. each frame
+    pub fn stats(&self) -> Renderer2DStats {
+        self.stats
+    }
+
+    /// Reset the draw counts, e.g. at the start of a frame
+    pub fn reset_stats(&mut self) {
+        self.stats = Renderer2DStats::default();
+    }
+
+    /// Check if something is visible by its bounds, counting it as culled if not
+    fn cull(&mut self, aabb: &Aabb) -> bool {
+        if self.view_frustum.intersects_aabb(aabb) {
+            return false;
         }
+        self.culled_count += 1;
+        true
+    }
+
+    /// Set the layer and order of the following draws in the batch.\
+    /// Reset to the default by `begin_batch`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `layer` - The layer and order to draw in
+    pub fn set_sort_layer(&mut self, layer: SortLayer) {
+        self.sort_layer = layer;
+    }
+
+    /// Get the layer and order draws are added to the batch with
+    pub const fn sort_layer(&self) -> SortLayer {
+        self.sort_layer
+    }
+
+    /// Set whether draws with the same layer, order and texture are sorted by the bottom
+    /// of their bounds, highest first, so lower sprites overlap higher ones in top-down views
+    /// 
+    /// # Arguments
+    /// 
+    /// * `y_sort` - Whether to sort by y
+    pub fn set_y_sort(&mut self, y_sort: bool) {
+        self.y_sort = y_sort;
     }
 
     /// Begin a new batch
     /// 
     /// **IMPORTANT**: no other drawing functions should be bound until end batch
     pub fn begin_batch(&mut self) {
+        self.queue.clear();
         self.rect_batch.reset();
-        self.default_texture.bind_to_slot(0);
+        self.sort_layer = SortLayer::default();
+        self.culled_count = 0;
     }
 
     /// End and draw the batch
-    pub fn end_batch(&self) {
-        self.default_shader.bind();
-        self.rect_batch.draw();
+    pub fn end_batch(&mut self) {
+        self.flush();
+    }
+
+    /// Sort and draw everything batched so far, then start a new batch.\
+    /// Draws are stably sorted by layer, order, texture, then y if y sorting,
+    /// and split into as few draw calls as rect and texture slot limits allow.
+    /// Anything batched afterwards is drawn over them, whatever its layer
+    pub fn flush(&mut self) {
+        if self.queue.is_empty() {
+            return;
+        }
+        let mut queue = std::mem::take(&mut self.queue);
+        let y_sort = self.y_sort;
+        queue.sort_by(|a, b| {
+            let texture = |quad: &QueuedQuad| quad.texture.as_ref().map(|texture| texture.handle());
+            a.layer.cmp(&b.layer)
+                .then_with(|| texture(a).cmp(&texture(b)))
+                .then_with(|| if y_sort { b.y.total_cmp(&a.y) } else { Ordering::Equal })
+        });
+
+        self.default_texture.bind_to_slot(0);
+        for quad in &queue {
+            if self.rect_batch.is_full() {
+                self.draw_batch();
+            }
+            let texture = quad.texture.as_ref().map(|texture| texture.handle());
+            let slot = match texture.map(|texture| (texture, self.rect_batch.texture_slot(texture))) {
+                None => 0,
+                Some((_, Some(slot))) => slot,
+                Some((texture, None)) => {
+                    self.draw_batch();
+                    self.rect_batch.texture_slot(texture).unwrap()
+                }
+            };
+            let slot = if quad.sdf { slot + SDF_SLOT_FLAG } else { slot };
+            self.rect_batch.add_quad(quad.corners, quad.z, quad.uvs, slot, quad.color);
+        }
+        self.draw_batch();
+        // Textures dropped while batched are only destroyed once drawn,
+        // the allocation is kept for the next batch
+        queue.clear();
+        self.queue = queue;
+    }
+
+    /// Draw the rects in the batch and empty it
+    fn draw_batch(&mut self) {
+        if self.rect_batch.len() > 0 {
+            self.default_shader.bind();
+            self.rect_batch.draw();
+            self.stats.draw_calls += 1;
+            self.stats.quads += self.rect_batch.len() as u32;
+        }
+        self.rect_batch.reset();
     }
 
     /// Draw a rect
@@ -285,8 +638,10 @@ impl Renderer2D {
     /// 
     /// * `rect` - The rect to draw
     /// * `color` - The color to draw with
-    pub fn draw_rect(&self, rect: Rect, color: Vec4f) {
-        self.draw_textured_rect(rect, &self.default_texture, color);
+    pub fn draw_rect(&mut self, rect: Rect, color: impl Into<Vec4f>) {
+        self.draw_single(rect, &self.default_texture, color.into());
+        self.stats.draw_calls += 1;
+        self.stats.quads += 1;
     }
 
     /// Draw a textured rect
@@ -296,7 +651,14 @@ impl Renderer2D {
     /// * `rect` - The rect to draw
     /// * `texture` - The texture to draw
     /// * `tint` - The color to tint the texture
-    pub fn draw_textured_rect(&self, rect: Rect, texture: &Texture, tint: Vec4f) {
+    pub fn draw_textured_rect(&mut self, rect: Rect, texture: &Texture, tint: impl Into<Vec4f>) {
+        self.draw_single(rect, texture, tint.into());
+        self.stats.draw_calls += 1;
+        self.stats.quads += 1;
+    }
+
+    /// Draw one textured rect with its own draw call
+    fn draw_single(&self, rect: Rect, texture: &Texture, tint: Vec4f) {
         let vertex_array = VertexArray::new();
         
         let bounds = rect.bounds();
@@ -331,11 +693,265 @@ impl Renderer2D {
         Renderer::draw_elements(&vertex_array, 6);
     }
 
-    pub fn batch_rect(&mut self, rect: Rect, color: Vec4f) {
-        self.rect_batch.add_rect(rect, color);
+    /// Add a rect to the batch at the current sort layer
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The rect to draw
+    /// * `color` - The color to draw with
+    pub fn batch_rect(&mut self, rect: Rect, color: impl Into<Vec4f>) {
+        self.batch_quad(&rect, &RectTransform::IDENTITY, None, color.into());
+    }
+
+    /// Add a textured rect to the batch at the current sort layer.\
+    /// Rects with the same texture share its slot
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The rect to draw
+    /// * `texture` - The texture to draw
+    /// * `tint` - The color to tint the texture
+    pub fn batch_textured_rect(&mut self, rect: Rect, texture: &Texture, tint: impl Into<Vec4f>) {
+        self.batch_quad(&rect, &RectTransform::IDENTITY, Some(texture), tint.into());
+    }
+
+    /// Add a rotated, scaled or flipped rect to the batch.\
+    /// The transform is applied around the rect's pivot
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The rect to draw
+    /// * `transform` - The transform of the rect
+    /// * `color` - The color to draw with
+    pub fn batch_transformed_rect(&mut self, rect: Rect, transform: &RectTransform, color: impl Into<Vec4f>) {
+        self.batch_quad(&rect, transform, None, color.into());
+    }
+
+    /// Add a rotated, scaled or flipped textured rect to the batch.\
+    /// The transform is applied around the rect's pivot
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The rect to draw
+    /// * `transform` - The transform of the rect
+    /// * `texture` - The texture to draw
+    /// * `tint` - The color to tint the texture
+    pub fn batch_transformed_textured_rect(&mut self, rect: Rect, transform: &RectTransform, texture: &Texture, tint: impl Into<Vec4f>) {
+        self.batch_quad(&rect, transform, Some(texture), tint.into());
+    }
+
+    /// Transform a rect into the batch
+    fn batch_quad(&mut self, rect: &Rect, transform: &RectTransform, texture: Option<&Texture>, color: Vec4f) {
+        self.batch_corners(transform.corners(rect), rect.position.z, transform.uvs(rect), texture, color);
+    }
+
+    /// Add a quad to the batch unless culled
+    fn batch_corners(&mut self, corners: [Vec2f; 4], z: f32, uvs: [Vec2f; 4], texture: Option<&Texture>, color: Vec4f) {
+        let aabb = Aabb::from_points(corners.map(|corner| corner.extend(z))).unwrap();
+        if self.cull(&aabb) { return; }
+        self.queue_quad(corners, z, uvs, texture, false, color, aabb.min.y);
+    }
+
+    /// Add a quad to the batch at the current sort layer.\
+    /// The texture is kept alive until the batch is flushed
+    #[allow(clippy::too_many_arguments)]
+    fn queue_quad(&mut self, corners: Quad, z: f32, uvs: [Vec2f; 4], texture: Option<&Texture>, sdf: bool, color: Vec4f, y: f32) {
+        self.queue.push(QueuedQuad {
+            layer: self.sort_layer,
+            y,
+            texture: texture.map(Texture::share),
+            sdf,
+            corners,
+            z,
+            uvs,
+            color
+        });
+    }
+
+    /// Add text to the batch, a quad per visible glyph.\
+    /// Glyphs are culled individually, and y sorted by the bottom of the whole text
+    /// 
+    /// # Arguments
+    /// 
+    /// * `font` - The font to draw with
+    /// * `text` - The text to draw, lines are broken at `'\n'`
+    /// * `position` - The top left of the text, and its depth
+    /// * `style` - The scale, alignment, wrapping and line spacing of the text
+    /// * `color` - The color of the text
+    pub fn batch_text(&mut self, font: &Font, text: &str, position: Vec3f, style: &TextStyle, color: impl Into<Vec4f>) {
+        let color = color.into();
+        let glyphs = font.layout(text, position.truncate(), style);
+        let bottom = glyphs.iter().map(|glyph| glyph.bounds.min.y).fold(f32::INFINITY, f32::min);
+        for glyph in glyphs {
+            let (min, max) = (glyph.bounds.min, glyph.bounds.max);
+            if self.cull(&Aabb::new(min.extend(position.z), max.extend(position.z))) { continue; }
+            let corners = [min, Vec2f::new(max.x, min.y), max, Vec2f::new(min.x, max.y)];
+            let (uv_min, uv_max) = (glyph.uv_min, glyph.uv_max);
+            let uvs = [uv_min, Vec2f::new(uv_max.x, uv_min.y), uv_max, Vec2f::new(uv_min.x, uv_max.y)];
+            self.queue_quad(corners, position.z, uvs, Some(&font.pages()[glyph.page]), font.is_sdf(), color, bottom);
+        }
+    }
+
+    /// Draw text, flushing the batch first so what was batched before is drawn under it
+    /// 
+    /// # Arguments
+    /// 
+    /// * `font` - The font to draw with
+    /// * `text` - The text to draw, lines are broken at `'\n'`
+    /// * `position` - The top left of the text, and its depth
+    /// * `style` - The scale, alignment, wrapping and line spacing of the text
+    /// * `color` - The color of the text
+    pub fn draw_text(&mut self, font: &Font, text: &str, position: Vec3f, style: &TextStyle, color: impl Into<Vec4f>) {
+        self.flush();
+        self.batch_text(font, text, position, style, color);
+        self.flush();
+    }
+
+    /// Add a line to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `start` - The start of the line
+    /// * `end` - The end of the line
+    /// * `z` - The depth of the line
+    /// * `stroke` - The width, caps and joins of the line
+    /// * `color` - The color to draw with
+    pub fn batch_line(&mut self, start: Vec2f, end: Vec2f, z: f32, stroke: &Stroke, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::line(start, end, stroke), z, color.into());
+    }
+
+    /// Add a line through a list of points to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `points` - The points the line passes through
+    /// * `closed` - Whether the last point connects back to the first
+    /// * `z` - The depth of the line
+    /// * `stroke` - The width, caps and joins of the line
+    /// * `color` - The color to draw with
+    pub fn batch_polyline(&mut self, points: &[Vec2f], closed: bool, z: f32, stroke: &Stroke, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::polyline(points, closed, stroke), z, color.into());
+    }
+
+    /// Add a filled triangle to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `corners` - The corners of the triangle
+    /// * `z` - The depth of the triangle
+    /// * `color` - The color to draw with
+    pub fn batch_triangle(&mut self, corners: [Vec2f; 3], z: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::triangle(corners), z, color.into());
+    }
+
+    /// Add a filled convex polygon to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `points` - The corners of the polygon, in order around it
+    /// * `z` - The depth of the polygon
+    /// * `color` - The color to draw with
+    pub fn batch_polygon(&mut self, points: &[Vec2f], z: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::convex_polygon(points), z, color.into());
+    }
+
+    /// Add a filled circle to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the circle
+    /// * `radius` - The radius of the circle
+    /// * `z` - The depth of the circle
+    /// * `color` - The color to draw with
+    pub fn batch_circle(&mut self, center: Vec2f, radius: f32, z: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::ellipse(center, Vec2f::splat(radius)), z, color.into());
+    }
+
+    /// Add the outline of a circle to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the circle
+    /// * `radius` - The radius of the circle, the outline is centered on it
+    /// * `z` - The depth of the circle
+    /// * `width` - The width of the outline
+    /// * `color` - The color to draw with
+    pub fn batch_circle_outline(&mut self, center: Vec2f, radius: f32, z: f32, width: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::ellipse_outline(center, Vec2f::splat(radius), width), z, color.into());
+    }
+
+    /// Add a filled ellipse to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the ellipse
+    /// * `radii` - The horizontal and vertical radius
+    /// * `z` - The depth of the ellipse
+    /// * `color` - The color to draw with
+    pub fn batch_ellipse(&mut self, center: Vec2f, radii: Vec2f, z: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::ellipse(center, radii), z, color.into());
+    }
+
+    /// Add the outline of an ellipse to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the ellipse
+    /// * `radii` - The horizontal and vertical radius, the outline is centered on it
+    /// * `z` - The depth of the ellipse
+    /// * `width` - The width of the outline
+    /// * `color` - The color to draw with
+    pub fn batch_ellipse_outline(&mut self, center: Vec2f, radii: Vec2f, z: f32, width: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::ellipse_outline(center, radii, width), z, color.into());
+    }
+
+    /// Add a circular arc to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the circle
+    /// * `radius` - The radius of the circle, the line is centered on it
+    /// * `start_angle` - The angle the arc starts at (in radians), counter-clockwise from the x axis
+    /// * `sweep` - The angle the arc covers (in radians), counter-clockwise when positive
+    /// * `z` - The depth of the arc
+    /// * `stroke` - The width, caps and joins of the line
+    /// * `color` - The color to draw with
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_arc(&mut self, center: Vec2f, radius: f32, start_angle: f32, sweep: f32, z: f32, stroke: &Stroke, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::arc(center, radius, start_angle, sweep, stroke), z, color.into());
+    }
+
+    /// Add a filled rounded rect to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The bounds and depth of the rect, its texture coordinates are unused
+    /// * `radius` - The radius of the corners, limited to half the shortest side
+    /// * `color` - The color to draw with
+    pub fn batch_rounded_rect(&mut self, rect: Rect, radius: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::rounded_rect(&rect.rect2(), radius), rect.position.z, color.into());
     }
 
-    pub fn batch_textured_rect(&mut self, rect: Rect, texture: &Texture, tint: Vec4f) {
-        self.rect_batch.add_textured_rect(rect, texture, tint);
+    /// Add the outline of a rounded rect to the batch
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rect` - The bounds and depth of the rect, the outline is centered on its edges
+    /// * `radius` - The radius of the corners, limited to half the shortest side
+    /// * `width` - The width of the outline
+    /// * `color` - The color to draw with
+    pub fn batch_rounded_rect_outline(&mut self, rect: Rect, radius: f32, width: f32, color: impl Into<Vec4f>) {
+        self.batch_quads(shape_2d::rounded_rect_outline(&rect.rect2(), radius, width), rect.position.z, color.into());
+    }
+
+    /// Add untextured quads to the batch, culled as one shape
+    fn batch_quads(&mut self, quads: Vec<Quad>, z: f32, color: Vec4f) {
+        let Some(aabb) = Aabb::from_points(quads.iter().flatten().map(|point| point.extend(z))) else {
+            return;
+        };
+        if self.cull(&aabb) { return; }
+        for quad in quads {
+            self.queue_quad(quad, z, [Vec2f::zero(); 4], None, false, color, aabb.min.y);
+        }
     }
 }
\ No newline at end of file
@@ -1,9 +1,54 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::CString;
+use std::fmt;
 
-use crate::math::{vec2f::Vec2f, vec3f::Vec3f, vec4f::Vec4f, mat4f::Mat4f};
+use super::device::{self, ShaderHandle, UniformValue};
+use crate::Result;
+use crate::math::{vec2::Vec2f, vec3::Vec3f, vec4::Vec4f, mat2f::Mat2f, mat3f::Mat3f, mat4f::Mat4f};
+
+/// A programmable stage of a `Shader`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShaderStage {
+    Vertex,
+    Fragment
+}
+
+impl fmt::Display for ShaderStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaderStage::Vertex => write!(f, "Vertex"),
+            ShaderStage::Fragment => write!(f, "Fragment")
+        }
+    }
+}
+
+/// Get the source lines a shader info log reports errors on, ascending
+///
+/// Understands the common driver formats, e.g. `0:12(5): error` (Mesa),
+/// `0(12) : error` (NVIDIA) and `ERROR: 0:12:` (AMD, Intel)
+///
+/// # Arguments
+///
+/// * `log` - The info log
+pub(crate) fn error_lines(log: &str) -> Vec<u32> {
+    let mut lines: Vec<u32> = log.lines().filter_map(|entry| {
+        // The first number is the source string index, the line follows it
+        let start = entry.find(|c: char| c.is_ascii_digit())?;
+        let rest = entry[start..].trim_start_matches(|c: char| c.is_ascii_digit());
+        let rest = rest.strip_prefix(':').or_else(|| rest.strip_prefix('('))?;
+        let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        rest[..end].parse().ok()
+    }).collect();
+    lines.sort_unstable();
+    lines.dedup();
+    lines
+}
 
 pub struct Shader {
-    program: u32
+    handle: ShaderHandle,
+    /// The arrays last set by `set_int_array`, so unchanged arrays aren't copied and uploaded again
+    int_arrays: RefCell<HashMap<CString, Vec<i32>>>
 }
 
 impl Shader {
@@ -13,102 +58,31 @@ impl Shader {
     /// 
     /// * `vertex_source` - The source code of the vertex shader
     /// * `fragment_source` - The source code of the fragment shader
-    pub fn new(vertex_source: &str, fragment_source: &str) -> Self {
-        let program: u32;
-
-        unsafe {
-            // Vertex shader
-            let vertex_shader = gl::CreateShader(gl::VERTEX_SHADER);
-            assert_ne!(vertex_shader, 0);
-            gl::ShaderSource(
-                vertex_shader,
-                1,
-                &(vertex_source.as_bytes().as_ptr().cast()),
-                &(vertex_source.len().try_into().unwrap())
-            );
-            gl::CompileShader(vertex_shader);
-
-            let mut success = 0;
-            gl::GetShaderiv(vertex_shader, gl::COMPILE_STATUS, &mut success);
-            if success == 0 {
-                let mut v: Vec<u8> = Vec::with_capacity(1024);
-                let mut log_len = 0;
-                gl::GetShaderInfoLog(
-                    vertex_shader,
-                    1024,
-                    &mut log_len,
-                    v.as_mut_ptr().cast(),
-                );
-                v.set_len(log_len.try_into().unwrap());
-                panic!("Vertex shader compile error: {}", String::from_utf8_lossy(&v));
-            }
-
-            // Fragment shader
-            let fragment_shader = gl::CreateShader(gl::FRAGMENT_SHADER);
-            assert_ne!(fragment_shader, 0);
-            gl::ShaderSource(
-                fragment_shader,
-                1,
-                &(fragment_source.as_bytes().as_ptr().cast()),
-                &(fragment_source.len().try_into().unwrap())
-            );
-            gl::CompileShader(fragment_shader);
-
-            let mut success = 0;
-            gl::GetShaderiv(fragment_shader, gl::COMPILE_STATUS, &mut success);
-            if success == 0 {
-                let mut v: Vec<u8> = Vec::with_capacity(1024);
-                let mut log_len = 0;
-                gl::GetShaderInfoLog(
-                    vertex_shader,
-                    1024,
-                    &mut log_len,
-                    v.as_mut_ptr().cast(),
-                );
-                v.set_len(log_len.try_into().unwrap());
-                panic!("Fragment shader compile error: {}", String::from_utf8_lossy(&v));
-            }
-
-            // Program
-            program = gl::CreateProgram();
-            assert_ne!(program, 0);
-            gl::AttachShader(program, vertex_shader);
-            gl::AttachShader(program, fragment_shader);
-            gl::LinkProgram(program);
-
-            let mut success = 0;
-            gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
-            if success == 0 {
-                let mut v: Vec<u8> = Vec::with_capacity(1024);
-                let mut log_len = 0_i32;
-                gl::GetProgramInfoLog(
-                    program,
-                    1024,
-                    &mut log_len,
-                    v.as_mut_ptr().cast(),
-                );
-                v.set_len(log_len.try_into().unwrap());
-                panic!("Program link error: {}", String::from_utf8_lossy(&v));
-            }
-    
-            gl::DeleteShader(vertex_shader);
-            gl::DeleteShader(fragment_shader);
-        }
-        Shader { program }
+    ///
+    /// Returns an error with the driver's log if a stage fails to compile or the program fails to link
+    pub fn new(vertex_source: &str, fragment_source: &str) -> Result<Self> {
+        let handle = device::with_device(|device| device.create_shader(vertex_source, fragment_source))?;
+        Ok(Shader { handle, int_arrays: RefCell::new(HashMap::new()) })
+    }
+
+    /// Get the shader's device handle
+    pub const fn handle(&self) -> ShaderHandle {
+        self.handle
     }
 
     /// Make this shader the active `Shader`
     pub fn bind(&self) {
-        unsafe {
-            gl::UseProgram(self.program);
-        }
+        device::with_device(|device| device.bind_shader(Some(self.handle)));
     }
 
     /// Unbind the current `Shader`
     pub fn unbind() {
-        unsafe {
-            gl::UseProgram(0);
-        }
+        device::with_device(|device| device.bind_shader(None));
+    }
+
+    /// Set a shader variable
+    fn set_uniform(&self, name: &CString, value: UniformValue) {
+        device::with_device(|device| device.set_uniform(self.handle, name, value));
     }
 
     /// Set an integer shader variable
@@ -118,13 +92,7 @@ impl Shader {
     /// * `name` - The name of the variable
     /// * `val` - The int value to set
     pub fn set_int(&self, name: &CString, val: i32) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::Uniform1i(location, val);
-        }
+        self.set_uniform(name, UniformValue::Int(val));
     }
 
     /// Set an integer array shader variable
@@ -133,14 +101,16 @@ impl Shader {
     /// 
     /// * `name` - The name of the variable
     /// * `vals` - The int values to set
+    ///
+    /// Does nothing if the variable already holds `vals`
     pub fn set_int_array(&self, name: &CString, vals: &Vec<i32>) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::Uniform1iv(location, vals.len() as i32, vals.as_ptr().cast());
+        let mut int_arrays = self.int_arrays.borrow_mut();
+        if int_arrays.get(name).is_some_and(|cached| cached == vals) {
+            return;
         }
+        int_arrays.insert(name.clone(), vals.clone());
+        drop(int_arrays);
+        self.set_uniform(name, UniformValue::IntArray(vals.clone()));
     }
 
     /// Set a float shader variable
@@ -150,13 +120,7 @@ impl Shader {
     /// * `name` - The name of the variable
     /// * `val` - The float value to set
     pub fn set_float(&self, name: &CString, val: f32) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::Uniform1f(location, val);
-        }
+        self.set_uniform(name, UniformValue::Float(val));
     }
 
     /// Set a 2D vector shader variable
@@ -166,13 +130,7 @@ impl Shader {
     /// * `name` - The name of the variable
     /// * `val` - The `Vec2f` value to set
     pub fn set_vec2f(&self, name: &CString, val: Vec2f) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::Uniform2f(location, val.x, val.y);
-        }
+        self.set_uniform(name, UniformValue::Vec2f(val));
     }
 
     /// Set a 3D vector shader variable
@@ -182,13 +140,7 @@ impl Shader {
     /// * `name` - The name of the variable
     /// * `val` - The `Vec3f` value to set
     pub fn set_vec3f(&self, name: &CString, val: Vec3f) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::Uniform3f(location, val.x, val.y, val.z);
-        }
+        self.set_uniform(name, UniformValue::Vec3f(val));
     }
 
     /// Set a 4D vector shader variable
@@ -198,13 +150,27 @@ impl Shader {
     /// * `name` - The name of the variable
     /// * `val` - The `Vec4f` value to set
     pub fn set_vec4f(&self, name: &CString, val: Vec4f) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::Uniform4f(location, val.x, val.y, val.z, val.w);
-        }
+        self.set_uniform(name, UniformValue::Vec4f(val));
+    }
+
+    /// Set a 2x2 matrix shader variable
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The name of the variable
+    /// * `val` - The `Mat2f` value to set
+    pub fn set_mat2f(&self, name: &CString, val: Mat2f) {
+        self.set_uniform(name, UniformValue::Mat2f(val));
+    }
+
+    /// Set a 3x3 matrix shader variable
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - The name of the variable
+    /// * `val` - The `Mat3f` value to set
+    pub fn set_mat3f(&self, name: &CString, val: Mat3f) {
+        self.set_uniform(name, UniformValue::Mat3f(val));
     }
 
     /// Set a 4x4 matrix shader variable
@@ -214,25 +180,25 @@ impl Shader {
     /// * `name` - The name of the variable
     /// * `val` - The `Mat4f` value to set
     pub fn set_mat4f(&self, name: &CString, val: Mat4f) {
-        unsafe {
-            let location = gl::GetUniformLocation(
-                self.program,
-                name.as_ptr()
-            );
-            gl::UniformMatrix4fv(
-                location,
-                1,
-                gl::FALSE,
-                val.values.as_ptr()
-            );
-        }
+        self.set_uniform(name, UniformValue::Mat4f(val));
     }
 }
 
 impl Drop for Shader {
     fn drop(&mut self) {
-        unsafe {
-            gl::DeleteProgram(self.program);
-        }
+        device::with_device(|device| device.destroy_shader(self.handle));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_error_lines() {
+        assert_eq!(error_lines("0:12(5): error: `x' undeclared\n0:3(1): error: syntax error"), vec![3, 12]);
+        assert_eq!(error_lines("0(7) : error C1008: undefined variable \"x\""), vec![7]);
+        assert_eq!(error_lines("ERROR: 0:9: 'x' : undeclared identifier\nERROR: 0:9: '' : compilation terminated"), vec![9]);
+        assert_eq!(error_lines("Linker error"), Vec::<u32>::new());
     }
 }
\ No newline at end of file
@@ -0,0 +1,390 @@
+use std::f32::consts::{PI, TAU};
+
+use crate::math::vec2::Vec2f;
+use crate::math::geometry::rect2::Rect2;
+
+/// Four corners drawn as the triangles (0, 1, 2) and (0, 2, 3).\
+/// A triangle repeats its last corner
+pub type Quad = [Vec2f; 4];
+
+/// The largest distance a curve's segments stray from the true curve
+const CURVE_TOLERANCE: f32 = 0.25;
+const MAX_CURVE_SEGMENTS: usize = 256;
+
+/// How the open ends of a stroke are drawn
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LineCap {
+    /// End exactly at the end points
+    Butt,
+    /// Extend past the end points by half the width
+    Square,
+    /// End in a semicircle around the end points
+    Round
+}
+
+/// How corners between stroke segments are drawn
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LineJoin {
+    /// Extend the outer edges to meet in a point, beveled past the miter limit
+    Miter,
+    /// Cut the corner off
+    Bevel,
+    /// Round the corner
+    Round
+}
+
+/// Describes how lines are drawn
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Stroke {
+    /// The width of the line, centered on it
+    pub width: f32,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    /// The longest a miter can be, as a multiple of half the width
+    pub miter_limit: f32
+}
+
+impl Stroke {
+    /// Creates a new `Stroke` with butt caps and miter joins
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the line
+    pub const fn new(width: f32) -> Self {
+        Stroke { width, cap: LineCap::Butt, join: LineJoin::Miter, miter_limit: 4.0 }
+    }
+
+    /// Creates a new `Stroke` with round caps and joins
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the line
+    pub const fn round(width: f32) -> Self {
+        Stroke { cap: LineCap::Round, join: LineJoin::Round, ..Self::new(width) }
+    }
+}
+
+/// Get the number of segments to approximate an arc with
+fn curve_segments(radius: f32, angle: f32) -> usize {
+    let step = if radius > CURVE_TOLERANCE {
+        2.0 * (1.0 - CURVE_TOLERANCE / radius).acos()
+    } else {
+        PI / 2.0
+    };
+    ((angle.abs() / step).ceil() as usize).clamp(1, MAX_CURVE_SEGMENTS)
+}
+
+/// Get points along an elliptical arc, both ends included
+fn arc_points(center: Vec2f, radii: Vec2f, start_angle: f32, sweep: f32, segments: usize) -> Vec<Vec2f> {
+    (0..=segments)
+        .map(|i| {
+            let angle = start_angle + sweep * i as f32 / segments as f32;
+            center + Vec2f::new(angle.cos() * radii.x, angle.sin() * radii.y)
+        })
+        .collect()
+}
+
+/// Get the points of a full ellipse, the first repeated at the end
+fn ellipse_points(center: Vec2f, radii: Vec2f) -> Vec<Vec2f> {
+    let segments = curve_segments(radii.max_component(), TAU).max(8);
+    arc_points(center, radii, 0.0, TAU, segments)
+}
+
+/// Fill a triangle fan, two triangles per quad
+fn fan(center: Vec2f, points: &[Vec2f], quads: &mut Vec<Quad>) {
+    let mut i = 0;
+    while i + 1 < points.len() {
+        let last = points[(i + 2).min(points.len() - 1)];
+        quads.push([center, points[i], points[i + 1], last]);
+        i += 2;
+    }
+}
+
+/// The direction rotated counter-clockwise by 90 degrees
+fn perpendicular(direction: Vec2f) -> Vec2f {
+    Vec2f::new(-direction.y, direction.x)
+}
+
+/// Tessellate a triangle
+///
+/// # Arguments
+///
+/// * `corners` - The corners of the triangle
+pub fn triangle(corners: [Vec2f; 3]) -> Vec<Quad> {
+    vec![[corners[0], corners[1], corners[2], corners[2]]]
+}
+
+/// Tessellate a filled convex polygon
+///
+/// # Arguments
+///
+/// * `points` - The corners of the polygon, in order around it
+pub fn convex_polygon(points: &[Vec2f]) -> Vec<Quad> {
+    let mut quads = Vec::new();
+    if points.len() >= 3 {
+        fan(points[0], &points[1..], &mut quads);
+    }
+    quads
+}
+
+/// Tessellate a filled ellipse
+///
+/// # Arguments
+///
+/// * `center` - The center of the ellipse
+/// * `radii` - The horizontal and vertical radius
+pub fn ellipse(center: Vec2f, radii: Vec2f) -> Vec<Quad> {
+    let mut quads = Vec::new();
+    fan(center, &ellipse_points(center, radii), &mut quads);
+    quads
+}
+
+/// Tessellate the outline of an ellipse
+///
+/// # Arguments
+///
+/// * `center` - The center of the ellipse
+/// * `radii` - The horizontal and vertical radius, the outline is centered on it
+/// * `width` - The width of the outline
+pub fn ellipse_outline(center: Vec2f, radii: Vec2f, width: f32) -> Vec<Quad> {
+    let half_width = Vec2f::splat(width / 2.0);
+    let outer = ellipse_points(center, radii + half_width);
+    let inner_radii = Vec2f::max(radii - half_width, Vec2f::zero());
+    let inner: Vec<Vec2f> = (0..outer.len())
+        .map(|i| {
+            let angle = TAU * i as f32 / (outer.len() - 1) as f32;
+            center + Vec2f::new(angle.cos() * inner_radii.x, angle.sin() * inner_radii.y)
+        })
+        .collect();
+    outer.windows(2).zip(inner.windows(2))
+        .map(|(outer, inner)| [outer[0], outer[1], inner[1], inner[0]])
+        .collect()
+}
+
+/// Tessellate a stroked circular arc
+///
+/// # Arguments
+///
+/// * `center` - The center of the circle
+/// * `radius` - The radius of the circle, the stroke is centered on it
+/// * `start_angle` - The angle the arc starts at (in radians), counter-clockwise from the x axis
+/// * `sweep` - The angle the arc covers (in radians), counter-clockwise when positive
+/// * `stroke` - How the arc is drawn
+pub fn arc(center: Vec2f, radius: f32, start_angle: f32, sweep: f32, stroke: &Stroke) -> Vec<Quad> {
+    let segments = curve_segments(radius + stroke.width / 2.0, sweep);
+    polyline(&arc_points(center, Vec2f::splat(radius), start_angle, sweep, segments), false, stroke)
+}
+
+/// Get the outline points of a rounded rectangle, counter-clockwise
+fn rounded_rect_points(rect: &Rect2, radius: f32) -> Vec<Vec2f> {
+    let radius = radius.clamp(0.0, rect.width().min(rect.height()) / 2.0);
+    if radius == 0.0 {
+        return vec![rect.min, Vec2f::new(rect.max.x, rect.min.y), rect.max, Vec2f::new(rect.min.x, rect.max.y)];
+    }
+    let segments = curve_segments(radius, PI / 2.0);
+    let inset = Vec2f::splat(radius);
+    let (min, max) = (rect.min + inset, rect.max - inset);
+    [
+        (Vec2f::new(max.x, min.y), -PI / 2.0),
+        (max, 0.0),
+        (Vec2f::new(min.x, max.y), PI / 2.0),
+        (min, PI)
+    ].into_iter()
+        .flat_map(|(center, start_angle)| arc_points(center, inset, start_angle, PI / 2.0, segments))
+        .collect()
+}
+
+/// Tessellate a filled rounded rectangle
+///
+/// # Arguments
+///
+/// * `rect` - The bounds of the rectangle
+/// * `radius` - The radius of the corners, limited to half the shortest side
+pub fn rounded_rect(rect: &Rect2, radius: f32) -> Vec<Quad> {
+    convex_polygon(&rounded_rect_points(rect, radius))
+}
+
+/// Tessellate the outline of a rounded rectangle
+///
+/// # Arguments
+///
+/// * `rect` - The bounds of the rectangle, the outline is centered on them
+/// * `radius` - The radius of the corners, limited to half the shortest side
+/// * `width` - The width of the outline
+pub fn rounded_rect_outline(rect: &Rect2, radius: f32, width: f32) -> Vec<Quad> {
+    polyline(&rounded_rect_points(rect, radius), true, &Stroke::new(width))
+}
+
+/// Tessellate a stroked line
+///
+/// # Arguments
+///
+/// * `start` - The start of the line
+/// * `end` - The end of the line
+/// * `stroke` - How the line is drawn
+pub fn line(start: Vec2f, end: Vec2f, stroke: &Stroke) -> Vec<Quad> {
+    polyline(&[start, end], false, stroke)
+}
+
+/// Tessellate a stroked line through a list of points.\
+/// Overlapping parts are drawn twice, showing with translucent colors
+///
+/// # Arguments
+///
+/// * `points` - The points the line passes through
+/// * `closed` - Whether the last point connects back to the first, instead of being capped
+/// * `stroke` - How the line is drawn
+pub fn polyline(points: &[Vec2f], closed: bool, stroke: &Stroke) -> Vec<Quad> {
+    let mut points: Vec<Vec2f> = points.to_vec();
+    points.dedup();
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let half_width = stroke.width / 2.0;
+    let mut quads = Vec::new();
+    if points.len() < 2 || half_width <= 0.0 {
+        return quads;
+    }
+
+    let segment_count = if closed && points.len() > 2 { points.len() } else { points.len() - 1 };
+    let closed = segment_count == points.len();
+    let segment = |i: usize| (points[i], points[(i + 1) % points.len()]);
+    let direction = |i: usize| {
+        let (start, end) = segment(i);
+        (end - start).normalized()
+    };
+
+    for i in 0..segment_count {
+        let (mut start, mut end) = segment(i);
+        let direction = direction(i);
+        if !closed && stroke.cap == LineCap::Square {
+            if i == 0 {
+                start -= direction * half_width;
+            }
+            if i == segment_count - 1 {
+                end += direction * half_width;
+            }
+        }
+        let normal = perpendicular(direction) * half_width;
+        quads.push([start - normal, end - normal, end + normal, start + normal]);
+    }
+
+    // Joins between segments
+    let joins = if closed { 0..segment_count } else { 1..segment_count };
+    for i in joins {
+        let previous = direction((i + segment_count - 1) % segment_count);
+        let next = direction(i);
+        let point = points[i];
+        let cross = previous.x * next.y - previous.y * next.x;
+        if cross.abs() < 1e-6 {
+            continue;
+        }
+        // The outer side of the corner
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let (previous_normal, next_normal) = (perpendicular(previous), perpendicular(next));
+        let outer_previous = point + previous_normal * (side * half_width);
+        let outer_next = point + next_normal * (side * half_width);
+
+        match stroke.join {
+            LineJoin::Miter => {
+                let miter = (previous_normal + next_normal) * (side * half_width / (1.0 + Vec2f::dot(previous_normal, next_normal)));
+                if miter.magnitude() <= stroke.miter_limit * half_width {
+                    quads.push([point, outer_previous, point + miter, outer_next]);
+                } else {
+                    quads.push([point, outer_previous, outer_next, outer_next]);
+                }
+            },
+            LineJoin::Bevel => quads.push([point, outer_previous, outer_next, outer_next]),
+            LineJoin::Round => {
+                let start_angle = (outer_previous.y - point.y).atan2(outer_previous.x - point.x);
+                let sweep = -side * Vec2f::dot(previous, next).clamp(-1.0, 1.0).acos();
+                let segments = curve_segments(half_width, sweep);
+                fan(point, &arc_points(point, Vec2f::splat(half_width), start_angle, sweep, segments), &mut quads);
+            }
+        }
+    }
+
+    // Caps at the open ends
+    if !closed && stroke.cap == LineCap::Round {
+        let segments = curve_segments(half_width, PI);
+        let (first, last) = (points[0], points[points.len() - 1]);
+        let first_normal = perpendicular(direction(0));
+        let last_normal = perpendicular(direction(segment_count - 1));
+        let start_angle = first_normal.y.atan2(first_normal.x);
+        let end_angle = (-last_normal.y).atan2(-last_normal.x);
+        fan(first, &arc_points(first, Vec2f::splat(half_width), start_angle, PI, segments), &mut quads);
+        fan(last, &arc_points(last, Vec2f::splat(half_width), end_angle, PI, segments), &mut quads);
+    }
+    quads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the total area of the quads' triangles
+    fn area(quads: &[Quad]) -> f32 {
+        let triangle = |a: Vec2f, b: Vec2f, c: Vec2f| ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0;
+        quads.iter().map(|q| triangle(q[0], q[1], q[2]) + triangle(q[0], q[2], q[3])).sum()
+    }
+
+    fn approx_eq(a: f32, b: f32, tolerance: f32) -> bool {
+        (a - b).abs() <= tolerance
+    }
+
+    #[test]
+    fn filled_shapes_cover_their_area() {
+        assert!(approx_eq(area(&triangle([Vec2f::zero(), Vec2f::new(4.0, 0.0), Vec2f::new(0.0, 2.0)])), 4.0, 1e-5));
+        let square = [Vec2f::zero(), Vec2f::new(2.0, 0.0), Vec2f::new(2.0, 2.0), Vec2f::new(0.0, 2.0)];
+        assert!(approx_eq(area(&convex_polygon(&square)), 4.0, 1e-5));
+        assert_eq!(convex_polygon(&square).len(), 1);
+        // Curves are approximated by segments, slightly inside them
+        assert!(approx_eq(area(&ellipse(Vec2f::zero(), Vec2f::new(20.0, 10.0))), PI * 200.0, 12.0));
+        assert!(approx_eq(area(&ellipse_outline(Vec2f::zero(), Vec2f::splat(10.0), 2.0)), PI * (121.0 - 81.0), 5.0));
+
+        let rect = Rect2::new(Vec2f::zero(), Vec2f::new(20.0, 10.0));
+        assert!(approx_eq(area(&rounded_rect(&rect, 0.0)), 200.0, 1e-4));
+        assert!(approx_eq(area(&rounded_rect(&rect, 4.0)), 200.0 - (64.0 - 16.0 * PI), 4.0));
+        assert!(approx_eq(area(&rounded_rect(&rect, 100.0)), 200.0 - (100.0 - 25.0 * PI), 4.0));
+    }
+
+    #[test]
+    fn lines_are_capped() {
+        let (start, end) = (Vec2f::zero(), Vec2f::new(10.0, 0.0));
+        assert!(approx_eq(area(&line(start, end, &Stroke::new(2.0))), 20.0, 1e-4));
+        let square = Stroke { cap: LineCap::Square, ..Stroke::new(2.0) };
+        assert!(approx_eq(area(&line(start, end, &square)), 24.0, 1e-4));
+        assert!(approx_eq(area(&line(start, end, &Stroke::round(20.0))), 200.0 + PI * 100.0, 10.0));
+        assert!(line(start, start, &Stroke::new(2.0)).is_empty());
+    }
+
+    #[test]
+    fn corners_are_joined() {
+        // A right angle, the joins add a square corner, a triangle or a quarter circle
+        let points = [Vec2f::zero(), Vec2f::new(10.0, 0.0), Vec2f::new(10.0, 10.0)];
+        let butt = 2.0 * 20.0;
+        assert!(approx_eq(area(&polyline(&points, false, &Stroke::new(2.0))), butt + 1.0, 1e-4));
+        let bevel = Stroke { join: LineJoin::Bevel, ..Stroke::new(2.0) };
+        assert!(approx_eq(area(&polyline(&points, false, &bevel)), butt + 0.5, 1e-4));
+        let round = Stroke { join: LineJoin::Round, ..Stroke::new(20.0) };
+        assert!(approx_eq(area(&polyline(&points, false, &round)), 400.0 + PI * 25.0, 3.0));
+
+        // Sharp corners fall back to a bevel past the miter limit
+        let sharp = [Vec2f::zero(), Vec2f::new(10.0, 0.0), Vec2f::new(0.0, 0.5)];
+        let miter = polyline(&sharp, false, &Stroke::new(2.0));
+        assert_eq!(miter.last().unwrap()[2], miter.last().unwrap()[3]);
+
+        // Closed outlines join every corner
+        let square = [Vec2f::zero(), Vec2f::new(10.0, 0.0), Vec2f::new(10.0, 10.0), Vec2f::new(0.0, 10.0)];
+        assert_eq!(polyline(&square, true, &Stroke::new(2.0)).len(), 8);
+        assert!(approx_eq(area(&polyline(&square, true, &Stroke::new(2.0))), 4.0 * 20.0 + 4.0, 1e-3));
+    }
+
+    #[test]
+    fn arcs_follow_the_circle() {
+        let quads = arc(Vec2f::zero(), 10.0, 0.0, PI, &Stroke::new(2.0));
+        assert!(approx_eq(area(&quads), PI * (121.0 - 81.0) / 2.0, 1.0));
+        let first = quads[0];
+        assert!(approx_eq(first[0].x, 11.0, 0.05) && approx_eq(first[3].x, 9.0, 0.05));
+    }
+}
\ No newline at end of file
@@ -1,7 +1,154 @@
-use sdl2::{surface::Surface, image::LoadSurface};
+use std::rc::Rc;
+
+#[cfg(feature = "window")]
+use sdl2::{surface::Surface, image::LoadSurface, pixels::PixelFormatEnum};
+
+use super::device::{self, TextureHandle, TextureFormat};
+use crate::{Error, Result};
+
+/// Load an image file as 8 bit RGBA
+///
+/// Returns the pixels with rows bottom to top, the width and the height
+///
+/// # Arguments
+///
+/// * `path` - The image filepath
+#[cfg(feature = "window")]
+pub(crate) fn load_image(path: &str) -> Result<(Vec<u8>, u32, u32)> {
+    let image_error = |message| Error::Image { path: path.to_string(), message };
+    let surface = Surface::from_file(path)
+        .and_then(|surface| surface.convert_format(PixelFormatEnum::RGBA32))
+        .map_err(image_error)?;
+
+    // Flip image, dropping any row padding
+    let (width, height) = (surface.width(), surface.height());
+    let row = width as usize * 4;
+    let pitch = surface.pitch() as usize;
+    let pixels = surface.without_lock().ok_or_else(|| image_error("Surface must be locked".to_string()))?;
+    let data = (0..height as usize).rev()
+        .flat_map(|y| &pixels[y * pitch..y * pitch + row])
+        .copied()
+        .collect();
+    Ok((data, width, height))
+}
+
+/// How texels are sampled
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextureFilter {
+    /// The closest texel, e.g. for pixel art
+    Nearest,
+    /// Interpolate the closest texels
+    Linear
+}
+
+/// How texture coordinates outside of 0 to 1 are sampled
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextureWrap {
+    Repeat,
+    MirroredRepeat,
+    ClampToEdge
+}
+
+/// Describes the format and sampling of a `Texture`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextureDescriptor {
+    /// The pixel format
+    pub format: TextureFormat,
+    /// The filter when the texture is shrunk.\
+    /// With mipmaps, linear also blends between mipmap levels
+    pub min_filter: TextureFilter,
+    /// The filter when the texture is enlarged
+    pub mag_filter: TextureFilter,
+    /// The wrap mode horizontally
+    pub wrap_u: TextureWrap,
+    /// The wrap mode vertically
+    pub wrap_v: TextureWrap,
+    /// Whether mipmaps are generated, kept up to date by `Texture::update_region`
+    pub mipmaps: bool,
+    /// The maximum anisotropic filtering samples, 1 disables.
+    /// Clamped to what the device supports
+    pub anisotropy: f32
+}
+
+impl TextureDescriptor {
+    /// Creates a new `TextureDescriptor`, linearly filtered
+    /// and repeating without mipmaps
+    /// 
+    /// # Arguments
+    /// 
+    /// * `format` - The pixel format
+    pub const fn new(format: TextureFormat) -> Self {
+        TextureDescriptor {
+            format,
+            min_filter: TextureFilter::Linear,
+            mag_filter: TextureFilter::Linear,
+            wrap_u: TextureWrap::Repeat,
+            wrap_v: TextureWrap::Repeat,
+            mipmaps: false,
+            anisotropy: 1.0
+        }
+    }
+
+    /// Creates a new `TextureDescriptor` for pixel art,
+    /// nearest filtered and clamped to its edges
+    /// 
+    /// # Arguments
+    /// 
+    /// * `format` - The pixel format
+    pub const fn pixel_art(format: TextureFormat) -> Self {
+        TextureDescriptor {
+            min_filter: TextureFilter::Nearest,
+            mag_filter: TextureFilter::Nearest,
+            wrap_u: TextureWrap::ClampToEdge,
+            wrap_v: TextureWrap::ClampToEdge,
+            ..Self::new(format)
+        }
+    }
+
+    /// Creates a new `TextureDescriptor` with mipmaps and
+    /// anisotropic filtering, e.g. for textures seen at an angle
+    /// 
+    /// # Arguments
+    /// 
+    /// * `format` - The pixel format
+    /// * `anisotropy` - The maximum anisotropic filtering samples
+    pub const fn mipmapped(format: TextureFormat, anisotropy: f32) -> Self {
+        TextureDescriptor {
+            mipmaps: true,
+            anisotropy,
+            ..Self::new(format)
+        }
+    }
+}
+
+impl Default for TextureDescriptor {
+    fn default() -> Self {
+        Self::new(TextureFormat::Rgba8)
+    }
+}
+
+/// A texture's device handle, destroyed when the last owner drops it
+#[derive(Debug)]
+pub(crate) struct TextureResource(TextureHandle);
+
+impl TextureResource {
+    /// Get the texture's device handle
+    pub(crate) const fn handle(&self) -> TextureHandle {
+        self.0
+    }
+}
+
+impl Drop for TextureResource {
+    fn drop(&mut self) {
+        device::with_device(|device| device.destroy_texture(self.0));
+    }
+}
 
 pub struct Texture {
-    id: u32
+    resource: Rc<TextureResource>,
+    width: u32,
+    height: u32,
+    descriptor: TextureDescriptor
 }
 
 impl Texture {
@@ -10,45 +157,29 @@ impl Texture {
     /// # Arguments
     /// 
     /// * `path` - The image filepath
-    pub fn new(path: &str) -> Self {
-        let mut id = 0;
-
-        // Load image
-        let surface = Surface::from_file(path).unwrap();
-        // Flip image
-        let pitch: usize = surface.pitch().try_into().unwrap();
-        let mut temp_row = vec![0u8; pitch];
-        let pixels: *mut u8;
-        unsafe {
-            pixels = (*surface.raw()).pixels.cast();
-            for i in 0..(surface.height() / 2) {
-                let row1 = pixels.offset((i * pitch as u32) as isize);
-                let row2 = pixels.offset(((surface.height() - i - 1) * pitch as u32) as isize);
-                std::ptr::copy_nonoverlapping(row1, temp_row.as_mut_ptr(), pitch);
-                std::ptr::copy_nonoverlapping(row2, row1, pitch);
-                std::ptr::copy_nonoverlapping(temp_row.as_mut_ptr(), row2, pitch);
-            }
-        }
+    ///
+    /// Returns an error if the image can't be loaded
+    #[cfg(feature = "window")]
+    pub fn new(path: &str) -> Result<Self> {
+        Self::from_file(path, TextureDescriptor::default())
+    }
 
-        // Create texture
-        unsafe {
-            gl::GenTextures(1, &mut id);
-            gl::BindTexture(gl::TEXTURE_2D, id);
-            gl::TexImage2D(
-                gl::TEXTURE_2D, 
-                0, 
-                gl::RGBA as i32,
-                surface.width() as i32,
-                surface.height() as i32,
-                0,
-                gl::RGBA,
-                gl::UNSIGNED_BYTE,
-                (*surface.raw()).pixels);
-
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as i32);
-        }
-        Texture { id }
+    /// Creates a new `Texture` from an image file
+    /// 
+    /// # Arguments
+    /// 
+    /// * `path` - The image filepath
+    /// * `descriptor` - The format and sampling, the format must be RGBA8 or sRGBA8
+    ///
+    /// Returns an error if the image can't be loaded
+    #[cfg(feature = "window")]
+    pub fn from_file(path: &str, descriptor: TextureDescriptor) -> Result<Self> {
+        assert!(
+            matches!(descriptor.format, TextureFormat::Rgba8 | TextureFormat::Srgba8),
+            "Images are loaded as 8 bit RGBA");
+
+        let (data, width, height) = load_image(path)?;
+        Ok(Self::from_data(&data, width, height, descriptor))
     }
 
     /// Creates a new `Texture` with the given data
@@ -58,48 +189,120 @@ impl Texture {
     /// * `data` - The image data as contiguous r, g, b, a bytes
     /// * `width` - The width of the image
     /// * `height` - The height of the image
-    pub fn with_data(data: &Vec<u8>, width: u32, height: u32) -> Self {
-        assert_eq!(data.len() / 4, (width * height) as usize);
-        let mut id = 0;
-        unsafe {
-            gl::GenTextures(1, &mut id);
-            gl::BindTexture(gl::TEXTURE_2D, id);
-            gl::TexImage2D(
-                gl::TEXTURE_2D, 
-                0, 
-                gl::RGBA as i32,
-                width as i32,
-                height as i32,
-                0,
-                gl::RGBA,
-                gl::UNSIGNED_BYTE,
-                data.as_ptr().cast());
-
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as i32);
-            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as i32);
+    pub fn with_data(data: &[u8], width: u32, height: u32) -> Self {
+        Self::from_data(data, width, height, TextureDescriptor::default())
+    }
+
+    /// Creates a new `Texture` with the given data
+    /// 
+    /// # Arguments
+    /// 
+    /// * `data` - The image data laid out as the descriptor's format, rows bottom to top
+    /// * `width` - The width of the image
+    /// * `height` - The height of the image
+    /// * `descriptor` - The format and sampling of the texture
+    pub fn from_data(data: &[u8], width: u32, height: u32, descriptor: TextureDescriptor) -> Self {
+        assert_eq!(data.len(), width as usize * height as usize * descriptor.format.bytes_per_pixel());
+        let handle = device::with_device(|device| {
+            device.create_texture(width, height, &descriptor, Some(data))
+        });
+        Texture { resource: Rc::new(TextureResource(handle)), width, height, descriptor }
+    }
+
+    /// Creates a new `Texture` with undefined contents, e.g. to render to
+    /// 
+    /// # Arguments
+    /// 
+    /// * `width` - The width of the texture
+    /// * `height` - The height of the texture
+    /// * `descriptor` - The format and sampling of the texture
+    pub fn empty(width: u32, height: u32, descriptor: TextureDescriptor) -> Self {
+        let handle = device::with_device(|device| device.create_texture(width, height, &descriptor, None));
+        Texture { resource: Rc::new(TextureResource(handle)), width, height, descriptor }
+    }
+
+    /// Get the texture's device handle
+    pub fn handle(&self) -> TextureHandle {
+        self.resource.handle()
+    }
+
+    /// Share ownership of the device texture, keeping it alive after this `Texture` is dropped
+    pub(crate) fn share(&self) -> Rc<TextureResource> {
+        Rc::clone(&self.resource)
+    }
+
+    /// Get the width of the texture
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the height of the texture
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the pixel format of the texture
+    pub const fn format(&self) -> TextureFormat {
+        self.descriptor.format
+    }
+
+    /// Get the format and sampling of the texture
+    pub const fn descriptor(&self) -> &TextureDescriptor {
+        &self.descriptor
+    }
+
+    /// Replace a region of the texture, regenerating mipmaps if it has them
+    /// 
+    /// # Arguments
+    /// 
+    /// * `x` - The left of the region
+    /// * `y` - The bottom of the region
+    /// * `width` - The width of the region
+    /// * `height` - The height of the region
+    /// * `data` - The new pixels laid out as the texture's format, rows bottom to top
+    /// 
+    /// Returns `Error::TextureRegion` if the region is outside of the texture
+    /// or `data` is the wrong length for it, leaving the texture unchanged
+    pub fn update_region(&self, x: u32, y: u32, width: u32, height: u32, data: &[u8]) -> Result<()> {
+        let inside = x.checked_add(width).is_some_and(|right| right <= self.width) &&
+            y.checked_add(height).is_some_and(|top| top <= self.height);
+        if !inside {
+            return Err(Error::TextureRegion(format!(
+                "{}x{} at ({}, {}) is outside of the {}x{} texture",
+                width, height, x, y, self.width, self.height)));
+        }
+        let expected = width as usize * height as usize * self.descriptor.format.bytes_per_pixel();
+        if data.len() != expected {
+            return Err(Error::TextureRegion(format!(
+                "{} bytes of data for a {}x{} region that needs {}",
+                data.len(), width, height, expected)));
         }
-        Texture { id }
+
+        device::with_device(|device| {
+            device.update_texture(self.handle(), x, y, width, height, self.descriptor.format, data);
+            if self.descriptor.mipmaps {
+                device.generate_mipmaps(self.handle());
+            }
+        });
+        Ok(())
+    }
+
+    /// Read the texture's pixels back from the device
+    /// 
+    /// Returns the pixels laid out as the texture's format, rows bottom to top
+    pub fn read_pixels(&self) -> Vec<u8> {
+        device::with_device(|device| {
+            device.read_texture(self.handle(), self.width, self.height, self.descriptor.format)
+        })
     }
 
     /// Make this buffer the active `Texture` in a chosen slot
     pub fn bind_to_slot(&self, slot: u32) {
-        unsafe {
-            gl::BindTextureUnit(slot, self.id);
-        }
+        device::with_device(|device| device.bind_texture(slot, Some(self.handle())));
     }
 
     /// Unbind the current `Texture` from a slot
     pub fn unbind_from_slot(slot: u32) {
-        unsafe {
-            gl::BindTextureUnit(0, 0);
-        }
-    }
-}
-
-impl Drop for Texture {
-    fn drop(&mut self) {
-        unsafe {
-            gl::DeleteTextures(1, &self.id);
-        }
+        device::with_device(|device| device.bind_texture(slot, None));
     }
 }
\ No newline at end of file
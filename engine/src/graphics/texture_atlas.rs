@@ -0,0 +1,553 @@
+use std::collections::HashMap;
+
+use crate::{Error, Result};
+use crate::math::{vec2::Vec2f, vec3::Vec3f};
+use super::device::TextureFormat;
+use super::renderer_2d::Rect;
+use super::texture::{Texture, TextureDescriptor, TextureWrap};
+#[cfg(feature = "window")]
+use super::texture::load_image;
+
+/// A named region of a `TextureAtlas`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sprite {
+    /// The left of the region in pixels
+    pub x: u32,
+    /// The bottom of the region in pixels
+    pub y: u32,
+    /// The width of the region in pixels
+    pub width: u32,
+    /// The height of the region in pixels
+    pub height: u32,
+    /// The bottom left texture coordinate, for `Rect::uv_min`
+    pub uv_min: Vec2f,
+    /// The top right texture coordinate, for `Rect::uv_max`
+    pub uv_max: Vec2f
+}
+
+impl Sprite {
+    /// Creates a new `Sprite` covering a region of a texture
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The left of the region in pixels
+    /// * `y` - The bottom of the region in pixels
+    /// * `width` - The width of the region in pixels
+    /// * `height` - The height of the region in pixels
+    /// * `texture_width` - The width of the texture
+    /// * `texture_height` - The height of the texture
+    pub fn new(x: u32, y: u32, width: u32, height: u32, texture_width: u32, texture_height: u32) -> Self {
+        let texture_size = Vec2f::new(texture_width as f32, texture_height as f32);
+        Sprite {
+            x,
+            y,
+            width,
+            height,
+            uv_min: Vec2f::new(x as f32, y as f32) / texture_size,
+            uv_max: Vec2f::new((x + width) as f32, (y + height) as f32) / texture_size
+        }
+    }
+
+    /// Get the size of the sprite in pixels
+    pub fn size(&self) -> Vec2f {
+        Vec2f::new(self.width as f32, self.height as f32)
+    }
+
+    /// Creates a `Rect` showing the sprite at its size in pixels
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position of the rect (from pivot)
+    /// * `pivot` - Pivot point (bottom-left (0.0, 0.0), top-right (1.0, 1.0))
+    pub fn rect(&self, position: Vec3f, pivot: Vec2f) -> Rect {
+        Rect::new(position, self.size(), pivot, self.uv_min, self.uv_max)
+    }
+}
+
+/// An image to pack into a `TextureAtlas`
+#[derive(Clone, PartialEq, Debug)]
+pub struct AtlasImage {
+    /// The name the sprite is looked up by
+    pub name: String,
+    /// The width of the image
+    pub width: u32,
+    /// The height of the image
+    pub height: u32,
+    /// The pixels laid out as the atlas format, rows bottom to top
+    pub data: Vec<u8>
+}
+
+impl AtlasImage {
+    /// Creates a new `AtlasImage`
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name the sprite is looked up by
+    /// * `data` - The pixels laid out as the atlas format, rows bottom to top
+    /// * `width` - The width of the image
+    /// * `height` - The height of the image
+    pub fn new(name: impl Into<String>, data: Vec<u8>, width: u32, height: u32) -> Self {
+        AtlasImage { name: name.into(), width, height, data }
+    }
+
+    /// Creates a new `AtlasImage` from an image file, as 8 bit RGBA
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name the sprite is looked up by
+    /// * `path` - The image filepath
+    ///
+    /// Returns an error if the image can't be loaded
+    #[cfg(feature = "window")]
+    pub fn from_file(name: impl Into<String>, path: &str) -> Result<Self> {
+        let (data, width, height) = load_image(path)?;
+        Ok(Self::new(name, data, width, height))
+    }
+}
+
+/// Describes how a `TextureAtlas` is packed
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AtlasDescriptor {
+    /// The maximum width and height of the atlas texture
+    pub max_size: u32,
+    /// The empty pixels between images, stops linear filtering bleeding between sprites
+    pub padding: u32,
+    /// The format and sampling of the atlas texture
+    pub texture: TextureDescriptor
+}
+
+impl AtlasDescriptor {
+    /// Creates a new `AtlasDescriptor` for an RGBA8 atlas of at most
+    /// 4096x4096 pixels, with images 1 pixel apart
+    pub const fn new() -> Self {
+        AtlasDescriptor {
+            max_size: 4096,
+            padding: 1,
+            texture: TextureDescriptor {
+                wrap_u: TextureWrap::ClampToEdge,
+                wrap_v: TextureWrap::ClampToEdge,
+                ..TextureDescriptor::new(TextureFormat::Rgba8)
+            }
+        }
+    }
+}
+
+impl Default for AtlasDescriptor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A texture holding many named sprites, so they can be drawn
+/// in one batch while only using one texture slot
+pub struct TextureAtlas {
+    texture: Texture,
+    sprites: Vec<Sprite>,
+    names: HashMap<String, usize>
+}
+
+impl TextureAtlas {
+    /// Creates a new `TextureAtlas` from a texture and its sprites
+    ///
+    /// # Arguments
+    ///
+    /// * `texture` - The texture the sprites are regions of
+    /// * `sprites` - The name and region of each sprite, in index order
+    pub fn new(texture: Texture, sprites: Vec<(String, Sprite)>) -> Self {
+        let mut names = HashMap::with_capacity(sprites.len());
+        let sprites = sprites.into_iter()
+            .enumerate()
+            .map(|(index, (name, sprite))| {
+                names.insert(name, index);
+                sprite
+            })
+            .collect();
+        TextureAtlas { texture, sprites, names }
+    }
+
+    /// Creates a new `TextureAtlas` by packing images into one texture.\
+    /// The texture is the smallest power of two size found that fits every image
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - The images to pack, sprite indices follow their order
+    /// * `descriptor` - The maximum size, padding and texture format
+    ///
+    /// Returns an error if an image's data doesn't match the atlas format,
+    /// or the images don't fit in the maximum size
+    pub fn pack(images: &[AtlasImage], descriptor: &AtlasDescriptor) -> Result<Self> {
+        let bytes_per_pixel = descriptor.texture.format.bytes_per_pixel();
+        for image in images {
+            let expected = image.width as usize * image.height as usize * bytes_per_pixel;
+            if image.data.len() != expected {
+                let (name, actual) = (image.name.clone(), image.data.len());
+                return Err(Error::ImageData { name, expected, actual });
+            }
+        }
+
+        // Tallest first packs tightest
+        let padding = descriptor.padding;
+        let mut order: Vec<usize> = (0..images.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse((images[i].height, images[i].width)));
+        let sizes: Vec<(u32, u32)> = order.iter()
+            .map(|&i| (images[i].width + padding, images[i].height + padding))
+            .collect();
+
+        // Grow from the smallest square that could hold the total area
+        let area: u64 = sizes.iter().map(|&(width, height)| width as u64 * height as u64).sum();
+        let side = ((area as f64).sqrt().ceil() as u32).max(1).next_power_of_two();
+        let (mut width, mut height) = (side.min(descriptor.max_size), side.min(descriptor.max_size));
+        let positions = loop {
+            match pack_rects(&sizes, width, height) {
+                Ok(positions) => break positions,
+                Err(failed) => {
+                    if width <= height && width * 2 <= descriptor.max_size {
+                        width *= 2;
+                    } else if height * 2 <= descriptor.max_size {
+                        height *= 2;
+                    } else if width * 2 <= descriptor.max_size {
+                        width *= 2;
+                    } else {
+                        let name = images[order[failed]].name.clone();
+                        return Err(Error::AtlasFull { name, max_size: descriptor.max_size });
+                    }
+                }
+            }
+        };
+
+        // Copy each image's rows into place
+        let mut data = vec![0u8; width as usize * height as usize * bytes_per_pixel];
+        let mut placed = vec![(0, 0); images.len()];
+        for (&i, &(x, y)) in order.iter().zip(&positions) {
+            let image = &images[i];
+            let row = image.width as usize * bytes_per_pixel;
+            for image_y in 0..image.height as usize {
+                let start = ((y as usize + image_y) * width as usize + x as usize) * bytes_per_pixel;
+                data[start..start + row].copy_from_slice(&image.data[image_y * row..(image_y + 1) * row]);
+            }
+            placed[i] = (x, y);
+        }
+
+        let texture = Texture::from_data(&data, width, height, descriptor.texture);
+        let sprites = images.iter()
+            .zip(placed)
+            .map(|(image, (x, y))| (image.name.clone(), Sprite::new(x, y, image.width, image.height, width, height)))
+            .collect();
+        Ok(Self::new(texture, sprites))
+    }
+
+    /// Creates a new `TextureAtlas` from a sprite sheet of equally sized cells.\
+    /// Sprites are numbered and named from "0", left to right and top to bottom
+    ///
+    /// # Arguments
+    ///
+    /// * `texture` - The sprite sheet
+    /// * `cell_width` - The width of each cell
+    /// * `cell_height` - The height of each cell
+    pub fn from_grid(texture: Texture, cell_width: u32, cell_height: u32) -> Self {
+        assert!(cell_width > 0 && cell_height > 0, "Cell size must be non-zero");
+        let (width, height) = (texture.width(), texture.height());
+        let (columns, rows) = (width / cell_width, height / cell_height);
+        let sprites = (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (column, row)))
+            .enumerate()
+            .map(|(index, (column, row))| {
+                // Texture rows are bottom to top
+                let y = height - (row + 1) * cell_height;
+                (index.to_string(), Sprite::new(column * cell_width, y, cell_width, cell_height, width, height))
+            })
+            .collect();
+        Self::new(texture, sprites)
+    }
+
+    /// Creates a new `TextureAtlas` from Aseprite or TexturePacker JSON with the `json` feature.\
+    /// Both the hash and array frame layouts are supported, sprites are
+    /// indexed in file order. Frame trimming offsets are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `texture` - The sprite sheet the JSON describes
+    /// * `json` - The JSON text
+    ///
+    /// Returns an error if the JSON is malformed, has rotated frames or frames outside the texture
+    #[cfg(feature = "json")]
+    pub fn from_json(texture: Texture, json: &str) -> Result<Self> {
+        let sheet: sheet_json::Sheet = serde_json::from_str(json)
+            .map_err(|error| Error::SpriteSheet(error.to_string()))?;
+        let (width, height) = (texture.width(), texture.height());
+        let sprites = sheet.frames.into_frames().into_iter()
+            .map(|(name, frame)| {
+                let rect = frame.frame;
+                if frame.rotated {
+                    return Err(Error::SpriteSheet(format!("Frame '{}' is rotated, which is unsupported", name)));
+                }
+                if rect.x.checked_add(rect.w).is_none_or(|right| right > width)
+                    || rect.y.checked_add(rect.h).is_none_or(|bottom| bottom > height) {
+                    return Err(Error::SpriteSheet(format!("Frame '{}' is outside of the texture", name)));
+                }
+                // Frames are from the top left, texture rows are bottom to top
+                let sprite = Sprite::new(rect.x, height - rect.y - rect.h, rect.w, rect.h, width, height);
+                Ok((name, sprite))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(texture, sprites))
+    }
+
+    /// Creates a new `TextureAtlas` from an Aseprite or TexturePacker JSON file,
+    /// loading the image its `meta.image` names relative to it
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The JSON filepath
+    /// * `descriptor` - The format and sampling of the texture, the format must be RGBA8 or sRGBA8
+    ///
+    /// Returns an error if either file can't be loaded or the JSON is invalid
+    #[cfg(all(feature = "json", feature = "window"))]
+    pub fn load_json(path: &str, descriptor: TextureDescriptor) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|error| Error::SpriteSheet(format!("Failed to read '{}': {}", path, error)))?;
+        let sheet: sheet_json::Sheet = serde_json::from_str(&json)
+            .map_err(|error| Error::SpriteSheet(error.to_string()))?;
+        let image = sheet.meta.and_then(|meta| meta.image)
+            .ok_or_else(|| Error::SpriteSheet(format!("'{}' doesn't name its image", path)))?;
+
+        let image_path = std::path::Path::new(path).with_file_name(image);
+        let texture = Texture::from_file(&image_path.to_string_lossy(), descriptor)?;
+        Self::from_json(texture, &json)
+    }
+
+    /// Get the texture holding the sprites
+    pub const fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Take the texture holding the sprites, dropping the sprites
+    pub fn into_texture(self) -> Texture {
+        self.texture
+    }
+
+    /// Get a sprite by name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the sprite
+    pub fn sprite(&self, name: &str) -> Option<Sprite> {
+        self.names.get(name).map(|&index| self.sprites[index])
+    }
+
+    /// Get the index of a sprite by name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the sprite
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    /// Get every sprite, in index order
+    pub fn sprites(&self) -> &[Sprite] {
+        &self.sprites
+    }
+
+    /// Get the number of sprites
+    pub fn len(&self) -> usize {
+        self.sprites.len()
+    }
+
+    /// Check if the atlas has no sprites
+    pub fn is_empty(&self) -> bool {
+        self.sprites.is_empty()
+    }
+}
+
+/// Pack rectangles into an area with the skyline bottom-left heuristic
+///
+/// Returns the bottom left of each rectangle, or the index of the first that didn't fit
+///
+/// # Arguments
+///
+/// * `sizes` - The width and height of each rectangle
+/// * `width` - The width of the area
+/// * `height` - The height of the area
+fn pack_rects(sizes: &[(u32, u32)], width: u32, height: u32) -> std::result::Result<Vec<(u32, u32)>, usize> {
+    // The top edge of everything placed so far, as (x, y, width) segments left to right
+    let mut skyline = vec![(0, 0, width)];
+    let mut positions = Vec::with_capacity(sizes.len());
+
+    for (index, &(rect_width, rect_height)) in sizes.iter().enumerate() {
+        // Lowest top edge, then narrowest segment to leave less wasted space
+        let fit = |start: usize| {
+            let x = skyline[start].0;
+            if x + rect_width > width {
+                return None;
+            }
+            let mut y = 0;
+            let mut covered = 0;
+            for &(_, segment_y, segment_width) in &skyline[start..] {
+                y = y.max(segment_y);
+                if y + rect_height > height {
+                    return None;
+                }
+                covered += segment_width;
+                if covered >= rect_width {
+                    break;
+                }
+            }
+            Some(y)
+        };
+        let best = (0..skyline.len())
+            .filter_map(|start| fit(start).map(|y| (y + rect_height, skyline[start].2, start, y)))
+            .min();
+        let Some((_, _, start, y)) = best else {
+            return Err(index);
+        };
+
+        // Raise the skyline under the rectangle
+        let x = skyline[start].0;
+        let right = x + rect_width;
+        skyline.insert(start, (x, y + rect_height, rect_width));
+        let next = start + 1;
+        while next < skyline.len() && skyline[next].0 < right {
+            let (segment_x, segment_y, segment_width) = skyline[next];
+            let overlap = right - segment_x;
+            if segment_width <= overlap {
+                skyline.remove(next);
+            } else {
+                skyline[next] = (right, segment_y, segment_width - overlap);
+                break;
+            }
+        }
+        skyline.dedup_by(|right, left| {
+            let merge = left.1 == right.1;
+            if merge {
+                left.2 += right.2;
+            }
+            merge
+        });
+
+        positions.push((x, y));
+    }
+    Ok(positions)
+}
+
+/// Aseprite and TexturePacker JSON layout
+#[cfg(feature = "json")]
+mod sheet_json {
+    use std::fmt;
+
+    use serde::Deserialize;
+    use serde::de::{Deserializer, MapAccess, Visitor};
+
+    #[derive(Deserialize)]
+    pub struct Sheet {
+        pub frames: Frames,
+        // Only needed to load the image
+        #[cfg(feature = "window")]
+        pub meta: Option<Meta>
+    }
+
+    #[cfg(feature = "window")]
+    #[derive(Deserialize)]
+    pub struct Meta {
+        pub image: Option<String>
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    pub enum Frames {
+        Array(Vec<NamedFrame>),
+        Hash(OrderedFrames)
+    }
+
+    impl Frames {
+        pub fn into_frames(self) -> Vec<(String, Frame)> {
+            match self {
+                Frames::Array(frames) => frames.into_iter()
+                    .map(|named| (named.filename, Frame { frame: named.frame, rotated: named.rotated }))
+                    .collect(),
+                Frames::Hash(OrderedFrames(frames)) => frames
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct NamedFrame {
+        pub filename: String,
+        pub frame: FrameRect,
+        #[serde(default)]
+        pub rotated: bool
+    }
+
+    #[derive(Deserialize)]
+    pub struct Frame {
+        pub frame: FrameRect,
+        #[serde(default)]
+        pub rotated: bool
+    }
+
+    #[derive(Deserialize)]
+    pub struct FrameRect {
+        pub x: u32,
+        pub y: u32,
+        pub w: u32,
+        pub h: u32
+    }
+
+    /// Frames by name, keeping file order so animations stay in sequence
+    pub struct OrderedFrames(pub Vec<(String, Frame)>);
+
+    impl<'de> Deserialize<'de> for OrderedFrames {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct OrderedVisitor;
+
+            impl<'de> Visitor<'de> for OrderedVisitor {
+                type Value = OrderedFrames;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "a map of frames")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                    let mut frames = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                    while let Some(entry) = map.next_entry()? {
+                        frames.push(entry);
+                    }
+                    Ok(OrderedFrames(frames))
+                }
+            }
+
+            deserializer.deserialize_map(OrderedVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlaps(a: ((u32, u32), (u32, u32)), b: ((u32, u32), (u32, u32))) -> bool {
+        let (((ax, ay), (aw, ah)), ((bx, by), (bw, bh))) = (a, b);
+        ax < bx + bw && bx < ax + aw && ay < by + bh && by < ay + ah
+    }
+
+    #[test]
+    fn packs_without_overlap() {
+        let sizes: Vec<(u32, u32)> = (0..40).map(|i| (3 + i * 7 % 13, 2 + i * 5 % 11)).collect();
+        let positions = pack_rects(&sizes, 64, 64).unwrap();
+
+        for (i, (&position, &size)) in positions.iter().zip(&sizes).enumerate() {
+            assert!(position.0 + size.0 <= 64 && position.1 + size.1 <= 64);
+            for (&other, &other_size) in positions.iter().zip(&sizes).skip(i + 1) {
+                assert!(!overlaps((position, size), (other, other_size)));
+            }
+        }
+    }
+
+    #[test]
+    fn fills_exactly() {
+        let sizes = vec![(16, 16); 16];
+        assert!(pack_rects(&sizes, 64, 64).is_ok());
+        assert_eq!(pack_rects(&[(16, 16), (64, 64)], 64, 64), Err(1));
+        assert_eq!(pack_rects(&[(65, 1)], 64, 64), Err(0));
+    }
+}
\ No newline at end of file
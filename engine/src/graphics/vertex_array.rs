@@ -1,32 +1,21 @@
-use super::{array_buffer::{ArrayBuffer, AttributeType}, index_buffer::IndexBuffer};
+use super::{array_buffer::ArrayBuffer, index_buffer::IndexBuffer};
+use super::device::{self, VertexArrayHandle};
 
 /// An array of vertex data
 pub struct VertexArray {
-    id: u32
+    handle: VertexArrayHandle
 }
 
 impl VertexArray {
     /// Creates a new `VertexArray`
     pub fn new() -> Self {
-        let mut id = 0;
-        unsafe {
-            gl::GenVertexArrays(1, &mut id);
-        }
-        VertexArray { id }
+        let handle = device::with_device(|device| device.create_vertex_array());
+        VertexArray { handle }
     }
 
-    /// Make this buffer the active `VertexArray`
-    pub fn bind(&self) {
-        unsafe {
-            gl::BindVertexArray(self.id);
-        }
-    }
-
-    /// Unbind the current `VertexArray`
-    pub fn unbind() {
-        unsafe {
-            gl::BindVertexArray(0);
-        }
+    /// Get the array's device handle
+    pub const fn handle(&self) -> VertexArrayHandle {
+        self.handle
     }
 
     /// Adds a `VertexBuffer` to this array
@@ -35,44 +24,9 @@ impl VertexArray {
     /// 
     /// * `vertex_buffer` - The `VertexBuffer` to add
     pub fn add_vertex_buffer(&self, vertex_buffer: &ArrayBuffer) {
-        self.bind();
-        vertex_buffer.bind();
-
-        let layout = vertex_buffer.layout();
-        let attributes = layout.attributes();
-        let offsets = layout.offsets();
-
-        for i in 0..attributes.len() {
-            let attribute_type = attributes[i].attribute_type();
-            unsafe {
-                gl::EnableVertexAttribArray(i as u32);
-                match attribute_type {
-                    AttributeType::Int => {
-                        gl::VertexAttribIPointer(
-                            i as u32,
-                            attribute_type.component_count() as i32,
-                            attribute_type.opengl_type(),
-                            layout.stride() as i32,
-                            offsets[i] as *const _
-                        );
-                    }
-                    AttributeType::Float |
-                    AttributeType::Vec2f |
-                    AttributeType::Vec3f |
-                    AttributeType::Vec4f => {
-                        gl::VertexAttribPointer(
-                            i as u32,
-                            attribute_type.component_count() as i32,
-                            attribute_type.opengl_type(),
-                            if attributes[i].normalized() { gl::TRUE } else { gl::FALSE },
-                            layout.stride() as i32,
-                            offsets[i] as *const _
-                        );
-                    }
-                }
-            }
-        }
-        Self::unbind();
+        device::with_device(|device| {
+            device.set_vertex_buffer(self.handle, vertex_buffer.handle(), vertex_buffer.layout())
+        });
     }
 
     /// Sets the `IndexBuffer` of this array
@@ -81,16 +35,12 @@ impl VertexArray {
     /// 
     /// * `index_buffer` - The `IndexBuffer` to set
     pub fn set_index_buffer(&self, index_buffer: &IndexBuffer) {
-        self.bind();
-        index_buffer.bind();
-        Self::unbind();
+        device::with_device(|device| device.set_index_buffer(self.handle, index_buffer.handle()));
     }
 }
 
 impl Drop for VertexArray {
     fn drop(&mut self) {
-        unsafe {
-            gl::DeleteVertexArrays(1, &self.id)
-        }
+        device::with_device(|device| device.destroy_vertex_array(self.handle));
     }
 }
\ No newline at end of file
@@ -1,7 +1,12 @@
+#[cfg(feature = "window")]
 extern crate sdl2;
 extern crate gl;
 
+mod error;
+pub use error::{Error, Result};
+
 pub mod system;
 pub mod math;
 pub mod graphics;
+pub mod scene;
 
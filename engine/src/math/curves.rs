@@ -0,0 +1,436 @@
+use core::f32::consts::PI;
+use core::ops::{Add, Sub, Mul};
+
+/// A value that can be interpolated along a curve
+///
+/// Implemented for `f32`, `Vec2f`, `Vec3f` and `Vec4f`
+pub trait Interpolate: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self> {}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>> Interpolate for T {}
+
+/// Linearly interpolate between two values
+///
+/// # Arguments
+///
+/// * `from` - The value at `t = 0`
+/// * `to` - The value at `t = 1`
+/// * `t` - The interpolation factor
+pub fn lerp<T: Interpolate>(from: T, to: T, t: f32) -> T {
+    from + (to - from) * t
+}
+
+/// Evaluate a quadratic Bézier curve
+///
+/// # Arguments
+///
+/// * `p0` - The start point
+/// * `p1` - The control point
+/// * `p2` - The end point
+/// * `t` - The curve parameter in [0, 1]
+pub fn quadratic_bezier<T: Interpolate>(p0: T, p1: T, p2: T, t: f32) -> T {
+    let u = 1.0 - t;
+    p0 * (u * u) + p1 * (2.0 * u * t) + p2 * (t * t)
+}
+
+/// Evaluate a cubic Bézier curve
+///
+/// # Arguments
+///
+/// * `p0` - The start point
+/// * `p1` - The first control point
+/// * `p2` - The second control point
+/// * `p3` - The end point
+/// * `t` - The curve parameter in [0, 1]
+pub fn cubic_bezier<T: Interpolate>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T {
+    let u = 1.0 - t;
+    p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+}
+
+/// Evaluate the tangent of a cubic Bézier curve
+///
+/// # Arguments
+///
+/// * `p0` - The start point
+/// * `p1` - The first control point
+/// * `p2` - The second control point
+/// * `p3` - The end point
+/// * `t` - The curve parameter in [0, 1]
+pub fn cubic_bezier_tangent<T: Interpolate>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T {
+    let u = 1.0 - t;
+    (p1 - p0) * (3.0 * u * u) + (p2 - p1) * (6.0 * u * t) + (p3 - p2) * (3.0 * t * t)
+}
+
+/// Evaluate a cubic Hermite spline segment
+///
+/// # Arguments
+///
+/// * `p0` - The start point
+/// * `m0` - The tangent at the start point
+/// * `p1` - The end point
+/// * `m1` - The tangent at the end point
+/// * `t` - The curve parameter in [0, 1]
+pub fn hermite<T: Interpolate>(p0: T, m0: T, p1: T, m1: T, t: f32) -> T {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (2.0 * t3 - 3.0 * t2 + 1.0) +
+    m0 * (t3 - 2.0 * t2 + t) +
+    p1 * (-2.0 * t3 + 3.0 * t2) +
+    m1 * (t3 - t2)
+}
+
+/// Evaluate a uniform Catmull-Rom spline segment between `p1` and `p2`
+///
+/// # Arguments
+///
+/// * `p0` - The point before the segment
+/// * `p1` - The start point of the segment
+/// * `p2` - The end point of the segment
+/// * `p3` - The point after the segment
+/// * `t` - The curve parameter in [0, 1]
+pub fn catmull_rom<T: Interpolate>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T {
+    hermite(p1, (p2 - p0) * 0.5, p2, (p3 - p1) * 0.5, t)
+}
+
+/// A Catmull-Rom spline passing through a list of points
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CatmullRomSpline<T> {
+    pub points: Vec<T>
+}
+
+impl<T: Interpolate> CatmullRomSpline<T> {
+    /// Creates a new `CatmullRomSpline`
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points the spline passes through
+    pub fn new(points: Vec<T>) -> Self {
+        CatmullRomSpline { points }
+    }
+
+    /// Evaluate the spline
+    ///
+    /// The end points are repeated so the spline starts at the first
+    /// point and ends at the last
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The curve parameter in [0, 1] across the whole spline
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spline has no points
+    pub fn evaluate(&self, t: f32) -> T {
+        let count = self.points.len();
+        assert!(count > 0, "CatmullRomSpline has no points");
+        if count == 1 {
+            return self.points[0];
+        }
+
+        let segments = count - 1;
+        let scaled = t.clamp(0.0, 1.0) * segments as f32;
+        let segment = (scaled as usize).min(segments - 1);
+        let local_t = scaled - segment as f32;
+
+        let point = |i: isize| self.points[i.clamp(0, count as isize - 1) as usize];
+        let i = segment as isize;
+        catmull_rom(point(i - 1), point(i), point(i + 1), point(i + 2), local_t)
+    }
+}
+
+/// A standard easing function
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut
+}
+
+impl Ease {
+    /// Apply the easing function
+    ///
+    /// Maps 0 to 0 and 1 to 1, `Back` and `Elastic` overshoot in between
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The linear progress, clamped to [0, 1]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => out(Ease::QuadIn, t),
+            Ease::QuadInOut => in_out(Ease::QuadIn, t),
+            Ease::CubicIn => t * t * t,
+            Ease::CubicOut => out(Ease::CubicIn, t),
+            Ease::CubicInOut => in_out(Ease::CubicIn, t),
+            Ease::QuartIn => t * t * t * t,
+            Ease::QuartOut => out(Ease::QuartIn, t),
+            Ease::QuartInOut => in_out(Ease::QuartIn, t),
+            Ease::QuintIn => t * t * t * t * t,
+            Ease::QuintOut => out(Ease::QuintIn, t),
+            Ease::QuintInOut => in_out(Ease::QuintIn, t),
+            Ease::SineIn => 1.0 - (t * PI * 0.5).cos(),
+            Ease::SineOut => out(Ease::SineIn, t),
+            Ease::SineInOut => in_out(Ease::SineIn, t),
+            Ease::ExpoIn => if t == 0.0 { 0.0 } else { 2f32.powf(10.0 * t - 10.0) },
+            Ease::ExpoOut => out(Ease::ExpoIn, t),
+            Ease::ExpoInOut => in_out(Ease::ExpoIn, t),
+            Ease::CircIn => 1.0 - (1.0 - t * t).sqrt(),
+            Ease::CircOut => out(Ease::CircIn, t),
+            Ease::CircInOut => in_out(Ease::CircIn, t),
+            Ease::BackIn => {
+                const OVERSHOOT: f32 = 1.70158;
+                t * t * ((OVERSHOOT + 1.0) * t - OVERSHOOT)
+            },
+            Ease::BackOut => out(Ease::BackIn, t),
+            Ease::BackInOut => in_out(Ease::BackIn, t),
+            Ease::ElasticIn => {
+                if t == 0.0 || t == 1.0 { return t; }
+                -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * (2.0 * PI / 3.0)).sin()
+            },
+            Ease::ElasticOut => out(Ease::ElasticIn, t),
+            Ease::ElasticInOut => in_out(Ease::ElasticIn, t),
+            Ease::BounceIn => out(Ease::BounceOut, t),
+            Ease::BounceOut => {
+                const N: f32 = 7.5625;
+                const D: f32 = 2.75;
+                if t < 1.0 / D {
+                    N * t * t
+                } else if t < 2.0 / D {
+                    let t = t - 1.5 / D;
+                    N * t * t + 0.75
+                } else if t < 2.5 / D {
+                    let t = t - 2.25 / D;
+                    N * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D;
+                    N * t * t + 0.984375
+                }
+            },
+            Ease::BounceInOut => in_out(Ease::BounceIn, t)
+        }
+    }
+}
+
+/// Mirror an ease in function into an ease out function
+fn out(ease_in: Ease, t: f32) -> f32 {
+    1.0 - ease_in.apply(1.0 - t)
+}
+
+/// Combine an ease in function and its mirror into an ease in out function
+fn in_out(ease_in: Ease, t: f32) -> f32 {
+    if t < 0.5 {
+        ease_in.apply(t * 2.0) * 0.5
+    } else {
+        1.0 - ease_in.apply((1.0 - t) * 2.0) * 0.5
+    }
+}
+
+/// How a `Tween` behaves once it reaches the end
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TweenMode {
+    /// Stop at the end value
+    #[default]
+    Once,
+    /// Restart from the start value
+    Loop,
+    /// Reverse direction back to the start value, and so on
+    PingPong
+}
+
+/// Animates a value between two end points over time
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Tween<T> {
+    pub from: T,
+    pub to: T,
+    pub duration: f32,
+    pub ease: Ease,
+    pub mode: TweenMode,
+    elapsed: f32
+}
+
+impl<T: Interpolate> Tween<T> {
+    /// Creates a new `Tween` that plays once
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The start value
+    /// * `to` - The end value
+    /// * `duration` - The duration in seconds
+    /// * `ease` - The easing function to apply
+    pub fn new(from: T, to: T, duration: f32, ease: Ease) -> Self {
+        Tween { from, to, duration, ease, mode: TweenMode::Once, elapsed: 0.0 }
+    }
+
+    /// Get this tween with a different mode
+    pub fn with_mode(mut self, mode: TweenMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Advance the tween and get the new value
+    ///
+    /// # Arguments
+    ///
+    /// * `delta_time` - The time step in seconds
+    pub fn update(&mut self, delta_time: f32) -> T {
+        self.elapsed += delta_time;
+        if self.mode == TweenMode::Once {
+            self.elapsed = self.elapsed.min(self.duration);
+        }
+        self.value()
+    }
+
+    /// Get the current value
+    pub fn value(&self) -> T {
+        lerp(self.from, self.to, self.ease.apply(self.progress()))
+    }
+
+    /// Get the linear progress in [0, 1] before easing,
+    /// accounting for looping and ping-pong
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+
+        let cycles = self.elapsed / self.duration;
+        match self.mode {
+            TweenMode::Once => cycles.min(1.0),
+            TweenMode::Loop => cycles - cycles.floor(),
+            TweenMode::PingPong => {
+                let phase = cycles % 2.0;
+                if phase <= 1.0 { phase } else { 2.0 - phase }
+            }
+        }
+    }
+
+    /// Get the time elapsed in seconds
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Check if the tween has finished, looping tweens never finish
+    pub fn is_finished(&self) -> bool {
+        self.mode == TweenMode::Once && self.elapsed >= self.duration
+    }
+
+    /// Restart the tween from the start value
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{vec2::Vec2f, vec3::Vec3f, vec4::Vec4f};
+
+    const EASES: [Ease; 31] = [
+        Ease::Linear,
+        Ease::QuadIn, Ease::QuadOut, Ease::QuadInOut,
+        Ease::CubicIn, Ease::CubicOut, Ease::CubicInOut,
+        Ease::QuartIn, Ease::QuartOut, Ease::QuartInOut,
+        Ease::QuintIn, Ease::QuintOut, Ease::QuintInOut,
+        Ease::SineIn, Ease::SineOut, Ease::SineInOut,
+        Ease::ExpoIn, Ease::ExpoOut, Ease::ExpoInOut,
+        Ease::CircIn, Ease::CircOut, Ease::CircInOut,
+        Ease::BackIn, Ease::BackOut, Ease::BackInOut,
+        Ease::ElasticIn, Ease::ElasticOut, Ease::ElasticInOut,
+        Ease::BounceIn, Ease::BounceOut, Ease::BounceInOut
+    ];
+
+    #[test]
+    fn eases_hit_end_points() {
+        for ease in EASES {
+            assert!(ease.apply(0.0).abs() < 1e-3, "{:?}(0) = {}", ease, ease.apply(0.0));
+            assert!((ease.apply(1.0) - 1.0).abs() < 1e-3, "{:?}(1) = {}", ease, ease.apply(1.0));
+        }
+        assert_eq!(Ease::QuadInOut.apply(0.5), 0.5);
+        assert_eq!(Ease::QuadOut.apply(0.5), 0.75);
+    }
+
+    #[test]
+    fn curves_pass_through_end_points() {
+        let p0 = Vec2f::new(0.0, 0.0);
+        let p1 = Vec2f::new(1.0, 2.0);
+        let p2 = Vec2f::new(3.0, 2.0);
+        let p3 = Vec2f::new(4.0, 0.0);
+
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.5), Vec2f::new(2.0, 1.5));
+        assert_eq!(quadratic_bezier(p0, p1, p3, 0.5), Vec2f::new(1.5, 1.0));
+        assert_eq!(cubic_bezier_tangent(p0, p1, p2, p3, 0.0), (p1 - p0) * 3.0);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 1.0), p2);
+        assert_eq!(hermite(1.0, 0.0, 3.0, 0.0, 0.5), 2.0);
+    }
+
+    #[test]
+    fn spline_passes_through_points() {
+        let points = vec![
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(1.0, 1.0, 0.0),
+            Vec3f::new(2.0, 0.0, 1.0),
+            Vec3f::new(3.0, 1.0, 1.0),
+            Vec3f::new(4.0, 0.0, 2.0)
+        ];
+        let spline = CatmullRomSpline::new(points.clone());
+        for (i, point) in points.iter().enumerate() {
+            let t = i as f32 / (points.len() - 1) as f32;
+            assert!(Vec3f::distance(spline.evaluate(t), *point) < 1e-5);
+        }
+    }
+
+    #[test]
+    fn tween_modes() {
+        let from = Vec4f::zero();
+        let to = Vec4f::one();
+
+        let mut once = Tween::new(from, to, 2.0, Ease::Linear);
+        assert_eq!(once.update(1.0), Vec4f::splat(0.5));
+        assert!(!once.is_finished());
+        assert_eq!(once.update(5.0), to);
+        assert!(once.is_finished());
+
+        let mut looping = Tween::new(0.0, 10.0, 2.0, Ease::Linear).with_mode(TweenMode::Loop);
+        assert_eq!(looping.update(2.5), 2.5);
+        assert!(!looping.is_finished());
+
+        let mut ping_pong = Tween::new(0.0, 10.0, 2.0, Ease::Linear).with_mode(TweenMode::PingPong);
+        assert_eq!(ping_pong.update(1.0), 5.0);
+        assert_eq!(ping_pong.update(2.0), 5.0);
+        assert_eq!(ping_pong.update(1.0), 0.0);
+        ping_pong.reset();
+        assert_eq!(ping_pong.value(), 0.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,718 @@
+use core::fmt;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::{vec2::{Vec2, Vec2f, Vec2x}, vec3::{Vec3, Vec3f, Vec3x}};
+
+const FRACTIONAL_BITS: u32 = 16;
+
+/// Number of table steps in a full turn, the sine table covers a quarter
+const SIN_STEPS: i64 = 1024;
+const QUARTER_STEPS: usize = (SIN_STEPS / 4) as usize;
+/// Number of table steps for atan over [0, 1]
+const ATAN_STEPS: usize = 256;
+
+/// A Q16.16 fixed-point number
+///
+/// All operations use integer arithmetic, so results are bit-identical
+/// on every platform. Operators wrap on overflow in debug and release
+/// builds alike, like `i32::wrapping_add`, products and quotients keep
+/// the low 32 bits of the full result. Division by zero panics
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[repr(transparent)]
+pub struct Fixed32(i32);
+
+impl Fixed32 {
+    pub const ZERO: Fixed32 = Fixed32(0);
+    pub const ONE: Fixed32 = Fixed32(1 << FRACTIONAL_BITS);
+    pub const HALF: Fixed32 = Fixed32(1 << (FRACTIONAL_BITS - 1));
+    pub const MIN: Fixed32 = Fixed32(i32::MIN);
+    pub const MAX: Fixed32 = Fixed32(i32::MAX);
+    /// The smallest positive value
+    pub const EPSILON: Fixed32 = Fixed32(1);
+    pub const PI: Fixed32 = Fixed32(205887);
+    pub const HALF_PI: Fixed32 = Fixed32(102944);
+    pub const TAU: Fixed32 = Fixed32(411775);
+
+    /// Creates a value from its raw Q16.16 bits
+    pub const fn from_bits(bits: i32) -> Self {
+        Fixed32(bits)
+    }
+
+    /// Get the raw Q16.16 bits
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Creates a value from an integer
+    pub const fn from_int(value: i32) -> Self {
+        Fixed32(value << FRACTIONAL_BITS)
+    }
+
+    /// Creates a value from a fraction
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator` - The numerator
+    /// * `denominator` - The denominator, must be non-zero
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        Fixed32((((numerator as i64) << FRACTIONAL_BITS) / denominator as i64) as i32)
+    }
+
+    /// Creates a value from an f32, rounding to the nearest representable value
+    ///
+    /// Only use this for data that is identical on every machine,
+    /// such as constants or values loaded from files
+    pub fn from_f32(value: f32) -> Self {
+        Fixed32((value as f64 * Self::ONE.0 as f64).round() as i32)
+    }
+
+    /// Convert to an f32, e.g. for rendering
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// Creates a value from an f64, rounding to the nearest representable value
+    pub fn from_f64(value: f64) -> Self {
+        Fixed32((value * Self::ONE.0 as f64).round() as i32)
+    }
+
+    /// Convert to an f64
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::ONE.0 as f64
+    }
+
+    /// Get the integer part, rounding towards negative infinity
+    pub const fn to_int(self) -> i32 {
+        self.0 >> FRACTIONAL_BITS
+    }
+
+    /// Round down to an integer value
+    pub const fn floor(self) -> Self {
+        Fixed32(self.0 & !(Self::ONE.0 - 1))
+    }
+
+    /// Round up to an integer value
+    pub const fn ceil(self) -> Self {
+        Fixed32(self.0.wrapping_add(Self::ONE.0 - 1)).floor()
+    }
+
+    /// Round to the nearest integer value, halves round up
+    pub const fn round(self) -> Self {
+        Fixed32(self.0.wrapping_add(Self::HALF.0)).floor()
+    }
+
+    /// Get the fractional part, always in [0, 1)
+    pub const fn fract(self) -> Self {
+        Fixed32(self.0 & (Self::ONE.0 - 1))
+    }
+
+    /// Get the absolute value, `MIN` wraps to itself
+    pub const fn abs(self) -> Self {
+        Fixed32(self.0.wrapping_abs())
+    }
+
+    /// Get -1, 0 or 1 depending on the sign
+    pub const fn signum(self) -> Self {
+        Fixed32::from_int(self.0.signum())
+    }
+
+    /// Get the square root, negative values return zero
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        Fixed32(isqrt((self.0 as u64) << FRACTIONAL_BITS) as i32)
+    }
+
+    /// Get the sine of an angle in radians
+    pub fn sin(self) -> Self {
+        sin_steps(self.to_sin_phase())
+    }
+
+    /// Get the cosine of an angle in radians
+    pub fn cos(self) -> Self {
+        sin_steps(self.to_sin_phase() + ((QUARTER_STEPS as i64) << FRACTIONAL_BITS))
+    }
+
+    /// Get the sine and cosine of an angle in radians
+    pub fn sin_cos(self) -> (Self, Self) {
+        let phase = self.to_sin_phase();
+        (sin_steps(phase), sin_steps(phase + ((QUARTER_STEPS as i64) << FRACTIONAL_BITS)))
+    }
+
+    /// Get the tangent of an angle in radians, saturating near the asymptotes
+    pub fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        if cos.0 == 0 {
+            return if sin.0 >= 0 { Self::MAX } else { Self::MIN };
+        }
+        let tan = ((sin.0 as i64) << FRACTIONAL_BITS) / cos.0 as i64;
+        Fixed32(tan.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    /// Get the angle in radians, in [-pi, pi], between the x axis and the point (x, y)
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The y coordinate
+    /// * `x` - The x coordinate
+    pub fn atan2(y: Self, x: Self) -> Self {
+        if x.0 == 0 && y.0 == 0 {
+            return Self::ZERO;
+        }
+
+        let (ax, ay) = ((x.0 as i64).abs(), (y.0 as i64).abs());
+        // Reduce to an octant so the ratio is in [0, 1]
+        let angle = if ay <= ax {
+            atan_unit((ay << FRACTIONAL_BITS) / ax)
+        } else {
+            Self::HALF_PI.0 as i64 - atan_unit((ax << FRACTIONAL_BITS) / ay)
+        };
+        let angle = if x.0 < 0 { Self::PI.0 as i64 - angle } else { angle };
+        Fixed32(if y.0 < 0 { -angle } else { angle } as i32)
+    }
+
+    /// Get the smaller of two values
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Get the larger of two values
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Clamp the value between `min` and `max`
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+
+    /// Linearly interpolate between two values
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The value at `t = 0`
+    /// * `to` - The value at `t = 1`
+    /// * `t` - The interpolation factor
+    pub fn lerp(from: Self, to: Self, t: Self) -> Self {
+        from + (to - from) * t
+    }
+
+    /// Convert the angle to a Q16.16 phase in sine table steps
+    fn to_sin_phase(self) -> i64 {
+        (self.0 as i64 * (SIN_STEPS << FRACTIONAL_BITS)) / Self::TAU.0 as i64
+    }
+}
+
+/// Sample the sine table at a Q16.16 phase in table steps
+fn sin_steps(phase: i64) -> Fixed32 {
+    let phase = phase.rem_euclid(SIN_STEPS << FRACTIONAL_BITS);
+    let step = (phase >> FRACTIONAL_BITS) as usize;
+    let fraction = phase & ((1 << FRACTIONAL_BITS) - 1);
+    let quadrant = step / QUARTER_STEPS;
+    let i = step % QUARTER_STEPS;
+
+    // Odd quadrants read the table backwards, the second half is negated
+    let (a, b) = match quadrant {
+        0 | 2 => (SIN_TABLE[i], SIN_TABLE[i + 1]),
+        _ => (SIN_TABLE[QUARTER_STEPS - i], SIN_TABLE[QUARTER_STEPS - i - 1])
+    };
+    let value = a as i64 + (((b - a) as i64 * fraction) >> FRACTIONAL_BITS);
+    Fixed32(if quadrant >= 2 { -value } else { value } as i32)
+}
+
+/// Get atan of a Q16.16 ratio in [0, 1] as Q16.16 radians
+fn atan_unit(ratio: i64) -> i64 {
+    let scaled = ratio * ATAN_STEPS as i64;
+    let step = ((scaled >> FRACTIONAL_BITS) as usize).min(ATAN_STEPS - 1);
+    let fraction = scaled - ((step as i64) << FRACTIONAL_BITS);
+    let (a, b) = (ATAN_TABLE[step] as i64, ATAN_TABLE[step + 1] as i64);
+    a + (((b - a) * fraction) >> FRACTIONAL_BITS)
+}
+
+/// Integer square root, rounded down
+const fn isqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+    // Newton's method from an initial guess above the root
+    let mut x = 1u64 << ((64 - value.leading_zeros()).div_ceil(2));
+    loop {
+        let next = (x + value / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+// Tables are generated at compile time with 2.62 fixed-point series,
+// so they do not depend on the platform's float implementation
+const TABLE_BITS: u32 = 62;
+const TABLE_ONE: i128 = 1 << TABLE_BITS;
+const PI_2_62: i128 = 14488038916154245685;
+
+const fn table_mul(a: i128, b: i128) -> i128 {
+    (a * b) >> TABLE_BITS
+}
+
+const fn table_to_fixed(value: i128) -> i32 {
+    ((value + (1 << (TABLE_BITS - FRACTIONAL_BITS - 1))) >> (TABLE_BITS - FRACTIONAL_BITS)) as i32
+}
+
+/// sin(x) for x in [0, pi/2] using the Taylor series
+const fn table_sin(x: i128) -> i128 {
+    let x2 = table_mul(x, x);
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    while n < 15 {
+        term = -table_mul(term, x2) / ((2 * n) * (2 * n + 1));
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+/// atan(x) for x in [0, 1] using the Taylor series after halving the angle
+const fn table_atan(x: i128) -> i128 {
+    // atan(x) = 2 atan(x / (1 + sqrt(1 + x^2))), which reduces x to at most tan(pi/8)
+    let root = table_sqrt(TABLE_ONE + table_mul(x, x));
+    let x = (x << TABLE_BITS) / (TABLE_ONE + root);
+    let x2 = table_mul(x, x);
+    let mut power = x;
+    let mut sum = x;
+    let mut n = 1;
+    while n < 24 {
+        power = -table_mul(power, x2);
+        sum += power / (2 * n + 1);
+        n += 1;
+    }
+    2 * sum
+}
+
+/// sqrt(x) in 2.62 fixed-point
+const fn table_sqrt(x: i128) -> i128 {
+    let value = (x as u128) << TABLE_BITS;
+    let mut root = 1u128 << ((128 - value.leading_zeros()).div_ceil(2));
+    loop {
+        let next = (root + value / root) / 2;
+        if next >= root {
+            return root as i128;
+        }
+        root = next;
+    }
+}
+
+/// sin over a quarter turn, in Q16.16
+const SIN_TABLE: [i32; QUARTER_STEPS + 1] = {
+    let mut table = [0; QUARTER_STEPS + 1];
+    let mut i = 0;
+    while i <= QUARTER_STEPS {
+        let angle = PI_2_62 / 2 * i as i128 / QUARTER_STEPS as i128;
+        table[i] = table_to_fixed(table_sin(angle));
+        i += 1;
+    }
+    table
+};
+
+/// atan over [0, 1], in Q16.16 radians
+const ATAN_TABLE: [i32; ATAN_STEPS + 1] = {
+    let mut table = [0; ATAN_STEPS + 1];
+    let mut i = 0;
+    while i <= ATAN_STEPS {
+        table[i] = table_to_fixed(table_atan(TABLE_ONE * i as i128 / ATAN_STEPS as i128));
+        i += 1;
+    }
+    table
+};
+
+impl From<i32> for Fixed32 {
+    fn from(value: i32) -> Self {
+        Fixed32::from_int(value)
+    }
+}
+
+impl From<Fixed32> for f32 {
+    fn from(value: Fixed32) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<Fixed32> for f64 {
+    fn from(value: Fixed32) -> Self {
+        value.to_f64()
+    }
+}
+
+impl_op_ex!(+ |a: &Fixed32, b: &Fixed32| -> Fixed32 { Fixed32(a.0.wrapping_add(b.0)) });
+impl_op_ex!(- |a: &Fixed32, b: &Fixed32| -> Fixed32 { Fixed32(a.0.wrapping_sub(b.0)) });
+impl_op_ex!(* |a: &Fixed32, b: &Fixed32| -> Fixed32 {
+    Fixed32(((a.0 as i64 * b.0 as i64) >> FRACTIONAL_BITS) as i32)
+});
+impl_op_ex!(/ |a: &Fixed32, b: &Fixed32| -> Fixed32 {
+    Fixed32((((a.0 as i64) << FRACTIONAL_BITS) / b.0 as i64) as i32)
+});
+impl_op_ex!(% |a: &Fixed32, b: &Fixed32| -> Fixed32 { Fixed32(a.0.wrapping_rem(b.0)) });
+impl_op_ex!(- |a: &Fixed32| -> Fixed32 { Fixed32(a.0.wrapping_neg()) });
+
+impl_op_ex_commutative!(* |a: &Fixed32, b: &i32| -> Fixed32 { Fixed32(a.0.wrapping_mul(*b)) });
+impl_op_ex!(/ |a: &Fixed32, b: &i32| -> Fixed32 { Fixed32(a.0.wrapping_div(*b)) });
+
+impl_op_ex!(+= |a: &mut Fixed32, b: &Fixed32| { *a = *a + b; });
+impl_op_ex!(-= |a: &mut Fixed32, b: &Fixed32| { *a = *a - b; });
+impl_op_ex!(*= |a: &mut Fixed32, b: &Fixed32| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut Fixed32, b: &Fixed32| { *a = *a / b; });
+
+impl fmt::Display for Fixed32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl Vec2x {
+    /// Creates vector: (0, 0)
+    pub const fn zero() -> Self {
+        Vec2 { x: Fixed32::ZERO, y: Fixed32::ZERO }
+    }
+
+    /// Creates vector: (1, 1)
+    pub const fn one() -> Self {
+        Vec2 { x: Fixed32::ONE, y: Fixed32::ONE }
+    }
+
+    /// Creates vector: (1, 0)
+    pub const fn right() -> Self {
+        Vec2 { x: Fixed32::ONE, y: Fixed32::ZERO }
+    }
+
+    /// Creates vector: (0, 1)
+    pub const fn up() -> Self {
+        Vec2 { x: Fixed32::ZERO, y: Fixed32::ONE }
+    }
+
+    /// Creates a vector from integer components
+    pub const fn from_ints(x: i32, y: i32) -> Self {
+        Vec2 { x: Fixed32::from_int(x), y: Fixed32::from_int(y) }
+    }
+
+    /// Creates a vector from a float vector, see `Fixed32::from_f32`
+    pub fn from_vec2f(v: Vec2f) -> Self {
+        Vec2 { x: Fixed32::from_f32(v.x), y: Fixed32::from_f32(v.y) }
+    }
+
+    /// Convert to a float vector, e.g. for rendering
+    pub fn to_vec2f(self) -> Vec2f {
+        Vec2 { x: self.x.to_f32(), y: self.y.to_f32() }
+    }
+
+    /// Calculate the dot product of two vectors
+    pub fn dot(lhs: Self, rhs: Self) -> Fixed32 {
+        lhs.x * rhs.x + lhs.y * rhs.y
+    }
+
+    /// Get the squared length of the vector
+    ///
+    /// Overflows for lengths above ~181, use `magnitude` for large vectors
+    pub fn sqr_magnitude(self) -> Fixed32 {
+        Self::dot(self, self)
+    }
+
+    /// Get the length of the vector
+    pub fn magnitude(self) -> Fixed32 {
+        magnitude(&[self.x, self.y])
+    }
+
+    /// Calculates the distance between two points in 2D space
+    pub fn distance(lhs: Self, rhs: Self) -> Fixed32 {
+        (lhs - rhs).magnitude()
+    }
+
+    /// Normalize this vector (Scale of length 1)
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Get this vector normalized (Scale of length 1)
+    pub fn normalized(self) -> Self {
+        let mag = self.magnitude();
+        if mag == Fixed32::ZERO { return Self::zero(); }
+        Vec2 { x: self.x / mag, y: self.y / mag }
+    }
+
+    /// Get the component-wise minimum of two vectors
+    pub fn min(lhs: Self, rhs: Self) -> Self {
+        Vec2 { x: lhs.x.min(rhs.x), y: lhs.y.min(rhs.y) }
+    }
+
+    /// Get the component-wise maximum of two vectors
+    pub fn max(lhs: Self, rhs: Self) -> Self {
+        Vec2 { x: lhs.x.max(rhs.x), y: lhs.y.max(rhs.y) }
+    }
+
+    /// Get the absolute value of each component
+    pub fn abs(self) -> Self {
+        Vec2 { x: self.x.abs(), y: self.y.abs() }
+    }
+
+    /// Linearly interpolate between two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The vector at `t = 0`
+    /// * `to` - The vector at `t = 1`
+    /// * `t` - The interpolation factor
+    pub fn lerp(from: Self, to: Self, t: Fixed32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Vec3x {
+    /// Creates vector: (0, 0, 0)
+    pub const fn zero() -> Self {
+        Vec3 { x: Fixed32::ZERO, y: Fixed32::ZERO, z: Fixed32::ZERO }
+    }
+
+    /// Creates vector: (1, 1, 1)
+    pub const fn one() -> Self {
+        Vec3 { x: Fixed32::ONE, y: Fixed32::ONE, z: Fixed32::ONE }
+    }
+
+    /// Creates vector: (1, 0, 0)
+    pub const fn right() -> Self {
+        Vec3 { x: Fixed32::ONE, y: Fixed32::ZERO, z: Fixed32::ZERO }
+    }
+
+    /// Creates vector: (0, 1, 0)
+    pub const fn up() -> Self {
+        Vec3 { x: Fixed32::ZERO, y: Fixed32::ONE, z: Fixed32::ZERO }
+    }
+
+    /// Creates vector: (0, 0, 1)
+    pub const fn forward() -> Self {
+        Vec3 { x: Fixed32::ZERO, y: Fixed32::ZERO, z: Fixed32::ONE }
+    }
+
+    /// Creates a vector from integer components
+    pub const fn from_ints(x: i32, y: i32, z: i32) -> Self {
+        Vec3 { x: Fixed32::from_int(x), y: Fixed32::from_int(y), z: Fixed32::from_int(z) }
+    }
+
+    /// Creates a vector from a float vector, see `Fixed32::from_f32`
+    pub fn from_vec3f(v: Vec3f) -> Self {
+        Vec3 { x: Fixed32::from_f32(v.x), y: Fixed32::from_f32(v.y), z: Fixed32::from_f32(v.z) }
+    }
+
+    /// Convert to a float vector, e.g. for rendering
+    pub fn to_vec3f(self) -> Vec3f {
+        Vec3 { x: self.x.to_f32(), y: self.y.to_f32(), z: self.z.to_f32() }
+    }
+
+    /// Calculate the dot product of two vectors
+    pub fn dot(lhs: Self, rhs: Self) -> Fixed32 {
+        lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z
+    }
+
+    /// Calculate the cross product of two vectors
+    pub fn cross(lhs: Self, rhs: Self) -> Self {
+        Vec3 {
+            x: lhs.y * rhs.z - lhs.z * rhs.y,
+            y: lhs.z * rhs.x - lhs.x * rhs.z,
+            z: lhs.x * rhs.y - lhs.y * rhs.x
+        }
+    }
+
+    /// Get the squared length of the vector
+    ///
+    /// Overflows for lengths above ~181, use `magnitude` for large vectors
+    pub fn sqr_magnitude(self) -> Fixed32 {
+        Self::dot(self, self)
+    }
+
+    /// Get the length of the vector
+    pub fn magnitude(self) -> Fixed32 {
+        magnitude(&[self.x, self.y, self.z])
+    }
+
+    /// Calculates the distance between two points in 3D space
+    pub fn distance(lhs: Self, rhs: Self) -> Fixed32 {
+        (lhs - rhs).magnitude()
+    }
+
+    /// Normalize this vector (Scale of length 1)
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Get this vector normalized (Scale of length 1)
+    pub fn normalized(self) -> Self {
+        let mag = self.magnitude();
+        if mag == Fixed32::ZERO { return Self::zero(); }
+        Vec3 { x: self.x / mag, y: self.y / mag, z: self.z / mag }
+    }
+
+    /// Get the component-wise minimum of two vectors
+    pub fn min(lhs: Self, rhs: Self) -> Self {
+        Vec3 { x: lhs.x.min(rhs.x), y: lhs.y.min(rhs.y), z: lhs.z.min(rhs.z) }
+    }
+
+    /// Get the component-wise maximum of two vectors
+    pub fn max(lhs: Self, rhs: Self) -> Self {
+        Vec3 { x: lhs.x.max(rhs.x), y: lhs.y.max(rhs.y), z: lhs.z.max(rhs.z) }
+    }
+
+    /// Get the absolute value of each component
+    pub fn abs(self) -> Self {
+        Vec3 { x: self.x.abs(), y: self.y.abs(), z: self.z.abs() }
+    }
+
+    /// Linearly interpolate between two vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The vector at `t = 0`
+    /// * `to` - The vector at `t = 1`
+    /// * `t` - The interpolation factor
+    pub fn lerp(from: Self, to: Self, t: Fixed32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+/// Get the length of a vector without overflowing the intermediate sum
+fn magnitude(components: &[Fixed32]) -> Fixed32 {
+    let sum: u64 = components.iter()
+        .map(|c| (c.0 as i64 * c.0 as i64) as u64)
+        .sum();
+    Fixed32(isqrt(sum).min(i32::MAX as u64) as i32)
+}
+
+impl_op_ex!(+ |a: &Vec2x, b: &Vec2x| -> Vec2x { Vec2 { x: a.x + b.x, y: a.y + b.y } });
+impl_op_ex!(- |a: &Vec2x, b: &Vec2x| -> Vec2x { Vec2 { x: a.x - b.x, y: a.y - b.y } });
+impl_op_ex!(* |a: &Vec2x, b: &Vec2x| -> Vec2x { Vec2 { x: a.x * b.x, y: a.y * b.y } });
+impl_op_ex!(- |a: &Vec2x| -> Vec2x { Vec2 { x: -a.x, y: -a.y } });
+impl_op_ex_commutative!(* |a: &Vec2x, b: &Fixed32| -> Vec2x { Vec2 { x: a.x * b, y: a.y * b } });
+impl_op_ex!(/ |a: &Vec2x, b: &Fixed32| -> Vec2x { Vec2 { x: a.x / b, y: a.y / b } });
+impl_op_ex!(+= |a: &mut Vec2x, b: &Vec2x| { *a = *a + b; });
+impl_op_ex!(-= |a: &mut Vec2x, b: &Vec2x| { *a = *a - b; });
+impl_op_ex!(*= |a: &mut Vec2x, b: &Fixed32| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut Vec2x, b: &Fixed32| { *a = *a / b; });
+
+impl_op_ex!(+ |a: &Vec3x, b: &Vec3x| -> Vec3x { Vec3 { x: a.x + b.x, y: a.y + b.y, z: a.z + b.z } });
+impl_op_ex!(- |a: &Vec3x, b: &Vec3x| -> Vec3x { Vec3 { x: a.x - b.x, y: a.y - b.y, z: a.z - b.z } });
+impl_op_ex!(* |a: &Vec3x, b: &Vec3x| -> Vec3x { Vec3 { x: a.x * b.x, y: a.y * b.y, z: a.z * b.z } });
+impl_op_ex!(- |a: &Vec3x| -> Vec3x { Vec3 { x: -a.x, y: -a.y, z: -a.z } });
+impl_op_ex_commutative!(* |a: &Vec3x, b: &Fixed32| -> Vec3x { Vec3 { x: a.x * b, y: a.y * b, z: a.z * b } });
+impl_op_ex!(/ |a: &Vec3x, b: &Fixed32| -> Vec3x { Vec3 { x: a.x / b, y: a.y / b, z: a.z / b } });
+impl_op_ex!(+= |a: &mut Vec3x, b: &Vec3x| { *a = *a + b; });
+impl_op_ex!(-= |a: &mut Vec3x, b: &Vec3x| { *a = *a - b; });
+impl_op_ex!(*= |a: &mut Vec3x, b: &Fixed32| { *a = *a * b; });
+impl_op_ex!(/= |a: &mut Vec3x, b: &Fixed32| { *a = *a / b; });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed(value: f64) -> Fixed32 {
+        Fixed32::from_f64(value)
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(fixed(1.5) + fixed(2.25), fixed(3.75));
+        assert_eq!(fixed(1.5) * fixed(-2.0), fixed(-3.0));
+        assert_eq!(fixed(7.0) / fixed(2.0), fixed(3.5));
+        assert_eq!(Fixed32::from_ratio(1, 4), fixed(0.25));
+        assert_eq!(fixed(-1.25).floor(), fixed(-2.0));
+        assert_eq!(fixed(-1.25).ceil(), fixed(-1.0));
+        assert_eq!(fixed(2.5).round(), fixed(3.0));
+        assert_eq!(fixed(-1.25).fract(), fixed(0.75));
+        assert_eq!(fixed(-1.25).to_int(), -2);
+        assert_eq!(Fixed32::PI, fixed(core::f64::consts::PI));
+        assert_eq!(Fixed32::TAU, fixed(core::f64::consts::TAU));
+        assert_eq!(Fixed32::HALF_PI, fixed(core::f64::consts::FRAC_PI_2));
+    }
+
+    #[test]
+    fn operators_wrap_on_overflow() {
+        let max = Fixed32::MAX;
+        let min = Fixed32::MIN;
+        assert_eq!(max + Fixed32::EPSILON, min);
+        assert_eq!(min - Fixed32::EPSILON, max);
+        assert_eq!(-min, min);
+        assert_eq!(min.abs(), min);
+        assert_eq!(max * 2, Fixed32::from_bits(-2));
+        assert_eq!(min / -1, min);
+        assert_eq!(min % -Fixed32::EPSILON, Fixed32::ZERO);
+
+        // Products and quotients keep the low 32 bits of the Q16.16 result
+        assert_eq!(max * Fixed32::from_int(2), Fixed32::from_bits(-2));
+        assert_eq!(Fixed32::from_int(20000) * Fixed32::from_int(20000), Fixed32::from_bits((400_000_000i64 << 16) as i32));
+        assert_eq!(max / Fixed32::HALF, Fixed32::from_bits(-2));
+        assert_eq!(min / -Fixed32::ONE, min);
+
+        let mut sum = max;
+        sum += Fixed32::ONE;
+        assert_eq!(sum, Fixed32::from_bits(i32::MIN + 0xFFFF));
+    }
+
+    #[test]
+    fn sqrt() {
+        for i in 0..180 {
+            assert_eq!(Fixed32::from_int(i * i).sqrt(), Fixed32::from_int(i));
+        }
+        assert_eq!(fixed(0.25).sqrt(), fixed(0.5));
+        assert_eq!(fixed(-4.0).sqrt(), Fixed32::ZERO);
+        assert!((fixed(2.0).sqrt().to_f64() - 2f64.sqrt()).abs() < 2e-5);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn trig_matches_float_within_tolerance() {
+        assert_eq!(Fixed32::ZERO.sin(), Fixed32::ZERO);
+        assert_eq!(Fixed32::ZERO.cos(), Fixed32::ONE);
+        assert_eq!(SIN_TABLE[QUARTER_STEPS], Fixed32::ONE.0);
+
+        let mut angle = -10.0;
+        while angle < 10.0 {
+            let (sin, cos) = fixed(angle).sin_cos();
+            let quantized = fixed(angle).to_f64();
+            assert!((sin.to_f64() - quantized.sin()).abs() < 1e-4, "sin({})", angle);
+            assert!((cos.to_f64() - quantized.cos()).abs() < 1e-4, "cos({})", angle);
+            assert_eq!(fixed(angle).sin(), sin);
+            assert_eq!(fixed(angle).cos(), cos);
+            angle += 0.0137;
+        }
+        assert!((fixed(0.5).tan().to_f64() - 0.5f64.tan()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn atan2_matches_float_within_tolerance() {
+        for i in 0..360 {
+            let angle = (i as f64).to_radians();
+            let (y, x) = (angle.sin() * 3.0, angle.cos() * 3.0);
+            let result = Fixed32::atan2(fixed(y), fixed(x)).to_f64();
+            assert!((result - y.atan2(x)).abs() < 1e-4, "atan2 at {} degrees: {}", i, result);
+        }
+        assert_eq!(Fixed32::atan2(Fixed32::ONE, Fixed32::ZERO), Fixed32::HALF_PI);
+        assert_eq!(Fixed32::atan2(Fixed32::ZERO, -Fixed32::ONE), Fixed32::PI);
+    }
+
+    #[test]
+    fn vectors() {
+        let a = Vec3x::from_ints(1, 2, 3);
+        let b = Vec3x::from_ints(4, 5, 6);
+        assert_eq!(Vec3x::dot(a, b), Fixed32::from_int(32));
+        assert_eq!(Vec3x::cross(Vec3x::right(), Vec3x::up()), Vec3x::forward());
+        assert_eq!(Vec3x::from_ints(2, 3, 6).magnitude(), Fixed32::from_int(7));
+        assert_eq!(Vec2x::from_ints(3, 4).magnitude(), Fixed32::from_int(5));
+        // Large vectors do not overflow the intermediate sum
+        assert_eq!(Vec2x::from_ints(3000, 4000).magnitude(), Fixed32::from_int(5000));
+
+        let normalized = Vec3x::from_ints(10, -20, 5).normalized();
+        assert!((normalized.magnitude() - Fixed32::ONE).abs() <= Fixed32::from_bits(4));
+        assert_eq!(Vec2x::zero().normalized(), Vec2x::zero());
+        assert_eq!(Vec2x::from_vec2f(Vec2f::new(0.5, -1.5)).to_vec2f(), Vec2f::new(0.5, -1.5));
+    }
+}
\ No newline at end of file
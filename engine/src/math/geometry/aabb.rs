@@ -0,0 +1,202 @@
+use crate::math::{vec3::Vec3f, vec4::Vec4f, mat4f::Mat4f};
+
+use super::sphere::Sphere;
+
+/// An axis-aligned bounding box
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    pub min: Vec3f,
+    pub max: Vec3f
+}
+
+impl Aabb {
+    /// Creates a new `Aabb`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `min` - The minimum corner
+    /// * `max` - The maximum corner
+    pub const fn new(min: Vec3f, max: Vec3f) -> Self {
+        Aabb { min, max }
+    }
+
+    /// Creates a box from its center and half size
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the box
+    /// * `extents` - Half the size of the box
+    pub fn from_center_extents(center: Vec3f, extents: Vec3f) -> Self {
+        Aabb { min: center - extents, max: center + extents }
+    }
+
+    /// Creates the smallest box containing all points
+    /// 
+    /// Returns `None` if there are no points
+    pub fn from_points(points: impl IntoIterator<Item = Vec3f>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb::new(first, first), |aabb, point| aabb.expanded(point)))
+    }
+
+    /// Get the center of the box
+    pub fn center(&self) -> Vec3f {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Get half the size of the box
+    pub fn extents(&self) -> Vec3f {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Get the size of the box
+    pub fn size(&self) -> Vec3f {
+        self.max - self.min
+    }
+
+    /// Get the eight corners of the box
+    pub fn corners(&self) -> [Vec3f; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            Vec3f::new(min.x, min.y, min.z), Vec3f::new(max.x, min.y, min.z),
+            Vec3f::new(min.x, max.y, min.z), Vec3f::new(max.x, max.y, min.z),
+            Vec3f::new(min.x, min.y, max.z), Vec3f::new(max.x, min.y, max.z),
+            Vec3f::new(min.x, max.y, max.z), Vec3f::new(max.x, max.y, max.z)
+        ]
+    }
+
+    /// Get this box grown to contain a point
+    pub fn expanded(&self, point: Vec3f) -> Self {
+        Aabb { min: Vec3f::min(self.min, point), max: Vec3f::max(self.max, point) }
+    }
+
+    /// Get the smallest box containing both boxes
+    pub fn union(&self, other: &Aabb) -> Self {
+        Aabb { min: Vec3f::min(self.min, other.min), max: Vec3f::max(self.max, other.max) }
+    }
+
+    /// Get the point in the box closest to a point
+    pub fn closest_point(&self, point: Vec3f) -> Vec3f {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Check if a point is inside the box
+    pub fn contains_point(&self, point: Vec3f) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y &&
+        point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    /// Check if this box overlaps another
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y &&
+        self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+
+    /// Check if this box overlaps a sphere
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        (self.closest_point(sphere.center) - sphere.center).sqr_magnitude() <= sphere.radius * sphere.radius
+    }
+
+    /// Get the box containing this box after a transformation
+    /// 
+    /// # Arguments
+    /// 
+    /// * `matrix` - The affine transformation to apply
+    pub fn transformed(&self, matrix: &Mat4f) -> Self {
+        let center = self.center();
+        let extents = self.extents();
+        let new_center = matrix * Vec4f::new(center.x, center.y, center.z, 1.0);
+
+        // Project the extents onto each axis of the transformation
+        let mut new_extents = Vec3f::zero();
+        for r in 0..3 {
+            new_extents[r] =
+                matrix.get(r, 0).abs() * extents.x +
+                matrix.get(r, 1).abs() * extents.y +
+                matrix.get(r, 2).abs() * extents.z;
+        }
+        Self::from_center_extents(new_center.truncate(), new_extents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::quatf::Quatf;
+
+    fn assert_vec_eq(a: Vec3f, b: Vec3f) {
+        assert!((a - b).magnitude() < 1e-5, "{} != {}", a, b);
+    }
+
+    fn unit() -> Aabb {
+        Aabb::new(Vec3f::zero(), Vec3f::one())
+    }
+
+    #[test]
+    fn contains_points_on_and_inside_the_faces() {
+        let aabb = unit();
+        assert!(aabb.contains_point(Vec3f::splat(0.5)));
+        assert!(aabb.contains_point(Vec3f::new(1.0, 0.0, 0.5)));
+        assert!(!aabb.contains_point(Vec3f::new(1.01, 0.5, 0.5)));
+        assert!(!aabb.contains_point(Vec3f::new(0.5, -0.01, 0.5)));
+    }
+
+    #[test]
+    fn intersects_boxes() {
+        let aabb = unit();
+        assert!(aabb.intersects(&Aabb::new(Vec3f::splat(0.5), Vec3f::splat(2.0))));
+        assert!(aabb.intersects(&Aabb::new(Vec3f::splat(0.25), Vec3f::splat(0.75))));
+        // Touching faces count as overlapping
+        assert!(aabb.intersects(&Aabb::new(Vec3f::new(1.0, 0.0, 0.0), Vec3f::new(2.0, 1.0, 1.0))));
+        // Separated on a single axis is enough to miss
+        assert!(!aabb.intersects(&Aabb::new(Vec3f::new(0.0, 0.0, 1.5), Vec3f::new(1.0, 1.0, 2.0))));
+    }
+
+    #[test]
+    fn intersects_spheres() {
+        let aabb = unit();
+        assert!(aabb.intersects_sphere(&Sphere::new(Vec3f::splat(0.5), 0.1)));
+        assert!(aabb.intersects_sphere(&Sphere::new(Vec3f::new(1.5, 0.5, 0.5), 0.6)));
+        assert!(aabb.intersects_sphere(&Sphere::new(Vec3f::new(2.0, 0.5, 0.5), 1.0)));
+        // Near a corner the distance is diagonal, not per axis
+        assert!(!aabb.intersects_sphere(&Sphere::new(Vec3f::splat(1.5), 0.8)));
+        assert!(!aabb.intersects_sphere(&Sphere::new(Vec3f::new(2.0, 0.5, 0.5), 0.9)));
+    }
+
+    #[test]
+    fn from_points_bounds_every_point() {
+        assert!(Aabb::from_points([]).is_none());
+        let points = [Vec3f::new(1.0, -2.0, 3.0), Vec3f::new(-1.0, 4.0, 0.0), Vec3f::new(0.0, 0.0, 5.0)];
+        let aabb = Aabb::from_points(points).unwrap();
+        assert_eq!(aabb, Aabb::new(Vec3f::new(-1.0, -2.0, 0.0), Vec3f::new(1.0, 4.0, 5.0)));
+        assert!(points.iter().all(|&point| aabb.contains_point(point)));
+    }
+
+    #[test]
+    fn transformed_bounds_the_transformed_corners() {
+        let aabb = Aabb::new(Vec3f::zero(), Vec3f::new(2.0, 1.0, 1.0));
+
+        let translation = Vec3f::new(10.0, 0.0, -3.0);
+        let moved = aabb.transformed(&Mat4f::translate(translation));
+        assert_vec_eq(moved.min, aabb.min + translation);
+        assert_vec_eq(moved.max, aabb.max + translation);
+
+        // A quarter turn swaps the x and y sizes
+        let rotation = Quatf::from_axis_angle(Vec3f::forward(), std::f32::consts::FRAC_PI_2);
+        let matrix = Mat4f::translate(translation) * Mat4f::rotate(rotation);
+        let turned = aabb.transformed(&matrix);
+        assert_vec_eq(turned.size(), Vec3f::new(1.0, 2.0, 1.0));
+        assert_vec_eq(turned.center(), rotation * aabb.center() + translation);
+        for corner in aabb.corners() {
+            let corner = rotation * corner + translation;
+            assert!(Aabb::from_center_extents(turned.center(), turned.extents() + Vec3f::splat(1e-5)).contains_point(corner));
+        }
+
+        // Non-uniform scale scales the extents, not only the center
+        let scaled = aabb.transformed(&Mat4f::scale(Vec3f::new(2.0, 3.0, 1.0)));
+        assert_vec_eq(scaled.max, Vec3f::new(4.0, 3.0, 1.0));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::math::{vec3::Vec3f, mat4f::Mat4f};
+
+use super::{plane::Plane, sphere::Sphere, aabb::Aabb};
+
+/// A convex volume bounded by six planes, with normals facing inwards
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum {
+    /// Left, right, bottom, top, near and far planes
+    pub planes: [Plane; 6]
+}
+
+impl Frustum {
+    /// Creates a new `Frustum`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `planes` - The left, right, bottom, top, near and far planes, facing inwards
+    pub const fn new(planes: [Plane; 6]) -> Self {
+        Frustum { planes }
+    }
+
+    /// Extract the frustum from a view-projection matrix.
+    /// The frustum is in the space the matrix transforms from (world space for view-projection).
+    /// 
+    /// Expects clip space depth in [0, 1], as produced by `Mat4f::persp_fov` and `Mat4f::ortho_off_center`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `view_projection` - The view-projection matrix
+    pub fn from_view_projection(view_projection: &Mat4f) -> Self {
+        let row = |r| Plane::new(
+            Vec3f::new(view_projection.get(r, 0), view_projection.get(r, 1), view_projection.get(r, 2)),
+            view_projection.get(r, 3));
+        let add = |a: Plane, b: Plane| Plane::new(a.normal + b.normal, a.distance + b.distance);
+        let sub = |a: Plane, b: Plane| Plane::new(a.normal - b.normal, a.distance - b.distance);
+
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+        Frustum { planes: [
+            add(w, x).normalized(),
+            sub(w, x).normalized(),
+            add(w, y).normalized(),
+            sub(w, y).normalized(),
+            z.normalized(),
+            sub(w, z).normalized()
+        ] }
+    }
+
+    /// Check if a point is inside the frustum
+    pub fn contains_point(&self, point: Vec3f) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Check if a sphere is at least partially inside the frustum
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    /// Check if a box is at least partially inside the frustum
+    /// 
+    /// Conservative, boxes near the corners of the frustum may pass
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            // The corner furthest along the plane normal
+            let positive = Vec3f::new(
+                if plane.normal.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+                if plane.normal.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+                if plane.normal.z >= 0.0 { aabb.max.z } else { aabb.min.z });
+            plane.signed_distance(positive) >= 0.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perspective_frustum_culls_spheres() {
+        let view_projection =
+            Mat4f::persp_fov(f32::to_radians(90.0), 1.0, 0.1, 100.0) *
+            Mat4f::look_at(Vec3f::zero(), Vec3f::forward(), Vec3f::up());
+        let frustum = Frustum::from_view_projection(&view_projection);
+
+        assert!(frustum.contains_point(Vec3f::new(0.0, 0.0, 10.0)));
+        assert!(!frustum.contains_point(Vec3f::new(0.0, 0.0, -10.0)));
+        assert!(frustum.intersects_sphere(&Sphere::new(Vec3f::new(0.0, 0.0, 50.0), 1.0)));
+        // Behind the camera, beyond the far plane and off to the side
+        assert!(!frustum.intersects_sphere(&Sphere::new(Vec3f::new(0.0, 0.0, -5.0), 1.0)));
+        assert!(!frustum.intersects_sphere(&Sphere::new(Vec3f::new(0.0, 0.0, 110.0), 1.0)));
+        assert!(!frustum.intersects_sphere(&Sphere::new(Vec3f::new(20.0, 0.0, 10.0), 1.0)));
+        // Straddling the right plane (x = z)
+        assert!(frustum.intersects_sphere(&Sphere::new(Vec3f::new(10.5, 0.0, 10.0), 1.0)));
+    }
+
+    #[test]
+    fn orthographic_frustum_culls_boxes() {
+        let frustum = Frustum::from_view_projection(
+            &Mat4f::ortho_off_center(0.0, 1280.0, 720.0, 0.0, -1.0, 1.0));
+
+        let inside = Aabb::new(Vec3f::new(10.0, 10.0, 0.0), Vec3f::new(20.0, 20.0, 0.0));
+        let straddling = Aabb::new(Vec3f::new(-10.0, 10.0, 0.0), Vec3f::new(10.0, 20.0, 0.0));
+        let outside = Aabb::new(Vec3f::new(1300.0, 10.0, 0.0), Vec3f::new(1400.0, 20.0, 0.0));
+        assert!(frustum.intersects_aabb(&inside));
+        assert!(frustum.intersects_aabb(&straddling));
+        assert!(!frustum.intersects_aabb(&outside));
+    }
+}
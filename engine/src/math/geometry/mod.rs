@@ -0,0 +1,7 @@
+pub mod aabb;
+pub mod sphere;
+pub mod plane;
+pub mod ray;
+pub mod frustum;
+pub mod obb;
+pub mod rect2;
@@ -0,0 +1,135 @@
+use crate::math::{vec3::Vec3f, quatf::Quatf};
+
+use super::aabb::Aabb;
+
+/// An oriented bounding box
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obb {
+    pub center: Vec3f,
+    pub half_extents: Vec3f,
+    pub rotation: Quatf
+}
+
+impl Obb {
+    /// Creates a new `Obb`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the box
+    /// * `half_extents` - Half the size of the box along each local axis
+    /// * `rotation` - The orientation of the box
+    pub const fn new(center: Vec3f, half_extents: Vec3f, rotation: Quatf) -> Self {
+        Obb { center, half_extents, rotation }
+    }
+
+    /// Get the local x, y and z axes of the box
+    pub fn axes(&self) -> [Vec3f; 3] {
+        [
+            self.rotation * Vec3f::right(),
+            self.rotation * Vec3f::up(),
+            self.rotation * Vec3f::forward()
+        ]
+    }
+
+    /// Check if a point is inside the box
+    pub fn contains_point(&self, point: Vec3f) -> bool {
+        let local = (self.rotation.inverse() * (point - self.center)).abs();
+        local.x <= self.half_extents.x &&
+        local.y <= self.half_extents.y &&
+        local.z <= self.half_extents.z
+    }
+
+    /// Get the point in the box closest to a point
+    pub fn closest_point(&self, point: Vec3f) -> Vec3f {
+        let local = self.rotation.inverse() * (point - self.center);
+        self.center + self.rotation * local.clamp(-self.half_extents, self.half_extents)
+    }
+
+    /// Get the smallest axis-aligned box containing this box
+    pub fn bounds(&self) -> Aabb {
+        let [x, y, z] = self.axes();
+        let extents =
+            x.abs() * self.half_extents.x +
+            y.abs() * self.half_extents.y +
+            z.abs() * self.half_extents.z;
+        Aabb::from_center_extents(self.center, extents)
+    }
+
+    /// Check if this box overlaps another, using the separating axis theorem
+    pub fn intersects(&self, other: &Obb) -> bool {
+        let axes_a = self.axes();
+        let axes_b = other.axes();
+        let offset = other.center - self.center;
+
+        let separated = |axis: Vec3f| {
+            // Skip degenerate axes from parallel edges
+            if axis.sqr_magnitude() < 1e-6 { return false; }
+
+            let project = |axes: &[Vec3f; 3], half_extents: Vec3f| {
+                Vec3f::dot(axes[0], axis).abs() * half_extents.x +
+                Vec3f::dot(axes[1], axis).abs() * half_extents.y +
+                Vec3f::dot(axes[2], axis).abs() * half_extents.z
+            };
+            Vec3f::dot(offset, axis).abs() >
+                project(&axes_a, self.half_extents) + project(&axes_b, other.half_extents)
+        };
+
+        for i in 0..3 {
+            if separated(axes_a[i]) || separated(axes_b[i]) { return false; }
+            for axis_b in axes_b {
+                if separated(Vec3f::cross(axes_a[i], axis_b)) { return false; }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    fn turned(center: Vec3f) -> Obb {
+        Obb::new(center, Vec3f::one(), Quatf::from_axis_angle(Vec3f::up(), FRAC_PI_4))
+    }
+
+    #[test]
+    fn contains_points_in_local_space() {
+        let obb = turned(Vec3f::zero());
+        let diagonal = 2.0f32.sqrt();
+        // The corners point along the world axes after the eighth turn
+        assert!(obb.contains_point(Vec3f::new(diagonal - 0.01, 0.0, 0.0)));
+        assert!(!obb.contains_point(Vec3f::new(diagonal + 0.01, 0.0, 0.0)));
+        assert!(!obb.contains_point(Vec3f::new(0.9, 0.0, 0.9)));
+        assert!(obb.contains_point(Vec3f::new(0.0, 1.0, 0.0)));
+        assert!(!obb.contains_point(Vec3f::new(0.0, 1.01, 0.0)));
+    }
+
+    #[test]
+    fn bounds_contain_the_rotated_box() {
+        let bounds = turned(Vec3f::new(1.0, 2.0, 3.0)).bounds();
+        let diagonal = 2.0f32.sqrt();
+        assert!((bounds.extents() - Vec3f::new(diagonal, 1.0, diagonal)).magnitude() < 1e-5);
+        assert_eq!(bounds.center(), Vec3f::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn intersects_with_separating_axes() {
+        let axis_aligned = |center| Obb::new(center, Vec3f::one(), Quatf::identity());
+        let obb = axis_aligned(Vec3f::zero());
+        assert!(obb.intersects(&axis_aligned(Vec3f::new(1.5, 0.5, 0.0))));
+        // Touching faces
+        assert!(obb.intersects(&axis_aligned(Vec3f::new(2.0, 0.0, 0.0))));
+        assert!(!obb.intersects(&axis_aligned(Vec3f::new(2.01, 0.0, 0.0))));
+
+        // The turned box's corner reaches sqrt(2) along x, so its bounds overlap
+        // at this distance but a face axis separates the boxes
+        let distance = 1.0 + 2.0f32.sqrt();
+        assert!(obb.intersects(&turned(Vec3f::new(distance - 0.01, 0.0, 0.0))));
+        assert!(!obb.intersects(&turned(Vec3f::new(distance + 0.01, 0.0, 0.0))));
+        let gap = turned(Vec3f::new(2.2, 0.0, 2.2));
+        assert!(obb.bounds().intersects(&gap.bounds()));
+        assert!(!obb.intersects(&gap));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::math::vec3::Vec3f;
+
+/// An infinite plane, the points `p` where `dot(normal, p) + distance = 0`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+    pub normal: Vec3f,
+    pub distance: f32
+}
+
+impl Plane {
+    /// Creates a new `Plane`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `normal` - The normal of the plane
+    /// * `distance` - The signed distance from the plane to the origin along the normal
+    pub const fn new(normal: Vec3f, distance: f32) -> Self {
+        Plane { normal, distance }
+    }
+
+    /// Creates a plane passing through a point
+    /// 
+    /// # Arguments
+    /// 
+    /// * `point` - A point on the plane
+    /// * `normal` - The normal of the plane
+    pub fn from_point_normal(point: Vec3f, normal: Vec3f) -> Self {
+        let normal = normal.normalized();
+        Plane { normal, distance: -Vec3f::dot(normal, point) }
+    }
+
+    /// Creates a plane passing through three points.
+    /// The normal faces the side from which the points appear clockwise.
+    pub fn from_points(a: Vec3f, b: Vec3f, c: Vec3f) -> Self {
+        Self::from_point_normal(a, Vec3f::cross(b - a, c - a))
+    }
+
+    /// Get this plane with a normal of length 1
+    pub fn normalized(self) -> Self {
+        let mag = self.normal.magnitude();
+        if mag == 0.0 { return self; }
+
+        Plane { normal: self.normal / mag, distance: self.distance / mag }
+    }
+
+    /// Get the signed distance from the plane to a point.
+    /// Positive on the side the normal faces.
+    pub fn signed_distance(&self, point: Vec3f) -> f32 {
+        Vec3f::dot(self.normal, point) + self.distance
+    }
+
+    /// Get the point on the plane closest to a point
+    pub fn closest_point(&self, point: Vec3f) -> Vec3f {
+        point - self.normal * self.signed_distance(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_points_passes_through_each_point() {
+        let (a, b, c) = (Vec3f::new(0.0, 0.0, 2.0), Vec3f::new(3.0, 0.0, 2.0), Vec3f::new(0.0, 5.0, 2.0));
+        let plane = Plane::from_points(a, b, c);
+        assert_eq!(plane.normal, Vec3f::forward());
+        assert_eq!(plane.distance, -2.0);
+        for point in [a, b, c] {
+            assert_eq!(plane.signed_distance(point), 0.0);
+        }
+        assert_eq!(plane.signed_distance(Vec3f::new(7.0, -1.0, 5.0)), 3.0);
+        assert_eq!(plane.signed_distance(Vec3f::zero()), -2.0);
+
+        // Swapping the winding flips the normal
+        let flipped = Plane::from_points(a, c, b);
+        assert_eq!(flipped.normal, Vec3f::back());
+        assert_eq!(flipped.signed_distance(Vec3f::zero()), 2.0);
+    }
+
+    #[test]
+    fn closest_point_is_on_the_plane() {
+        let plane = Plane::from_point_normal(Vec3f::new(0.0, 1.0, 0.0), Vec3f::new(0.0, 4.0, 0.0));
+        assert_eq!(plane.normal, Vec3f::up());
+        assert_eq!(plane.closest_point(Vec3f::new(2.0, 5.0, 3.0)), Vec3f::new(2.0, 1.0, 3.0));
+        assert_eq!(Plane::new(Vec3f::new(0.0, 0.0, 2.0), 4.0).normalized(), Plane::new(Vec3f::forward(), 2.0));
+    }
+}
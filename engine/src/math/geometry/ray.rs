@@ -0,0 +1,170 @@
+use crate::math::vec3::Vec3f;
+
+use super::{aabb::Aabb, sphere::Sphere, plane::Plane, obb::Obb};
+
+/// A half-line starting at an origin
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray {
+    pub origin: Vec3f,
+    pub direction: Vec3f
+}
+
+impl Ray {
+    /// Creates a new `Ray`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `origin` - The start of the ray
+    /// * `direction` - The direction of the ray, normalized on creation
+    pub fn new(origin: Vec3f, direction: Vec3f) -> Self {
+        Ray { origin, direction: direction.normalized() }
+    }
+
+    /// Get the point at a distance along the ray
+    pub fn at(&self, distance: f32) -> Vec3f {
+        self.origin + self.direction * distance
+    }
+
+    /// Intersect the ray with a plane
+    /// 
+    /// Returns the distance along the ray to the hit, if any
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denom = Vec3f::dot(plane.normal, self.direction);
+        if denom.abs() < f32::EPSILON { return None; }
+
+        let t = -plane.signed_distance(self.origin) / denom;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Intersect the ray with an axis-aligned box
+    /// 
+    /// Returns the distance along the ray to the hit, if any.
+    /// Rays starting inside the box hit at distance 0.
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+
+        for axis in 0..3 {
+            let inv_dir = 1.0 / self.direction[axis];
+            let mut t0 = (aabb.min[axis] - self.origin[axis]) * inv_dir;
+            let mut t1 = (aabb.max[axis] - self.origin[axis]) * inv_dir;
+            if t0 > t1 { std::mem::swap(&mut t0, &mut t1); }
+
+            // NaN comparisons are false, so parallel rays on a slab boundary are kept
+            if t0 > t_min { t_min = t0; }
+            if t1 < t_max { t_max = t1; }
+            if t_max < t_min { return None; }
+        }
+        Some(t_min)
+    }
+
+    /// Intersect the ray with an oriented box
+    /// 
+    /// Returns the distance along the ray to the hit, if any.
+    /// Rays starting inside the box hit at distance 0.
+    pub fn intersect_obb(&self, obb: &Obb) -> Option<f32> {
+        let inv_rotation = obb.rotation.inverse();
+        let local = Ray {
+            origin: inv_rotation * (self.origin - obb.center),
+            direction: inv_rotation * self.direction
+        };
+        local.intersect_aabb(&Aabb::new(-obb.half_extents, obb.half_extents))
+    }
+
+    /// Intersect the ray with a sphere
+    /// 
+    /// Returns the distance along the ray to the hit, if any.
+    /// Rays starting inside the sphere hit at distance 0.
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        let to_origin = self.origin - sphere.center;
+        let c = to_origin.sqr_magnitude() - sphere.radius * sphere.radius;
+        if c <= 0.0 { return Some(0.0); }
+
+        let b = Vec3f::dot(to_origin, self.direction);
+        // Outside and pointing away
+        if b > 0.0 { return None; }
+
+        let discriminant = b * b - c;
+        if discriminant < 0.0 { return None; }
+        Some(-b - discriminant.sqrt())
+    }
+
+    /// Intersect the ray with a triangle, from either side
+    /// 
+    /// Returns the distance along the ray to the hit, if any
+    pub fn intersect_triangle(&self, a: Vec3f, b: Vec3f, c: Vec3f) -> Option<f32> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = Vec3f::cross(self.direction, edge2);
+        let det = Vec3f::dot(edge1, p);
+        // Parallel to the triangle
+        if det.abs() < f32::EPSILON { return None; }
+        let inv_det = 1.0 / det;
+
+        let s = self.origin - a;
+        let u = Vec3f::dot(s, p) * inv_det;
+        if !(0.0..=1.0).contains(&u) { return None; }
+
+        let q = Vec3f::cross(s, edge1);
+        let v = Vec3f::dot(self.direction, q) * inv_det;
+        if v < 0.0 || u + v > 1.0 { return None; }
+
+        let t = Vec3f::dot(edge2, q) * inv_det;
+        (t >= 0.0).then_some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::quatf::Quatf;
+
+    fn assert_hit(hit: Option<f32>, expected: f32) {
+        let t = hit.expect("expected a hit");
+        assert!((t - expected).abs() < 1e-4, "{} != {}", t, expected);
+    }
+
+    #[test]
+    fn ray_hits_aabb() {
+        let aabb = Aabb::new(Vec3f::new(-1.0, -1.0, 4.0), Vec3f::new(1.0, 1.0, 6.0));
+        assert_hit(Ray::new(Vec3f::zero(), Vec3f::forward()).intersect_aabb(&aabb), 4.0);
+        assert_hit(Ray::new(Vec3f::new(0.0, 0.0, 5.0), Vec3f::up()).intersect_aabb(&aabb), 0.0);
+        assert!(Ray::new(Vec3f::zero(), Vec3f::back()).intersect_aabb(&aabb).is_none());
+        assert!(Ray::new(Vec3f::new(2.0, 0.0, 0.0), Vec3f::forward()).intersect_aabb(&aabb).is_none());
+    }
+
+    #[test]
+    fn ray_hits_obb() {
+        let obb = Obb::new(
+            Vec3f::new(0.0, 0.0, 5.0),
+            Vec3f::new(1.0, 1.0, 1.0),
+            Quatf::from_axis_angle(Vec3f::up(), std::f32::consts::FRAC_PI_4));
+        assert_hit(Ray::new(Vec3f::zero(), Vec3f::forward()).intersect_obb(&obb), 5.0 - 2.0f32.sqrt());
+    }
+
+    #[test]
+    fn ray_hits_sphere() {
+        let sphere = Sphere::new(Vec3f::new(0.0, 0.0, 10.0), 2.0);
+        assert_hit(Ray::new(Vec3f::zero(), Vec3f::forward()).intersect_sphere(&sphere), 8.0);
+        assert_hit(Ray::new(sphere.center, Vec3f::up()).intersect_sphere(&sphere), 0.0);
+        assert!(Ray::new(Vec3f::zero(), Vec3f::back()).intersect_sphere(&sphere).is_none());
+        assert!(Ray::new(Vec3f::new(3.0, 0.0, 0.0), Vec3f::forward()).intersect_sphere(&sphere).is_none());
+    }
+
+    #[test]
+    fn ray_hits_triangle() {
+        let (a, b, c) = (Vec3f::new(-1.0, -1.0, 3.0), Vec3f::new(1.0, -1.0, 3.0), Vec3f::new(0.0, 1.0, 3.0));
+        assert_hit(Ray::new(Vec3f::zero(), Vec3f::forward()).intersect_triangle(a, b, c), 3.0);
+        assert_hit(Ray::new(Vec3f::new(0.0, 0.0, 6.0), Vec3f::back()).intersect_triangle(a, b, c), 3.0);
+        assert!(Ray::new(Vec3f::new(2.0, 0.0, 0.0), Vec3f::forward()).intersect_triangle(a, b, c).is_none());
+        assert!(Ray::new(Vec3f::zero(), Vec3f::up()).intersect_triangle(a, b, c).is_none());
+    }
+
+    #[test]
+    fn ray_hits_plane() {
+        let plane = Plane::from_point_normal(Vec3f::new(0.0, -2.0, 0.0), Vec3f::up());
+        assert_hit(Ray::new(Vec3f::zero(), Vec3f::down()).intersect_plane(&plane), 2.0);
+        assert!(Ray::new(Vec3f::zero(), Vec3f::up()).intersect_plane(&plane).is_none());
+    }
+}
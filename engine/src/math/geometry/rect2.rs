@@ -0,0 +1,123 @@
+use crate::math::vec2::Vec2f;
+
+/// An axis-aligned 2D rectangle
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect2 {
+    pub min: Vec2f,
+    pub max: Vec2f
+}
+
+impl Rect2 {
+    /// Creates a new `Rect2`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `min` - The bottom-left corner
+    /// * `max` - The top-right corner
+    pub const fn new(min: Vec2f, max: Vec2f) -> Self {
+        Rect2 { min, max }
+    }
+
+    /// Creates a rectangle from its bottom-left corner and size
+    /// 
+    /// # Arguments
+    /// 
+    /// * `position` - The bottom-left corner
+    /// * `size` - The size of the rectangle
+    pub fn from_position_size(position: Vec2f, size: Vec2f) -> Self {
+        Rect2 { min: position, max: position + size }
+    }
+
+    /// Get the width of the rectangle
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    /// Get the height of the rectangle
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    /// Get the size of the rectangle
+    pub fn size(&self) -> Vec2f {
+        self.max - self.min
+    }
+
+    /// Get the center of the rectangle
+    pub fn center(&self) -> Vec2f {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Check if a point is inside the rectangle
+    pub fn contains_point(&self, point: Vec2f) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    /// Check if another rectangle is entirely inside this one
+    pub fn contains(&self, other: &Rect2) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Check if this rectangle overlaps another
+    pub fn overlaps(&self, other: &Rect2) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y
+    }
+
+    /// Get the overlapping area of two rectangles, if any
+    pub fn intersection(&self, other: &Rect2) -> Option<Rect2> {
+        self.overlaps(other).then(|| Rect2 {
+            min: Vec2f::max(self.min, other.min),
+            max: Vec2f::min(self.max, other.max)
+        })
+    }
+
+    /// Get the smallest rectangle containing both rectangles
+    pub fn union(&self, other: &Rect2) -> Rect2 {
+        Rect2 {
+            min: Vec2f::min(self.min, other.min),
+            max: Vec2f::max(self.max, other.max)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit() -> Rect2 {
+        Rect2::new(Vec2f::zero(), Vec2f::one())
+    }
+
+    #[test]
+    fn contains_points_and_rects() {
+        let rect = unit();
+        assert!(rect.contains_point(Vec2f::splat(0.5)));
+        assert!(rect.contains_point(Vec2f::new(1.0, 0.0)));
+        assert!(!rect.contains_point(Vec2f::new(1.01, 0.5)));
+
+        assert!(rect.contains(&Rect2::new(Vec2f::splat(0.25), Vec2f::splat(0.75))));
+        assert!(rect.contains(&rect));
+        assert!(!rect.contains(&Rect2::new(Vec2f::splat(0.5), Vec2f::splat(1.5))));
+    }
+
+    #[test]
+    fn overlaps_and_intersections() {
+        let rect = unit();
+        let other = Rect2::from_position_size(Vec2f::splat(0.5), Vec2f::one());
+        assert!(rect.overlaps(&other));
+        assert_eq!(rect.intersection(&other), Some(Rect2::new(Vec2f::splat(0.5), Vec2f::one())));
+
+        // Touching edges overlap with an empty intersection
+        let touching = Rect2::from_position_size(Vec2f::new(1.0, 0.0), Vec2f::one());
+        assert!(rect.overlaps(&touching));
+        assert_eq!(rect.intersection(&touching).unwrap().width(), 0.0);
+
+        let apart = Rect2::from_position_size(Vec2f::new(0.0, 1.5), Vec2f::one());
+        assert!(!rect.overlaps(&apart));
+        assert_eq!(rect.intersection(&apart), None);
+        assert_eq!(rect.union(&apart), Rect2::new(Vec2f::zero(), Vec2f::new(1.0, 2.5)));
+    }
+}
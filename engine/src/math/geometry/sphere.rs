@@ -0,0 +1,76 @@
+use crate::math::vec3::Vec3f;
+
+use super::aabb::Aabb;
+
+/// A sphere
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    pub center: Vec3f,
+    pub radius: f32
+}
+
+impl Sphere {
+    /// Creates a new `Sphere`
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - The center of the sphere
+    /// * `radius` - The radius of the sphere
+    pub const fn new(center: Vec3f, radius: f32) -> Self {
+        Sphere { center, radius }
+    }
+
+    /// Check if a point is inside the sphere
+    pub fn contains_point(&self, point: Vec3f) -> bool {
+        (point - self.center).sqr_magnitude() <= self.radius * self.radius
+    }
+
+    /// Check if this sphere overlaps another
+    pub fn intersects(&self, other: &Sphere) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).sqr_magnitude() <= radii * radii
+    }
+
+    /// Check if this sphere overlaps a box
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        aabb.intersects_sphere(self)
+    }
+
+    /// Get the smallest box containing the sphere
+    pub fn bounds(&self) -> Aabb {
+        Aabb::from_center_extents(self.center, Vec3f::splat(self.radius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_points() {
+        let sphere = Sphere::new(Vec3f::new(1.0, 0.0, 0.0), 2.0);
+        assert!(sphere.contains_point(Vec3f::new(1.0, 1.0, 1.0)));
+        assert!(sphere.contains_point(Vec3f::new(3.0, 0.0, 0.0)));
+        assert!(!sphere.contains_point(Vec3f::new(2.5, 1.5, 0.0)));
+    }
+
+    #[test]
+    fn intersects_spheres() {
+        let sphere = Sphere::new(Vec3f::zero(), 1.0);
+        assert!(sphere.intersects(&Sphere::new(Vec3f::new(1.0, 1.0, 0.0), 1.0)));
+        assert!(sphere.intersects(&Sphere::new(Vec3f::new(0.0, 3.0, 0.0), 2.0)));
+        assert!(!sphere.intersects(&Sphere::new(Vec3f::new(0.0, 3.01, 0.0), 2.0)));
+        assert!(sphere.intersects(&Sphere::new(Vec3f::zero(), 0.1)));
+    }
+
+    #[test]
+    fn intersects_boxes() {
+        let aabb = Aabb::new(Vec3f::zero(), Vec3f::one());
+        assert!(Sphere::new(Vec3f::new(1.5, 0.5, 0.5), 0.5).intersects_aabb(&aabb));
+        assert!(!Sphere::new(Vec3f::new(1.5, 0.5, 0.5), 0.49).intersects_aabb(&aabb));
+        assert!(!Sphere::new(Vec3f::splat(2.0), 1.7).intersects_aabb(&aabb));
+        assert!(Sphere::new(Vec3f::splat(2.0), 1.8).intersects_aabb(&aabb));
+        assert_eq!(Sphere::new(Vec3f::one(), 2.0).bounds(), Aabb::new(Vec3f::splat(-1.0), Vec3f::splat(3.0)));
+    }
+}
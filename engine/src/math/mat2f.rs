@@ -0,0 +1,223 @@
+use core::fmt;
+
+use auto_ops::{impl_op_ex_commutative, impl_op_ex};
+
+use super::vec2::Vec2f;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Mat2f {
+    pub values: [f32; 2 * 2]
+}
+
+const fn cell(x: usize, y: usize) -> usize {
+    x * 2 + y
+}
+
+impl Mat2f {
+    /// Creates an identity matrix
+    pub const fn identity() -> Self {
+        Mat2f { values: [
+            1.0, 0.0,
+            0.0, 1.0
+        ] }
+    }
+
+    /// Get a value in the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row in the matrix
+    /// * `column` - The column in the matrix
+    pub const fn get(self, row: usize, column: usize) -> f32 {
+        self.values[cell(column, row)]
+    }
+
+    /// Set a value in the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row in the matrix
+    /// * `column` - The column in the matrix
+    /// * `value` - The value to set
+    pub fn set(&mut self, row: usize, column: usize, value: f32) {
+        self.values[cell(column, row)] = value;
+    }
+
+    /// Creates a matrix from its rows
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - The rows of the matrix, top to bottom
+    pub fn from_rows(rows: [[f32; 2]; 2]) -> Self {
+        let mut matrix = Mat2f { values: [0.0; 2 * 2] };
+        for (row, values) in rows.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                matrix.set(row, column, *value);
+            }
+        }
+        matrix
+    }
+
+    /// Get the rows of the matrix, top to bottom
+    pub fn to_rows(&self) -> [[f32; 2]; 2] {
+        let mut rows = [[0.0; 2]; 2];
+        for (row, values) in rows.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = self.get(row, column);
+            }
+        }
+        rows
+    }
+
+    /// Creates a rotation matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - Angle of rotation (in radians), counter-clockwise
+    pub fn rotate(angle: f32) -> Self {
+        let sin = angle.sin();
+        let cos = angle.cos();
+
+        let mut res = Self::identity();
+        res.values[cell(0, 0)] = cos;
+        res.values[cell(1, 0)] = -sin;
+        res.values[cell(0, 1)] = sin;
+        res.values[cell(1, 1)] = cos;
+        res
+    }
+
+    /// Creates a scale matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale as a 2D vector (s<sub>x</sub>, s<sub>y</sub>)
+    pub const fn scale(scale: Vec2f) -> Self {
+        let mut res = Self::identity();
+        res.values[cell(0, 0)] = scale.x;
+        res.values[cell(1, 1)] = scale.y;
+        res
+    }
+
+    /// Get the transpose of this matrix
+    pub fn transpose(&self) -> Self {
+        let mut res = Mat2f::identity();
+        for r in 0..2 {
+            for c in 0..2 {
+                res.values[cell(r, c)] = self.values[cell(c, r)];
+            }
+        }
+        res
+    }
+
+    /// Calculate the determinant of this matrix
+    pub fn determinant(&self) -> f32 {
+        self.values[cell(0, 0)] * self.values[cell(1, 1)] -
+        self.values[cell(1, 0)] * self.values[cell(0, 1)]
+    }
+
+    /// Get the inverse of this matrix
+    ///
+    /// Returns `None` if the matrix is singular (determinant of 0)
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 { return None; }
+        let inv_det = 1.0 / det;
+
+        let mut res = Mat2f::identity();
+        res.values[cell(0, 0)] = self.values[cell(1, 1)] * inv_det;
+        res.values[cell(1, 0)] = -self.values[cell(1, 0)] * inv_det;
+        res.values[cell(0, 1)] = -self.values[cell(0, 1)] * inv_det;
+        res.values[cell(1, 1)] = self.values[cell(0, 0)] * inv_det;
+        Some(res)
+    }
+}
+
+impl_op_ex_commutative!(* | a: &Mat2f, b: &f32 | -> Mat2f {
+    Mat2f { values: a.values.map(|value| value * b) }
+});
+
+impl_op_ex!(* | a: &Mat2f, b: &Vec2f | -> Vec2f {
+    Vec2f {
+        x: a.values[cell(0, 0)] * b.x + a.values[cell(1, 0)] * b.y,
+        y: a.values[cell(0, 1)] * b.x + a.values[cell(1, 1)] * b.y
+    }
+});
+
+impl_op_ex!(* | a: &Mat2f, b: &Mat2f | -> Mat2f {
+    let mut res = Mat2f::identity();
+
+    for r in 0..2 {
+        for c in 0..2 {
+            res.values[cell(c, r)] =
+                a.values[cell(0, r)] * b.values[cell(c, 0)] +
+                a.values[cell(1, r)] * b.values[cell(c, 1)];
+        }
+    }
+    res
+});
+
+/// Serialized as an array of rows
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mat2f {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_rows().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mat2f {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[[f32; 2]; 2]>::deserialize(deserializer).map(Mat2f::from_rows)
+    }
+}
+
+impl fmt::Display for Mat2f {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"\
+        ┌ {}, {} ┐\n\
+        └ {}, {} ┘",
+        self.values[cell(0, 0)],
+        self.values[cell(1, 0)],
+        self.values[cell(0, 1)],
+        self.values[cell(1, 1)])
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_mat_eq(a: Mat2f, b: Mat2f) {
+        for i in 0..4 {
+            assert!((a.values[i] - b.values[i]).abs() < EPSILON, "\n{}\n!=\n{}", a, b);
+        }
+    }
+
+    #[test]
+    fn determinant_and_inverse() {
+        let m = Mat2f::from_rows([[4.0, 7.0], [2.0, 6.0]]);
+        assert!((m.determinant() - 10.0).abs() < EPSILON);
+        let inverse = m.inverse().unwrap();
+        assert_mat_eq(inverse, Mat2f::from_rows([[0.6, -0.7], [-0.2, 0.4]]));
+        assert_mat_eq(m * inverse, Mat2f::identity());
+        assert_mat_eq(inverse * m, Mat2f::identity());
+    }
+
+    #[test]
+    fn inverse_of_singular_is_none() {
+        assert!(Mat2f::from_rows([[1.0, 2.0], [2.0, 4.0]]).inverse().is_none());
+        assert!(Mat2f::scale(Vec2f::new(3.0, 0.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn rotation_and_scale_apply_to_vectors() {
+        let rotated = Mat2f::rotate(std::f32::consts::FRAC_PI_2) * Vec2f::new(1.0, 0.0);
+        assert!(Vec2f::distance(rotated, Vec2f::new(0.0, 1.0)) < EPSILON);
+        assert_eq!(Mat2f::scale(Vec2f::new(2.0, 3.0)) * Vec2f::new(1.0, 1.0), Vec2f::new(2.0, 3.0));
+        assert!((Mat2f::rotate(1.3).determinant() - 1.0).abs() < EPSILON);
+        assert_eq!(Mat2f::from_rows(Mat2f::rotate(0.4).to_rows()), Mat2f::rotate(0.4));
+    }
+}
\ No newline at end of file
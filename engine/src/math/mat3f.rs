@@ -0,0 +1,368 @@
+use core::fmt;
+
+use auto_ops::{impl_op_ex_commutative, impl_op_ex};
+
+use super::{vec2::Vec2f, vec3::Vec3f, mat2f::Mat2f, mat4f::Mat4f};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Mat3f {
+    pub values: [f32; 3 * 3]
+}
+
+const fn cell(x: usize, y: usize) -> usize {
+    x * 3 + y
+}
+
+impl Mat3f {
+    /// Creates an identity matrix
+    pub const fn identity() -> Self {
+        Mat3f { values: [
+            1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0
+        ] }
+    }
+
+    /// Get a value in the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row in the matrix
+    /// * `column` - The column in the matrix
+    pub const fn get(self, row: usize, column: usize) -> f32 {
+        self.values[cell(column, row)]
+    }
+
+    /// Set a value in the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row in the matrix
+    /// * `column` - The column in the matrix
+    /// * `value` - The value to set
+    pub fn set(&mut self, row: usize, column: usize, value: f32) {
+        self.values[cell(column, row)] = value;
+    }
+
+    /// Creates a matrix from its rows
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - The rows of the matrix, top to bottom
+    pub fn from_rows(rows: [[f32; 3]; 3]) -> Self {
+        let mut matrix = Mat3f { values: [0.0; 3 * 3] };
+        for (row, values) in rows.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                matrix.set(row, column, *value);
+            }
+        }
+        matrix
+    }
+
+    /// Get the rows of the matrix, top to bottom
+    pub fn to_rows(&self) -> [[f32; 3]; 3] {
+        let mut rows = [[0.0; 3]; 3];
+        for (row, values) in rows.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = self.get(row, column);
+            }
+        }
+        rows
+    }
+
+    /// Creates a matrix from the upper-left 3x3 of a 4x4 matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The 4x4 matrix
+    pub fn from_mat4f(matrix: &Mat4f) -> Self {
+        let mut res = Self::identity();
+        for r in 0..3 {
+            for c in 0..3 {
+                res.values[cell(c, r)] = matrix.get(r, c);
+            }
+        }
+        res
+    }
+
+    /// Creates a matrix with a 2x2 matrix in the upper-left
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The 2x2 matrix
+    pub fn from_mat2f(matrix: &Mat2f) -> Self {
+        let mut res = Self::identity();
+        for r in 0..2 {
+            for c in 0..2 {
+                res.values[cell(c, r)] = matrix.get(r, c);
+            }
+        }
+        res
+    }
+
+    /// Creates a 2D translation matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The translation as a 2D vector (t<sub>x</sub>, t<sub>y</sub>)
+    pub const fn translate_2d(translation: Vec2f) -> Self {
+        let mut res = Self::identity();
+        res.values[cell(2, 0)] = translation.x;
+        res.values[cell(2, 1)] = translation.y;
+        res
+    }
+
+    /// Creates a 2D rotation matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - Angle of rotation (in radians), counter-clockwise
+    pub fn rotate_2d(angle: f32) -> Self {
+        Self::from_mat2f(&Mat2f::rotate(angle))
+    }
+
+    /// Creates a 2D scale matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale as a 2D vector (s<sub>x</sub>, s<sub>y</sub>)
+    pub const fn scale_2d(scale: Vec2f) -> Self {
+        let mut res = Self::identity();
+        res.values[cell(0, 0)] = scale.x;
+        res.values[cell(1, 1)] = scale.y;
+        res
+    }
+
+    /// Creates a 2D shear matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `shear` - The shear factors as a 2D vector (x along y, y along x)
+    pub const fn shear_2d(shear: Vec2f) -> Self {
+        let mut res = Self::identity();
+        res.values[cell(1, 0)] = shear.x;
+        res.values[cell(0, 1)] = shear.y;
+        res
+    }
+
+    /// Creates a 2D transformation matrix.
+    /// Combines translation, rotation, and scale into a single matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The translation component as a 2D vector (t<sub>x</sub>, t<sub>y</sub>)
+    /// * `rotation` - Angle of rotation (in radians), counter-clockwise
+    /// * `scale` - The scale component as a 2D vector (s<sub>x</sub>, s<sub>y</sub>)
+    pub fn transformation_2d(translation: Vec2f, rotation: f32, scale: Vec2f) -> Self {
+        Self::translate_2d(translation) *
+        Self::rotate_2d(rotation) *
+        Self::scale_2d(scale)
+    }
+
+    /// Transform a 2D point, applying translation
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to transform
+    pub fn transform_point_2d(&self, point: Vec2f) -> Vec2f {
+        let res = self * Vec3f::new(point.x, point.y, 1.0);
+        Vec2f::new(res.x, res.y)
+    }
+
+    /// Transform a 2D direction, ignoring translation
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - The direction to transform
+    pub fn transform_vector_2d(&self, vector: Vec2f) -> Vec2f {
+        let res = self * Vec3f::new(vector.x, vector.y, 0.0);
+        Vec2f::new(res.x, res.y)
+    }
+
+    /// Get the transpose of this matrix
+    pub fn transpose(&self) -> Self {
+        let mut res = Mat3f::identity();
+        for r in 0..3 {
+            for c in 0..3 {
+                res.values[cell(r, c)] = self.values[cell(c, r)];
+            }
+        }
+        res
+    }
+
+    /// Calculate the determinant of this matrix
+    pub fn determinant(&self) -> f32 {
+        let m = |r, c| self.values[cell(c, r)];
+
+        m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1)) -
+        m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0)) +
+        m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+    }
+
+    /// Get the inverse of this matrix
+    ///
+    /// Returns `None` if the matrix is singular (determinant of 0)
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 { return None; }
+        let inv_det = 1.0 / det;
+
+        let m = |r, c| self.values[cell(c, r)];
+        let mut res = Mat3f::identity();
+        let mut set = |r, c, value: f32| res.values[cell(c, r)] = value * inv_det;
+        set(0, 0, m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1));
+        set(0, 1, m(0, 2) * m(2, 1) - m(0, 1) * m(2, 2));
+        set(0, 2, m(0, 1) * m(1, 2) - m(0, 2) * m(1, 1));
+        set(1, 0, m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2));
+        set(1, 1, m(0, 0) * m(2, 2) - m(0, 2) * m(2, 0));
+        set(1, 2, m(0, 2) * m(1, 0) - m(0, 0) * m(1, 2));
+        set(2, 0, m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0));
+        set(2, 1, m(0, 1) * m(2, 0) - m(0, 0) * m(2, 1));
+        set(2, 2, m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0));
+        Some(res)
+    }
+}
+
+impl_op_ex_commutative!(* | a: &Mat3f, b: &f32 | -> Mat3f {
+    Mat3f { values: a.values.map(|value| value * b) }
+});
+
+impl_op_ex!(* | a: &Mat3f, b: &Vec3f | -> Vec3f {
+    Vec3f {
+        x: a.values[cell(0, 0)] * b.x + a.values[cell(1, 0)] * b.y + a.values[cell(2, 0)] * b.z,
+        y: a.values[cell(0, 1)] * b.x + a.values[cell(1, 1)] * b.y + a.values[cell(2, 1)] * b.z,
+        z: a.values[cell(0, 2)] * b.x + a.values[cell(1, 2)] * b.y + a.values[cell(2, 2)] * b.z
+    }
+});
+
+impl_op_ex!(* | a: &Mat3f, b: &Mat3f | -> Mat3f {
+    let mut res = Mat3f::identity();
+
+    for r in 0..3 {
+        for c in 0..3 {
+            res.values[cell(c, r)] =
+                a.values[cell(0, r)] * b.values[cell(c, 0)] +
+                a.values[cell(1, r)] * b.values[cell(c, 1)] +
+                a.values[cell(2, r)] * b.values[cell(c, 2)];
+        }
+    }
+    res
+});
+
+/// Serialized as an array of rows
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mat3f {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_rows().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mat3f {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[[f32; 3]; 3]>::deserialize(deserializer).map(Mat3f::from_rows)
+    }
+}
+
+impl fmt::Display for Mat3f {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"\
+        ┌ {}, {}, {} ┐\n\
+        │ {}, {}, {} │\n\
+        └ {}, {}, {} ┘",
+        self.values[cell(0, 0)],
+        self.values[cell(1, 0)],
+        self.values[cell(2, 0)],
+        self.values[cell(0, 1)],
+        self.values[cell(1, 1)],
+        self.values[cell(2, 1)],
+        self.values[cell(0, 2)],
+        self.values[cell(1, 2)],
+        self.values[cell(2, 2)])
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::math::quatf::Quatf;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_mat_eq(a: Mat3f, b: Mat3f) {
+        for i in 0..9 {
+            assert!((a.values[i] - b.values[i]).abs() < EPSILON, "\n{}\n!=\n{}", a, b);
+        }
+    }
+
+    fn assert_vec_eq(a: Vec2f, b: Vec2f) {
+        assert!(Vec2f::distance(a, b) < EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn determinant_and_inverse() {
+        let m = Mat3f::from_rows([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert!((m.determinant() - 6.0).abs() < EPSILON);
+        let inverse = m.inverse().unwrap();
+        assert_mat_eq(m * inverse, Mat3f::identity());
+        assert_mat_eq(inverse * m, Mat3f::identity());
+
+        let affine = Mat3f::transformation_2d(Vec2f::new(3.0, -1.0), 0.7, Vec2f::new(2.0, 0.5));
+        assert!((affine.determinant() - 1.0).abs() < EPSILON);
+        assert_mat_eq(affine * affine.inverse().unwrap(), Mat3f::identity());
+    }
+
+    #[test]
+    fn inverse_of_singular_is_none() {
+        assert!(Mat3f::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]).inverse().is_none());
+        assert!(Mat3f::scale_2d(Vec2f::new(0.0, 1.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn affine_helpers_compose_on_points() {
+        let point = Vec2f::new(1.0, 0.0);
+        assert_vec_eq(Mat3f::translate_2d(Vec2f::new(2.0, 3.0)).transform_point_2d(point), Vec2f::new(3.0, 3.0));
+        assert_vec_eq(Mat3f::rotate_2d(FRAC_PI_2).transform_point_2d(point), Vec2f::new(0.0, 1.0));
+        assert_vec_eq(Mat3f::shear_2d(Vec2f::new(1.0, 0.0)).transform_point_2d(Vec2f::new(0.0, 2.0)), Vec2f::new(2.0, 2.0));
+
+        // Scale, then rotate, then translate
+        let m = Mat3f::transformation_2d(Vec2f::new(10.0, 0.0), FRAC_PI_2, Vec2f::new(2.0, 1.0));
+        assert_mat_eq(m, Mat3f::translate_2d(Vec2f::new(10.0, 0.0)) * Mat3f::rotate_2d(FRAC_PI_2) * Mat3f::scale_2d(Vec2f::new(2.0, 1.0)));
+        assert_vec_eq(m.transform_point_2d(point), Vec2f::new(10.0, 2.0));
+        // Directions ignore the translation
+        assert_vec_eq(m.transform_vector_2d(point), Vec2f::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn from_mat4f_takes_the_upper_left() {
+        let rotation = Quatf::from_yaw_pitch_roll(0.4, -1.1, 2.3);
+        let m = Mat4f::transformation(Vec3f::new(5.0, 6.0, 7.0), rotation, Vec3f::one());
+        let upper = Mat3f::from_mat4f(&m);
+        for row in 0..3 {
+            for column in 0..3 {
+                assert_eq!(upper.get(row, column), m.get(row, column));
+            }
+        }
+        assert_eq!(Mat3f::from_mat2f(&Mat2f::rotate(0.3)).get(2, 2), 1.0);
+    }
+
+    #[test]
+    fn normal_matrix_is_inverse_transpose() {
+        let scale = Vec3f::new(2.0, 0.5, 4.0);
+        let m = Mat4f::transformation(Vec3f::new(1.0, 2.0, 3.0), Quatf::from_yaw_pitch_roll(0.3, 0.2, 0.1), scale);
+        let normal = m.normal_matrix().unwrap();
+        assert_mat_eq(normal, Mat3f::from_mat4f(&m).inverse().unwrap().transpose());
+
+        // Normals stay perpendicular to their surface under non-uniform scale
+        let surface_normal = Vec3f::new(1.0, 1.0, 0.0).normalized();
+        let tangent = Vec3f::new(1.0, -1.0, 0.0);
+        let upper = Mat3f::from_mat4f(&m);
+        assert!(Vec3f::dot(normal * surface_normal, upper * tangent).abs() < EPSILON);
+        assert!(Vec3f::dot(upper * surface_normal, upper * tangent).abs() > 0.1);
+
+        assert!(Mat4f::scale(Vec3f::new(1.0, 0.0, 1.0)).normal_matrix().is_none());
+    }
+}
\ No newline at end of file
@@ -2,9 +2,10 @@ use core::fmt;
 
 use auto_ops::{impl_op_ex_commutative, impl_op_ex};
 
-use super::{vec3f::Vec3f, vec4f::Vec4f};
+use super::{vec3::Vec3f, vec4::Vec4f, mat3f::Mat3f, quatf::Quatf, simd};
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
 pub struct Mat4f {
     pub values: [f32; 4 * 4]
 }
@@ -45,6 +46,32 @@ impl Mat4f {
         self.values[cell(column, row)] = value;
     }
 
+    /// Creates a matrix from its rows
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rows` - The rows of the matrix, top to bottom
+    pub fn from_rows(rows: [[f32; 4]; 4]) -> Self {
+        let mut matrix = Mat4f { values: [0.0; 4 * 4] };
+        for (row, values) in rows.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                matrix.set(row, column, *value);
+            }
+        }
+        matrix
+    }
+
+    /// Get the rows of the matrix, top to bottom
+    pub fn to_rows(&self) -> [[f32; 4]; 4] {
+        let mut rows = [[0.0; 4]; 4];
+        for (row, values) in rows.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = self.get(row, column);
+            }
+        }
+        rows
+    }
+
     /// Creates a translation matrix
     /// 
     /// # Arguments
@@ -86,6 +113,15 @@ impl Mat4f {
         res
     }
 
+    /// Creates a rotation matrix
+    /// 
+    /// # Arguments
+    /// 
+    /// * `rotation` - The rotation as a quaternion
+    pub fn rotate(rotation: Quatf) -> Self {
+        rotation.to_mat4f()
+    }
+
     /// Creates a scale matrix
     /// 
     /// # Arguments
@@ -94,8 +130,8 @@ impl Mat4f {
     pub const fn scale(scale: Vec3f) -> Self {
         let mut res = Self::identity();
         res.values[cell(0,0)] = scale.x;
-        res.values[cell(1,1)] = scale.x;
-        res.values[cell(2,2)] = scale.x;
+        res.values[cell(1,1)] = scale.y;
+        res.values[cell(2,2)] = scale.z;
         res
     }
 
@@ -105,9 +141,24 @@ impl Mat4f {
     /// # Arguments
     /// 
     /// * `translation` - The translation component as a 3D vector (t<sub>x</sub>, t<sub>y</sub>, t<sub>z</sub>)
-    /// * `rotation` - The rotation component as a 3D vector (r<sub>x</sub>, r<sub>y</sub>, r<sub>z</sub>)
+    /// * `rotation` - The rotation component as a quaternion
     /// * `scale` - The rotation component as a 3D vector (s<sub>x</sub>, s<sub>y</sub>, s<sub>z</sub>)
-    pub fn transformation(translation: Vec3f, rotation: Vec3f, scale: Vec3f) -> Self {
+    pub fn transformation(translation: Vec3f, rotation: Quatf, scale: Vec3f) -> Self {
+        Self::translate(translation) *
+        Self::rotate(rotation) *
+        Self::scale(scale)
+    }
+
+    /// Creates a transformation matrix from euler angles.
+    /// Same as `Mat4f::transformation` with the rotation given as
+    /// (pitch, yaw, roll) in radians.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `translation` - The translation component as a 3D vector (t<sub>x</sub>, t<sub>y</sub>, t<sub>z</sub>)
+    /// * `rotation` - The rotation component as a 3D vector (r<sub>x</sub>, r<sub>y</sub>, r<sub>z</sub>)
+    /// * `scale` - The scale component as a 3D vector (s<sub>x</sub>, s<sub>y</sub>, s<sub>z</sub>)
+    pub fn transformation_euler(translation: Vec3f, rotation: Vec3f, scale: Vec3f) -> Self {
         Self::translate(translation) *
         Self::rotate_yaw_pitch_roll(rotation.y, rotation.x, rotation.z) *
         Self::scale(scale)
@@ -157,6 +208,8 @@ impl Mat4f {
 
     /// Creates a perspective projection matrix.
     /// Centered on the origin.
+    /// The w component of a projected point is its view space depth,
+    /// depth maps from 0 at `near` to 1 at `far` after the perspective divide.
     /// 
     /// # Arguments
     /// 
@@ -175,8 +228,131 @@ impl Mat4f {
         res.values[cell(2, 2)] = q;
         res.values[cell(2, 3)] = 1.0;
         res.values[cell(3, 2)] = -q * near;
+        res.values[cell(3, 3)] = 0.0;
         res
     }
+
+    /// Creates a view matrix looking from a position towards a target
+    /// 
+    /// # Arguments
+    /// 
+    /// * `eye` - The position of the viewer
+    /// * `target` - The position to look at
+    /// * `up` - The up direction of the viewer
+    pub fn look_at(eye: Vec3f, target: Vec3f, up: Vec3f) -> Self {
+        let forward = (target - eye).normalized();
+        let right = Vec3f::cross(up, forward).normalized();
+        let up = Vec3f::cross(forward, right);
+
+        let mut res = Mat4f::identity();
+        res.values[cell(0, 0)] = right.x;
+        res.values[cell(1, 0)] = right.y;
+        res.values[cell(2, 0)] = right.z;
+        res.values[cell(0, 1)] = up.x;
+        res.values[cell(1, 1)] = up.y;
+        res.values[cell(2, 1)] = up.z;
+        res.values[cell(0, 2)] = forward.x;
+        res.values[cell(1, 2)] = forward.y;
+        res.values[cell(2, 2)] = forward.z;
+        res.values[cell(3, 0)] = -Vec3f::dot(right, eye);
+        res.values[cell(3, 1)] = -Vec3f::dot(up, eye);
+        res.values[cell(3, 2)] = -Vec3f::dot(forward, eye);
+        res
+    }
+
+    /// Get the transpose of this matrix
+    pub fn transpose(&self) -> Self {
+        let mut res = Mat4f::identity();
+        for r in 0..4 {
+            for c in 0..4 {
+                res.values[cell(r, c)] = self.values[cell(c, r)];
+            }
+        }
+        res
+    }
+
+    /// Calculate the determinant of this matrix
+    pub fn determinant(&self) -> f32 {
+        simd::mat4_determinant(&self.values)
+    }
+
+    /// Get the inverse of this matrix
+    /// 
+    /// Returns `None` if the matrix is singular (determinant of 0)
+    pub fn inverse(&self) -> Option<Self> {
+        simd::mat4_inverse(&self.values).map(|values| Mat4f { values })
+    }
+
+    /// Multiply each matrix in a slice by this matrix
+    /// 
+    /// # Arguments
+    /// 
+    /// * `matrices` - The matrices to multiply (right hand side)
+    /// * `out` - The results, must be the same length as `matrices`
+    pub fn mul_batch(&self, matrices: &[Mat4f], out: &mut [Mat4f]) {
+        // Safety: Mat4f is a transparent wrapper around [f32; 16]
+        let (matrices, out) = unsafe {(
+            std::slice::from_raw_parts(matrices.as_ptr().cast(), matrices.len()),
+            std::slice::from_raw_parts_mut(out.as_mut_ptr().cast(), out.len())
+        )};
+        simd::mat4_mul_batch(&self.values, matrices, out);
+    }
+
+    /// Transform each vector in a slice by this matrix
+    /// 
+    /// # Arguments
+    /// 
+    /// * `vectors` - The vectors to transform
+    /// * `out` - The results, must be the same length as `vectors`
+    pub fn transform_batch(&self, vectors: &[Vec4f], out: &mut [Vec4f]) {
+        // Safety: Vec4f is a repr(C) struct of four f32s
+        let (vectors, out) = unsafe {(
+            std::slice::from_raw_parts(vectors.as_ptr().cast(), vectors.len()),
+            std::slice::from_raw_parts_mut(out.as_mut_ptr().cast(), out.len())
+        )};
+        simd::mat4_mul_vec4_batch(&self.values, vectors, out);
+    }
+
+    /// Get the normal matrix of this transformation matrix.
+    /// The inverse transpose of the upper-left 3x3,
+    /// used to transform normals under non-uniform scale.
+    /// 
+    /// Returns `None` if the matrix is singular (determinant of 0)
+    pub fn normal_matrix(&self) -> Option<Mat3f> {
+        Mat3f::from_mat4f(self).inverse().map(|inverse| inverse.transpose())
+    }
+
+    /// Split a transformation matrix into its components.
+    /// Inverse of `Mat4f::transformation`.
+    /// 
+    /// Returns (translation, rotation, scale). Shear is discarded.
+    pub fn decompose(&self) -> (Vec3f, Quatf, Vec3f) {
+        let column = |c| Vec3f::new(
+            self.values[cell(c, 0)],
+            self.values[cell(c, 1)],
+            self.values[cell(c, 2)]);
+
+        let translation = column(3);
+        let mut scale = Vec3f::new(
+            column(0).magnitude(),
+            column(1).magnitude(),
+            column(2).magnitude());
+        // A negative determinant means the matrix contains a reflection
+        if self.determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+
+        let mut rotation_matrix = Mat4f::identity();
+        for c in 0..3 {
+            let axis_scale = [scale.x, scale.y, scale.z][c];
+            let axis = if axis_scale == 0.0 { Vec3f::zero() } else { column(c) / axis_scale };
+            rotation_matrix.values[cell(c, 0)] = axis.x;
+            rotation_matrix.values[cell(c, 1)] = axis.y;
+            rotation_matrix.values[cell(c, 2)] = axis.z;
+        }
+
+        (translation, Quatf::from_mat4f(&rotation_matrix), scale)
+    }
 }
 
 impl_op_ex_commutative!(* | a: &Mat4f, b: &f32 | -> Mat4f {
@@ -186,29 +362,28 @@ impl_op_ex_commutative!(* | a: &Mat4f, b: &f32 | -> Mat4f {
 });
 
 impl_op_ex!(* | a: &Mat4f, b: &Vec4f | -> Vec4f {
-    Vec4f {
-        x: a.values[cell(0, 0)] * b.x + a.values[cell(1, 0)] * b.y + a.values[cell(2, 0)] * b.z + a.values[cell(3, 0)] * b.w,
-        y: a.values[cell(0, 1)] * b.x + a.values[cell(1, 1)] * b.y + a.values[cell(2, 1)] * b.z + a.values[cell(3, 1)] * b.w,
-        z: a.values[cell(0, 2)] * b.x + a.values[cell(1, 2)] * b.y + a.values[cell(2, 2)] * b.z + a.values[cell(3, 2)] * b.w,
-        w: a.values[cell(0, 3)] * b.x + a.values[cell(1, 3)] * b.y + a.values[cell(2, 3)] * b.z + a.values[cell(3, 3)] * b.w
-    }
+    Vec4f::from(simd::mat4_mul_vec4(&a.values, &[b.x, b.y, b.z, b.w]))
 });
 
 impl_op_ex!(* | a: &Mat4f, b: &Mat4f | -> Mat4f {
-    let mut res = Mat4f::identity();
-
-    for r in 0..4 {
-        for c in 0..4 {
-            res.values[cell(c, r)] = 
-                a.values[cell(0, r)] * b.values[cell(c, 0)] +
-                a.values[cell(1, r)] * b.values[cell(c, 1)] +
-                a.values[cell(2, r)] * b.values[cell(c, 2)] +
-                a.values[cell(3, r)] * b.values[cell(c, 3)];
-        }
-    }
-    res
+    Mat4f { values: simd::mat4_mul(&a.values, &b.values) }
 });
 
+/// Serialized as an array of rows
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mat4f {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_rows().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mat4f {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[[f32; 4]; 4]>::deserialize(deserializer).map(Mat4f::from_rows)
+    }
+}
+
 impl fmt::Display for Mat4f {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f,"\
@@ -233,4 +408,149 @@ impl fmt::Display for Mat4f {
         self.values[cell(2, 3)],
         self.values[cell(3, 3)])
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn assert_mat_eq(a: Mat4f, b: Mat4f) {
+        for i in 0..16 {
+            assert!((a.values[i] - b.values[i]).abs() < EPSILON, "\n{}\n!=\n{}", a, b);
+        }
+    }
+
+    fn assert_vec_eq(a: Vec3f, b: Vec3f) {
+        assert!(Vec3f::distance(a, b) < EPSILON, "{} != {}", a, b);
+    }
+
+    fn sample_transformation() -> Mat4f {
+        Mat4f::transformation(
+            Vec3f::new(1.0, -2.0, 3.5),
+            Quatf::from_yaw_pitch_roll(0.4, -1.1, 2.3),
+            Vec3f::new(2.0, 0.5, 1.5))
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let m = sample_transformation();
+        let t = m.transpose();
+        for r in 0..4 {
+            for c in 0..4 {
+                assert_eq!(m.get(r, c), t.get(c, r));
+            }
+        }
+        assert_mat_eq(t.transpose(), m);
+    }
+
+    #[test]
+    fn determinant_of_scale_is_product() {
+        let m = Mat4f::scale(Vec3f::new(2.0, 3.0, 4.0));
+        assert!((m.determinant() - 24.0).abs() < EPSILON);
+        assert!((Mat4f::identity().determinant() - 1.0).abs() < EPSILON);
+        assert!((sample_transformation().determinant() - 1.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn determinant_matches_inverse() {
+        let m = Mat4f::persp_fov(1.2, 1.5, 0.5, 20.0) * sample_transformation();
+        let inv = m.inverse().unwrap();
+        assert!((m.determinant() * inv.determinant() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn transformation_euler_matches_quaternion() {
+        let translation = Vec3f::new(1.0, -2.0, 3.5);
+        let euler = Vec3f::new(-1.1, 0.4, 2.3);
+        let scale = Vec3f::new(2.0, 0.5, 1.5);
+        assert_mat_eq(
+            Mat4f::transformation_euler(translation, euler, scale),
+            Mat4f::transformation(translation, Quatf::from_euler(euler), scale));
+    }
+
+    #[test]
+    fn inverse_of_transformation() {
+        let m = sample_transformation();
+        let inv = m.inverse().unwrap();
+        assert_mat_eq(m * inv, Mat4f::identity());
+        assert_mat_eq(inv * m, Mat4f::identity());
+    }
+
+    #[test]
+    fn inverse_of_persp_fov() {
+        let m = Mat4f::persp_fov(f32::to_radians(90.0), 16.0 / 9.0, 0.1, 10.0);
+        let inv = m.inverse().unwrap();
+        assert_mat_eq(m * inv, Mat4f::identity());
+
+        // Unproject the far plane centre back into view space
+        let far = inv * Vec4f::new(0.0, 0.0, 1.0, 1.0);
+        assert!((far.z / far.w - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn scale_uses_each_axis() {
+        let v = Mat4f::scale(Vec3f::new(2.0, 3.0, 4.0)) * Vec4f::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(v, Vec4f::new(2.0, 3.0, 4.0, 1.0));
+    }
+
+    #[test]
+    fn persp_fov_divides_by_view_depth() {
+        let m = Mat4f::persp_fov(f32::to_radians(90.0), 1.0, 0.5, 20.0);
+        assert_eq!(m.get(3, 3), 0.0);
+
+        let project = |z: f32| {
+            let clip = m * Vec4f::new(1.0, 1.0, z, 1.0);
+            assert!((clip.w - z).abs() < EPSILON);
+            clip.z / clip.w
+        };
+        assert!(project(0.5).abs() < EPSILON);
+        assert!((project(20.0) - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn inverse_of_singular_is_none() {
+        assert!(Mat4f::scale(Vec3f::new(1.0, 0.0, 1.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn decompose_round_trips_transformation() {
+        let translation = Vec3f::new(1.0, -2.0, 3.5);
+        let rotation = Quatf::from_yaw_pitch_roll(0.4, -1.1, 2.3);
+        let scale = Vec3f::new(2.0, 0.5, 1.5);
+        let m = Mat4f::transformation(translation, rotation, scale);
+
+        let (t, r, s) = m.decompose();
+        assert_vec_eq(t, translation);
+        assert_vec_eq(s, scale);
+        assert!((Quatf::dot(r, rotation).abs() - 1.0).abs() < EPSILON);
+        assert_mat_eq(Mat4f::transformation(t, r, s), m);
+    }
+
+    #[test]
+    fn decompose_handles_reflection() {
+        let m = Mat4f::transformation(
+            Vec3f::zero(),
+            Quatf::from_yaw_pitch_roll(0.3, 0.2, 0.1),
+            Vec3f::new(-1.0, 2.0, 3.0));
+        let (t, r, s) = m.decompose();
+        assert_mat_eq(Mat4f::transformation(t, r, s), m);
+    }
+
+    #[test]
+    fn look_at_maps_eye_to_origin() {
+        let eye = Vec3f::new(3.0, 2.0, -5.0);
+        let target = Vec3f::new(0.0, 1.0, 0.0);
+        let view = Mat4f::look_at(eye, target, Vec3f::up());
+
+        let origin = view * Vec4f::new(eye.x, eye.y, eye.z, 1.0);
+        assert_vec_eq(Vec3f::new(origin.x, origin.y, origin.z), Vec3f::zero());
+
+        // The target lies straight ahead along +z
+        let ahead = view * Vec4f::new(target.x, target.y, target.z, 1.0);
+        assert_vec_eq(
+            Vec3f::new(ahead.x, ahead.y, ahead.z),
+            Vec3f::new(0.0, 0.0, Vec3f::distance(eye, target)));
+    }
+}
@@ -1,4 +1,33 @@
-pub mod vec2f;
-pub mod vec3f;
-pub mod vec4f;
-pub mod mat4f;
\ No newline at end of file
+pub mod vec2;
+pub mod vec3;
+pub mod vec4;
+
+/// Former home of [`Vec2f`](vec2::Vec2f), kept so existing paths still resolve
+#[deprecated(note = "use poseidon::math::vec2 instead")]
+pub mod vec2f {
+    pub use super::vec2::Vec2f;
+}
+
+/// Former home of [`Vec3f`](vec3::Vec3f), kept so existing paths still resolve
+#[deprecated(note = "use poseidon::math::vec3 instead")]
+pub mod vec3f {
+    pub use super::vec3::Vec3f;
+}
+
+/// Former home of [`Vec4f`](vec4::Vec4f), kept so existing paths still resolve
+#[deprecated(note = "use poseidon::math::vec4 instead")]
+pub mod vec4f {
+    pub use super::vec4::Vec4f;
+}
+
+pub mod mat2f;
+pub mod mat3f;
+pub mod mat4f;
+pub mod quatf;
+pub mod fixed32;
+pub mod geometry;
+pub mod random;
+pub mod noise;
+pub mod curves;
+
+mod simd;
@@ -0,0 +1,466 @@
+use core::fmt;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::{vec3::Vec3f, mat4f::Mat4f};
+
+/// A quaternion with f32 components, used to represent rotations
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quatf {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32
+}
+
+impl Default for Quatf {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Quatf {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates quaternion: (0, 0, 0, 1)
+    pub const fn identity() -> Self {
+        Quatf { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }
+    }
+
+    /// Creates a rotation about an axis
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis of rotation
+    /// * `angle` - Angle of rotation (in radians) about the axis
+    pub fn from_axis_angle(axis: Vec3f, angle: f32) -> Self {
+        let axis = axis.normalized();
+        let half_sin = (angle * 0.5).sin();
+        let half_cos = (angle * 0.5).cos();
+        Quatf {
+            x: axis.x * half_sin,
+            y: axis.y * half_sin,
+            z: axis.z * half_sin,
+            w: half_cos
+        }
+    }
+
+    /// Creates a rotation from euler angles.
+    /// Matches `Mat4f::rotate_yaw_pitch_roll`.
+    ///
+    /// # Arguments
+    ///
+    /// * `yaw` - Angle of rotation (in radians) about the y-axis
+    /// * `pitch` - Angle of rotation (in radians) about the x-axis
+    /// * `roll` - Angle of rotation (in radians) about the z-axis
+    pub fn from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Self {
+        let sin_yaw = (yaw * 0.5).sin();
+        let cos_yaw = (yaw * 0.5).cos();
+        let sin_pitch = (pitch * 0.5).sin();
+        let cos_pitch = (pitch * 0.5).cos();
+        let sin_roll = (roll * 0.5).sin();
+        let cos_roll = (roll * 0.5).cos();
+
+        Quatf {
+            x: cos_yaw * sin_pitch * cos_roll + sin_yaw * cos_pitch * sin_roll,
+            y: sin_yaw * cos_pitch * cos_roll - cos_yaw * sin_pitch * sin_roll,
+            z: cos_yaw * cos_pitch * sin_roll - sin_yaw * sin_pitch * cos_roll,
+            w: cos_yaw * cos_pitch * cos_roll + sin_yaw * sin_pitch * sin_roll
+        }
+    }
+
+    /// Creates a rotation from euler angles
+    ///
+    /// # Arguments
+    ///
+    /// * `euler` - The angles (in radians) about each axis as a 3D vector (r<sub>x</sub>, r<sub>y</sub>, r<sub>z</sub>)
+    pub fn from_euler(euler: Vec3f) -> Self {
+        Self::from_yaw_pitch_roll(euler.y, euler.x, euler.z)
+    }
+
+    /// Creates a rotation from the rotation part of a matrix
+    ///
+    /// The upper 3x3 of the matrix is expected to be a pure rotation
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The rotation matrix
+    pub fn from_mat4f(matrix: &Mat4f) -> Self {
+        let m = |row, column| matrix.get(row, column);
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+        let res = if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+            Quatf {
+                x: (m(2, 1) - m(1, 2)) * s,
+                y: (m(0, 2) - m(2, 0)) * s,
+                z: (m(1, 0) - m(0, 1)) * s,
+                w: 0.25 / s
+            }
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            let s = 2.0 * (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt();
+            Quatf {
+                x: 0.25 * s,
+                y: (m(0, 1) + m(1, 0)) / s,
+                z: (m(0, 2) + m(2, 0)) / s,
+                w: (m(2, 1) - m(1, 2)) / s
+            }
+        } else if m(1, 1) > m(2, 2) {
+            let s = 2.0 * (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt();
+            Quatf {
+                x: (m(0, 1) + m(1, 0)) / s,
+                y: 0.25 * s,
+                z: (m(1, 2) + m(2, 1)) / s,
+                w: (m(0, 2) - m(2, 0)) / s
+            }
+        } else {
+            let s = 2.0 * (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt();
+            Quatf {
+                x: (m(0, 2) + m(2, 0)) / s,
+                y: (m(1, 2) + m(2, 1)) / s,
+                z: 0.25 * s,
+                w: (m(1, 0) - m(0, 1)) / s
+            }
+        };
+        res.normalized()
+    }
+
+    /// Creates a rotation matrix from this quaternion
+    pub fn to_mat4f(self) -> Mat4f {
+        let Quatf { x, y, z, w } = self.normalized();
+
+        let mut res = Mat4f::identity();
+        res.set(0, 0, 1.0 - 2.0 * (y * y + z * z));
+        res.set(0, 1, 2.0 * (x * y - z * w));
+        res.set(0, 2, 2.0 * (x * z + y * w));
+        res.set(1, 0, 2.0 * (x * y + z * w));
+        res.set(1, 1, 1.0 - 2.0 * (x * x + z * z));
+        res.set(1, 2, 2.0 * (y * z - x * w));
+        res.set(2, 0, 2.0 * (x * z - y * w));
+        res.set(2, 1, 2.0 * (y * z + x * w));
+        res.set(2, 2, 1.0 - 2.0 * (x * x + y * y));
+        res
+    }
+
+    /// Get the euler angles (in radians) of this rotation
+    /// as a 3D vector (r<sub>x</sub>, r<sub>y</sub>, r<sub>z</sub>)
+    ///
+    /// Inverse of `from_euler`. When the pitch is ±90° the
+    /// yaw and roll are ambiguous, roll is set to 0 in that case
+    pub fn to_euler(self) -> Vec3f {
+        let matrix = self.to_mat4f();
+        let m = |row, column| matrix.get(row, column);
+
+        let sin_pitch = -m(1, 2);
+        if sin_pitch.abs() >= 0.99999 {
+            // Gimbal lock
+            Vec3f {
+                x: core::f32::consts::FRAC_PI_2.copysign(sin_pitch),
+                y: (-m(2, 0)).atan2(m(0, 0)),
+                z: 0.0
+            }
+        } else {
+            Vec3f {
+                x: sin_pitch.asin(),
+                y: m(0, 2).atan2(m(2, 2)),
+                z: m(1, 0).atan2(m(1, 1))
+            }
+        }
+    }
+
+    /// Get the axis and angle (in radians) of this rotation
+    pub fn to_axis_angle(self) -> (Vec3f, f32) {
+        let q = self.normalized();
+        let angle = 2.0 * q.w.clamp(-1.0, 1.0).acos();
+        let sin_half = (1.0 - q.w * q.w).max(0.0).sqrt();
+        if sin_half < 0.0001 {
+            return (Vec3f::right(), 0.0);
+        }
+        (Vec3f::new(q.x, q.y, q.z) / sin_half, angle)
+    }
+
+    /// Calculate the dot product of two quaternions
+    pub fn dot(lhs: Quatf, rhs: Quatf) -> f32 {
+        lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z + lhs.w * rhs.w
+    }
+
+    /// Get the squared length of the quaternion
+    pub fn sqr_magnitude(self) -> f32 {
+        Self::dot(self, self)
+    }
+
+    /// Get the length of the quaternion
+    pub fn magnitude(self) -> f32 {
+        self.sqr_magnitude().sqrt()
+    }
+
+    /// Normalize this quaternion (Scale of length 1)
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Get this quaternion normalized (Scale of length 1)
+    pub fn normalized(self) -> Quatf {
+        let mag = self.magnitude();
+        if mag == 0.0 { return Quatf::identity(); }
+
+        let scale = 1.0 / mag;
+        Quatf {
+            x: self.x * scale,
+            y: self.y * scale,
+            z: self.z * scale,
+            w: self.w * scale
+        }
+    }
+
+    /// Get the conjugate of this quaternion
+    pub fn conjugate(self) -> Quatf {
+        Quatf { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+
+    /// Get the inverse of this quaternion (the opposite rotation)
+    pub fn inverse(self) -> Quatf {
+        let sqr_mag = self.sqr_magnitude();
+        if sqr_mag == 0.0 { return Quatf::identity(); }
+
+        self.conjugate() * (1.0 / sqr_mag)
+    }
+
+    /// Rotate a vector by this quaternion
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - The vector to rotate
+    pub fn rotate(self, vector: Vec3f) -> Vec3f {
+        let axis = Vec3f::new(self.x, self.y, self.z);
+        let t = 2.0 * Vec3f::cross(axis, vector);
+        vector + self.w * t + Vec3f::cross(axis, t)
+    }
+
+    /// Normalized linear interpolation between two rotations
+    ///
+    /// Cheaper than `slerp` but does not have a constant angular velocity
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The rotation at `t = 0`
+    /// * `to` - The rotation at `t = 1`
+    /// * `t` - The interpolation factor
+    pub fn nlerp(from: Quatf, to: Quatf, t: f32) -> Quatf {
+        // Take the shortest path
+        let to = if Self::dot(from, to) < 0.0 { -to } else { to };
+        (from * (1.0 - t) + to * t).normalized()
+    }
+
+    /// Spherical linear interpolation between two rotations
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The rotation at `t = 0`
+    /// * `to` - The rotation at `t = 1`
+    /// * `t` - The interpolation factor
+    pub fn slerp(from: Quatf, to: Quatf, t: f32) -> Quatf {
+        let mut cos_theta = Self::dot(from, to);
+        // Take the shortest path
+        let to = if cos_theta < 0.0 {
+            cos_theta = -cos_theta;
+            -to
+        } else {
+            to
+        };
+
+        // Nearly parallel, avoid division by zero
+        if cos_theta > 0.9995 {
+            return Self::nlerp(from, to, t);
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let from_scale = ((1.0 - t) * theta).sin() / sin_theta;
+        let to_scale = (t * theta).sin() / sin_theta;
+        from * from_scale + to * to_scale
+    }
+}
+
+impl_op_ex!(- | a: &Quatf | -> Quatf {
+    Quatf {
+        x: -a.x,
+        y: -a.y,
+        z: -a.z,
+        w: -a.w
+    }
+});
+
+impl_op_ex!(+ | a: &Quatf, b: &Quatf | -> Quatf {
+    Quatf {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+        w: a.w + b.w
+    }
+});
+
+impl_op_ex_commutative!(* | a: &Quatf, b: &f32 | -> Quatf {
+    Quatf {
+        x: a.x * b,
+        y: a.y * b,
+        z: a.z * b,
+        w: a.w * b
+    }
+});
+
+// Composition, applies `b` then `a`
+impl_op_ex!(* | a: &Quatf, b: &Quatf | -> Quatf {
+    Quatf {
+        x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z
+    }
+});
+
+impl_op_ex!(* | a: &Quatf, b: &Vec3f | -> Vec3f {
+    a.rotate(*b)
+});
+
+impl_op_ex!(*= | a: &mut Quatf, b: &Quatf| {
+    *a = *a * b;
+});
+
+impl From<Quatf> for Mat4f {
+    fn from(rotation: Quatf) -> Self {
+        rotation.to_mat4f()
+    }
+}
+
+/// Serialized as `[x, y, z, w]`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Quatf {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.x, self.y, self.z, self.w].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Quatf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(Quatf { x, y, z, w })
+    }
+}
+
+impl fmt::Display for Quatf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+    use crate::math::vec4::Vec4f;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn assert_vec_eq(a: Vec3f, b: Vec3f) {
+        assert!(Vec3f::distance(a, b) < EPSILON, "{} != {}", a, b);
+    }
+
+    /// Quaternions `q` and `-q` are the same rotation
+    fn assert_rotation_eq(a: Quatf, b: Quatf) {
+        assert!((Quatf::dot(a, b).abs() - 1.0).abs() < EPSILON, "{} != {}", a, b);
+    }
+
+    fn assert_mat_eq(a: Mat4f, b: Mat4f) {
+        for row in 0..4 {
+            for column in 0..4 {
+                assert!((a.get(row, column) - b.get(row, column)).abs() < EPSILON, "\n{}\n!=\n{}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn yaw_pitch_roll_matches_matrix() {
+        for (yaw, pitch, roll) in [(0.4, -1.1, 2.3), (-2.0, 0.3, 0.0), (0.0, 0.0, -0.8)] {
+            assert_mat_eq(
+                Quatf::from_yaw_pitch_roll(yaw, pitch, roll).to_mat4f(),
+                Mat4f::rotate_yaw_pitch_roll(yaw, pitch, roll));
+        }
+    }
+
+    #[test]
+    fn matrix_round_trips_every_branch() {
+        let rotations = [
+            // Positive trace
+            Quatf::from_yaw_pitch_roll(0.4, -0.3, 0.2),
+            // Half turns leave the largest diagonal on x, y and z
+            Quatf::from_axis_angle(Vec3f::new(1.0, 0.1, 0.0), PI),
+            Quatf::from_axis_angle(Vec3f::new(0.1, 1.0, 0.0), PI),
+            Quatf::from_axis_angle(Vec3f::new(0.0, 0.1, 1.0), PI)
+        ];
+        for rotation in rotations {
+            assert_rotation_eq(Quatf::from_mat4f(&rotation.to_mat4f()), rotation);
+        }
+    }
+
+    #[test]
+    fn euler_round_trips_away_from_gimbal_lock() {
+        let euler = Vec3f::new(0.3, -0.7, 1.2);
+        assert_vec_eq(Quatf::from_euler(euler).to_euler(), euler);
+
+        // Yaw and roll can't be told apart at ±90° pitch, but the rotation is kept
+        let locked = Quatf::from_euler(Vec3f::new(FRAC_PI_2, 0.5, 0.2));
+        assert_rotation_eq(Quatf::from_euler(locked.to_euler()), locked);
+        assert_eq!(locked.to_euler().z, 0.0);
+    }
+
+    #[test]
+    fn interpolation_takes_the_shortest_path() {
+        let from = Quatf::from_axis_angle(Vec3f::up(), 0.0);
+        let to = Quatf::from_axis_angle(Vec3f::up(), FRAC_PI_2);
+        for lerp in [Quatf::slerp, Quatf::nlerp] {
+            assert_rotation_eq(lerp(from, to, 0.0), from);
+            assert_rotation_eq(lerp(from, to, 1.0), to);
+            // The negated target is the same rotation, and shouldn't go the long way around
+            assert_rotation_eq(lerp(from, -to, 0.5), Quatf::from_axis_angle(Vec3f::up(), FRAC_PI_2 / 2.0));
+        }
+
+        // Nearly parallel rotations fall back to nlerp instead of dividing by ~0
+        let close = Quatf::from_axis_angle(Vec3f::up(), 0.001);
+        let halfway = Quatf::slerp(from, close, 0.5);
+        assert!(halfway.x.is_finite() && halfway.w.is_finite());
+        assert_rotation_eq(halfway, Quatf::from_axis_angle(Vec3f::up(), 0.0005));
+    }
+
+    #[test]
+    fn inverse_and_composition() {
+        let a = Quatf::from_yaw_pitch_roll(0.4, -1.1, 2.3);
+        let b = Quatf::from_axis_angle(Vec3f::new(1.0, 2.0, 3.0), 0.7);
+        assert_rotation_eq(a * a.inverse(), Quatf::identity());
+        // Unnormalized quaternions still invert
+        assert_rotation_eq((a * 2.0) * (a * 2.0).inverse(), Quatf::identity());
+
+        // `a * b` applies `b` first, like the matrices
+        let vector = Vec3f::new(1.0, -2.0, 0.5);
+        assert_vec_eq((a * b).rotate(vector), a.rotate(b.rotate(vector)));
+        assert_mat_eq((a * b).to_mat4f(), a.to_mat4f() * b.to_mat4f());
+        let rotated = a.to_mat4f() * Vec4f::new(vector.x, vector.y, vector.z, 0.0);
+        assert_vec_eq(a * vector, Vec3f::new(rotated.x, rotated.y, rotated.z));
+    }
+
+    #[test]
+    fn rotate_turns_vectors_about_the_axis() {
+        let quarter = Quatf::from_axis_angle(Vec3f::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        assert_vec_eq(quarter.rotate(Vec3f::new(1.0, 0.0, 0.0)), Vec3f::new(0.0, 1.0, 0.0));
+        assert_vec_eq(quarter.rotate(Vec3f::new(0.0, 0.0, 2.0)), Vec3f::new(0.0, 0.0, 2.0));
+
+        let (axis, angle) = quarter.to_axis_angle();
+        assert_vec_eq(axis, Vec3f::new(0.0, 0.0, 1.0));
+        assert!((angle - FRAC_PI_2).abs() < EPSILON);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,268 @@
+use crate::math::{vec2::Vec2f, vec3::Vec3f};
+
+const PCG_MULTIPLIER: u64 = 6364136223846793005;
+const DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb;
+
+/// A seedable pseudo-random number generator (PCG32)
+///
+/// Only integer arithmetic and correctly rounded float operations
+/// are used, so a given seed produces the same sequence on every platform
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Random {
+    state: u64,
+    increment: u64
+}
+
+impl Random {
+    /// Creates a new generator
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the sequence
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, DEFAULT_STREAM)
+    }
+
+    /// Creates a new generator on a specific stream
+    ///
+    /// Generators with the same seed but different streams
+    /// produce independent sequences
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the sequence
+    /// * `stream` - The stream to select
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut random = Random { state: 0, increment: (stream << 1) | 1 };
+        random.next_u32();
+        random.state = random.state.wrapping_add(seed);
+        random.next_u32();
+        random
+    }
+
+    /// Get the next random u32
+    pub fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(PCG_MULTIPLIER)
+            .wrapping_add(self.increment);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rotation = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+
+    /// Get the next random u64
+    pub fn next_u64(&mut self) -> u64 {
+        let high = self.next_u32() as u64;
+        let low = self.next_u32() as u64;
+        (high << 32) | low
+    }
+
+    /// Get a random f32 in the range [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Get a random f64 in the range [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Get a random bool
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u32() >> 31 == 1
+    }
+
+    /// Get a random u32 in the range [0, bound)
+    ///
+    /// Uses rejection sampling so every value is equally likely
+    ///
+    /// # Arguments
+    ///
+    /// * `bound` - The exclusive upper bound, must be non-zero
+    pub fn below(&mut self, bound: u32) -> u32 {
+        assert!(bound > 0, "Random bound must be non-zero");
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let value = self.next_u32();
+            if value >= threshold {
+                return value % bound;
+            }
+        }
+    }
+
+    /// Get a random i32 in the range [min, max]
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The inclusive lower bound
+    /// * `max` - The inclusive upper bound
+    pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        assert!(min <= max, "Random range min must not exceed max");
+        let span = max.wrapping_sub(min) as u32;
+        if span == u32::MAX {
+            return self.next_u32() as i32;
+        }
+        min.wrapping_add(self.below(span + 1) as i32)
+    }
+
+    /// Get a random f32 in the range [min, max)
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The inclusive lower bound
+    /// * `max` - The exclusive upper bound
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Get a random vector with each component in the range [min, max)
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The inclusive lower bounds
+    /// * `max` - The exclusive upper bounds
+    pub fn range_vec2f(&mut self, min: Vec2f, max: Vec2f) -> Vec2f {
+        let x = self.range_f32(min.x, max.x);
+        let y = self.range_f32(min.y, max.y);
+        Vec2f::new(x, y)
+    }
+
+    /// Get a random vector with each component in the range [min, max)
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The inclusive lower bounds
+    /// * `max` - The exclusive upper bounds
+    pub fn range_vec3f(&mut self, min: Vec3f, max: Vec3f) -> Vec3f {
+        let x = self.range_f32(min.x, max.x);
+        let y = self.range_f32(min.y, max.y);
+        let z = self.range_f32(min.z, max.z);
+        Vec3f::new(x, y, z)
+    }
+
+    /// Get a random point inside the unit circle
+    pub fn in_unit_circle(&mut self) -> Vec2f {
+        loop {
+            let point = self.range_vec2f(Vec2f::splat(-1.0), Vec2f::one());
+            if point.sqr_magnitude() <= 1.0 {
+                return point;
+            }
+        }
+    }
+
+    /// Get a random point on the unit circle
+    pub fn on_unit_circle(&mut self) -> Vec2f {
+        loop {
+            let point = self.in_unit_circle();
+            if point.sqr_magnitude() > 1e-6 {
+                return point.normalized();
+            }
+        }
+    }
+
+    /// Get a random point inside the unit sphere
+    pub fn in_unit_sphere(&mut self) -> Vec3f {
+        loop {
+            let point = self.range_vec3f(Vec3f::splat(-1.0), Vec3f::one());
+            if point.sqr_magnitude() <= 1.0 {
+                return point;
+            }
+        }
+    }
+
+    /// Get a random point on the unit sphere (a random direction)
+    pub fn on_unit_sphere(&mut self) -> Vec3f {
+        loop {
+            let point = self.in_unit_sphere();
+            if point.sqr_magnitude() > 1e-6 {
+                return point.normalized();
+            }
+        }
+    }
+
+    /// Pick a random element of a slice
+    ///
+    /// Returns `None` if the slice is empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        Some(&items[self.below(items.len() as u32) as usize])
+    }
+
+    /// Shuffle a slice in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u32 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_pcg32_reference_sequence() {
+        let mut random = Random::with_stream(42, 54);
+        let expected = [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e];
+        for value in expected {
+            assert_eq!(random.next_u32(), value);
+        }
+    }
+
+    #[test]
+    fn new_matches_pcg32_on_the_default_stream() {
+        // pcg32_srandom_r(seed, 0xda3e39cb94b95bdb) followed by pcg32_random_r
+        let expected: [(u64, [u32; 6]); 2] = [
+            (0, [0x0a65ce7d, 0x97a1773e, 0xc03f123a, 0xf1654d25, 0x85a07892, 0x5a7eb74f]),
+            (1234, [0x43a1f79a, 0xccbb6256, 0x3e90b159, 0xbb6c8bb2, 0x63f3d57e, 0x4951fa91])
+        ];
+        for (seed, values) in expected {
+            let mut random = Random::new(seed);
+            for value in values {
+                assert_eq!(random.next_u32(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Random::new(1234);
+        let mut b = Random::new(1234);
+        let mut c = Random::new(1235);
+        let a_values: Vec<u32> = (0..16).map(|_| a.next_u32()).collect();
+        let b_values: Vec<u32> = (0..16).map(|_| b.next_u32()).collect();
+        let c_values: Vec<u32> = (0..16).map(|_| c.next_u32()).collect();
+        assert_eq!(a_values, b_values);
+        assert_ne!(a_values, c_values);
+    }
+
+    #[test]
+    fn samples_stay_in_range() {
+        let mut random = Random::new(7);
+        for _ in 0..1000 {
+            let f = random.next_f32();
+            assert!((0.0..1.0).contains(&f));
+            let i = random.range_i32(-3, 3);
+            assert!((-3..=3).contains(&i));
+            let r = random.range_f32(2.0, 5.0);
+            assert!((2.0..5.0).contains(&r));
+            assert!(random.in_unit_sphere().sqr_magnitude() <= 1.0);
+            assert!((random.on_unit_sphere().magnitude() - 1.0).abs() < 1e-5);
+            assert!((random.on_unit_circle().magnitude() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn shuffle_is_a_permutation() {
+        let mut random = Random::new(99);
+        let mut items: Vec<u32> = (0..32).collect();
+        random.shuffle(&mut items);
+        assert_ne!(items, (0..32).collect::<Vec<u32>>());
+        items.sort();
+        assert_eq!(items, (0..32).collect::<Vec<u32>>());
+    }
+}
\ No newline at end of file
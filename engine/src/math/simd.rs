@@ -0,0 +1,354 @@
+//! 4x4 matrix kernels on column-major `[f32; 16]` arrays.
+//!
+//! Uses SSE2 on x86_64 (always available on that target)
+//! and falls back to scalar code everywhere else.
+
+type Mat4 = [f32; 16];
+type Vec4 = [f32; 4];
+
+/// Multiply two matrices
+pub(crate) fn mat4_mul(a: &Mat4, b: &Mat4) -> Mat4 {
+    #[cfg(target_arch = "x86_64")]
+    unsafe { sse2::mat4_mul(a, b) }
+    #[cfg(not(target_arch = "x86_64"))]
+    scalar::mat4_mul(a, b)
+}
+
+/// Multiply a vector by a matrix
+pub(crate) fn mat4_mul_vec4(m: &Mat4, v: &Vec4) -> Vec4 {
+    #[cfg(target_arch = "x86_64")]
+    unsafe { sse2::mat4_mul_vec4(m, v) }
+    #[cfg(not(target_arch = "x86_64"))]
+    scalar::mat4_mul_vec4(m, v)
+}
+
+/// Invert a matrix, `None` if it is singular
+pub(crate) fn mat4_inverse(m: &Mat4) -> Option<Mat4> {
+    #[cfg(target_arch = "x86_64")]
+    unsafe { sse2::mat4_inverse(m) }
+    #[cfg(not(target_arch = "x86_64"))]
+    scalar::mat4_inverse(m)
+}
+
+/// Calculate the determinant of a matrix
+pub(crate) fn mat4_determinant(m: &Mat4) -> f32 {
+    scalar::mat4_determinant(m)
+}
+
+/// Multiply every matrix in `rhs` by `lhs`
+pub(crate) fn mat4_mul_batch(lhs: &Mat4, rhs: &[Mat4], out: &mut [Mat4]) {
+    assert_eq!(rhs.len(), out.len());
+    for (dst, src) in out.iter_mut().zip(rhs) {
+        *dst = mat4_mul(lhs, src);
+    }
+}
+
+/// Multiply every vector in `vectors` by `m`
+pub(crate) fn mat4_mul_vec4_batch(m: &Mat4, vectors: &[Vec4], out: &mut [Vec4]) {
+    assert_eq!(vectors.len(), out.len());
+    #[cfg(target_arch = "x86_64")]
+    unsafe { sse2::mat4_mul_vec4_batch(m, vectors, out) }
+    #[cfg(not(target_arch = "x86_64"))]
+    for (dst, src) in out.iter_mut().zip(vectors) {
+        *dst = scalar::mat4_mul_vec4(m, src);
+    }
+}
+
+#[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+pub(crate) mod scalar {
+    use super::{Mat4, Vec4};
+
+    const fn cell(x: usize, y: usize) -> usize {
+        x * 4 + y
+    }
+
+    pub(crate) fn mat4_mul(a: &Mat4, b: &Mat4) -> Mat4 {
+        let mut res = [0.0; 16];
+        for r in 0..4 {
+            for c in 0..4 {
+                res[cell(c, r)] =
+                    a[cell(0, r)] * b[cell(c, 0)] +
+                    a[cell(1, r)] * b[cell(c, 1)] +
+                    a[cell(2, r)] * b[cell(c, 2)] +
+                    a[cell(3, r)] * b[cell(c, 3)];
+            }
+        }
+        res
+    }
+
+    pub(crate) fn mat4_mul_vec4(m: &Mat4, v: &Vec4) -> Vec4 {
+        let mut res = [0.0; 4];
+        for (r, value) in res.iter_mut().enumerate() {
+            *value =
+                m[cell(0, r)] * v[0] +
+                m[cell(1, r)] * v[1] +
+                m[cell(2, r)] * v[2] +
+                m[cell(3, r)] * v[3];
+        }
+        res
+    }
+
+    /// 2x2 minors of the top two rows (`s`) and the bottom two rows (`c`),
+    /// shared by the determinant and the inverse
+    fn minors(matrix: &Mat4) -> ([f32; 6], [f32; 6]) {
+        let m = |r, c| matrix[cell(c, r)];
+
+        let s0 = m(0, 0) * m(1, 1) - m(1, 0) * m(0, 1);
+        let s1 = m(0, 0) * m(1, 2) - m(1, 0) * m(0, 2);
+        let s2 = m(0, 0) * m(1, 3) - m(1, 0) * m(0, 3);
+        let s3 = m(0, 1) * m(1, 2) - m(1, 1) * m(0, 2);
+        let s4 = m(0, 1) * m(1, 3) - m(1, 1) * m(0, 3);
+        let s5 = m(0, 2) * m(1, 3) - m(1, 2) * m(0, 3);
+
+        let c5 = m(2, 2) * m(3, 3) - m(3, 2) * m(2, 3);
+        let c4 = m(2, 1) * m(3, 3) - m(3, 1) * m(2, 3);
+        let c3 = m(2, 1) * m(3, 2) - m(3, 1) * m(2, 2);
+        let c2 = m(2, 0) * m(3, 3) - m(3, 0) * m(2, 3);
+        let c1 = m(2, 0) * m(3, 2) - m(3, 0) * m(2, 2);
+        let c0 = m(2, 0) * m(3, 1) - m(3, 0) * m(2, 1);
+
+        ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5])
+    }
+
+    fn determinant(s: &[f32; 6], c: &[f32; 6]) -> f32 {
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    pub(crate) fn mat4_determinant(matrix: &Mat4) -> f32 {
+        let (s, c) = minors(matrix);
+        determinant(&s, &c)
+    }
+
+    pub(crate) fn mat4_inverse(matrix: &Mat4) -> Option<Mat4> {
+        let m = |r, c| matrix[cell(c, r)];
+        let (s, c) = minors(matrix);
+        let [s0, s1, s2, s3, s4, s5] = s;
+        let [c0, c1, c2, c3, c4, c5] = c;
+
+        let det = determinant(&s, &c);
+        if det == 0.0 { return None; }
+        let inv_det = 1.0 / det;
+
+        let mut res = [0.0; 16];
+        let mut set = |r, c, value: f32| res[cell(c, r)] = value * inv_det;
+        set(0, 0, m(1, 1) * c5 - m(1, 2) * c4 + m(1, 3) * c3);
+        set(0, 1, -m(0, 1) * c5 + m(0, 2) * c4 - m(0, 3) * c3);
+        set(0, 2, m(3, 1) * s5 - m(3, 2) * s4 + m(3, 3) * s3);
+        set(0, 3, -m(2, 1) * s5 + m(2, 2) * s4 - m(2, 3) * s3);
+
+        set(1, 0, -m(1, 0) * c5 + m(1, 2) * c2 - m(1, 3) * c1);
+        set(1, 1, m(0, 0) * c5 - m(0, 2) * c2 + m(0, 3) * c1);
+        set(1, 2, -m(3, 0) * s5 + m(3, 2) * s2 - m(3, 3) * s1);
+        set(1, 3, m(2, 0) * s5 - m(2, 2) * s2 + m(2, 3) * s1);
+
+        set(2, 0, m(1, 0) * c4 - m(1, 1) * c2 + m(1, 3) * c0);
+        set(2, 1, -m(0, 0) * c4 + m(0, 1) * c2 - m(0, 3) * c0);
+        set(2, 2, m(3, 0) * s4 - m(3, 1) * s2 + m(3, 3) * s0);
+        set(2, 3, -m(2, 0) * s4 + m(2, 1) * s2 - m(2, 3) * s0);
+
+        set(3, 0, -m(1, 0) * c3 + m(1, 1) * c1 - m(1, 2) * c0);
+        set(3, 1, m(0, 0) * c3 - m(0, 1) * c1 + m(0, 2) * c0);
+        set(3, 2, -m(3, 0) * s3 + m(3, 1) * s1 - m(3, 2) * s0);
+        set(3, 3, m(2, 0) * s3 - m(2, 1) * s1 + m(2, 2) * s0);
+        Some(res)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sse2 {
+    use core::arch::x86_64::*;
+
+    use super::{Mat4, Vec4};
+
+    const fn mask(x: i32, y: i32, z: i32, w: i32) -> i32 {
+        x | (y << 2) | (z << 4) | (w << 6)
+    }
+
+    unsafe fn load_columns(m: &Mat4) -> [__m128; 4] {
+        let p = m.as_ptr();
+        [_mm_loadu_ps(p), _mm_loadu_ps(p.add(4)), _mm_loadu_ps(p.add(8)), _mm_loadu_ps(p.add(12))]
+    }
+
+    unsafe fn store_columns(columns: [__m128; 4]) -> Mat4 {
+        let mut res = [0.0; 16];
+        let p = res.as_mut_ptr();
+        _mm_storeu_ps(p, columns[0]);
+        _mm_storeu_ps(p.add(4), columns[1]);
+        _mm_storeu_ps(p.add(8), columns[2]);
+        _mm_storeu_ps(p.add(12), columns[3]);
+        res
+    }
+
+    /// Linear combination of the columns of `m` weighted by the lanes of `v`
+    #[inline(always)]
+    unsafe fn combine(m: &[__m128; 4], v: __m128) -> __m128 {
+        let x = _mm_shuffle_ps::<{ mask(0, 0, 0, 0) }>(v, v);
+        let y = _mm_shuffle_ps::<{ mask(1, 1, 1, 1) }>(v, v);
+        let z = _mm_shuffle_ps::<{ mask(2, 2, 2, 2) }>(v, v);
+        let w = _mm_shuffle_ps::<{ mask(3, 3, 3, 3) }>(v, v);
+        _mm_add_ps(
+            _mm_add_ps(_mm_mul_ps(m[0], x), _mm_mul_ps(m[1], y)),
+            _mm_add_ps(_mm_mul_ps(m[2], z), _mm_mul_ps(m[3], w)))
+    }
+
+    pub(super) unsafe fn mat4_mul(a: &Mat4, b: &Mat4) -> Mat4 {
+        let a = load_columns(a);
+        let b = load_columns(b);
+        store_columns([combine(&a, b[0]), combine(&a, b[1]), combine(&a, b[2]), combine(&a, b[3])])
+    }
+
+    pub(super) unsafe fn mat4_mul_vec4(m: &Mat4, v: &Vec4) -> Vec4 {
+        let m = load_columns(m);
+        let mut res = [0.0; 4];
+        _mm_storeu_ps(res.as_mut_ptr(), combine(&m, _mm_loadu_ps(v.as_ptr())));
+        res
+    }
+
+    pub(super) unsafe fn mat4_mul_vec4_batch(m: &Mat4, vectors: &[Vec4], out: &mut [Vec4]) {
+        let m = load_columns(m);
+        for (dst, src) in out.iter_mut().zip(vectors) {
+            _mm_storeu_ps(dst.as_mut_ptr(), combine(&m, _mm_loadu_ps(src.as_ptr())));
+        }
+    }
+
+    // 2x2 matrix helpers, each __m128 holds a row-major 2x2 matrix [a, b, c, d]
+
+    /// `a * b`
+    #[inline(always)]
+    unsafe fn mat2_mul(a: __m128, b: __m128) -> __m128 {
+        _mm_add_ps(
+            _mm_mul_ps(a, _mm_shuffle_ps::<{ mask(0, 3, 0, 3) }>(b, b)),
+            _mm_mul_ps(
+                _mm_shuffle_ps::<{ mask(1, 0, 3, 2) }>(a, a),
+                _mm_shuffle_ps::<{ mask(2, 1, 2, 1) }>(b, b)))
+    }
+
+    /// `adjugate(a) * b`
+    #[inline(always)]
+    unsafe fn mat2_adj_mul(a: __m128, b: __m128) -> __m128 {
+        _mm_sub_ps(
+            _mm_mul_ps(_mm_shuffle_ps::<{ mask(3, 3, 0, 0) }>(a, a), b),
+            _mm_mul_ps(
+                _mm_shuffle_ps::<{ mask(1, 1, 2, 2) }>(a, a),
+                _mm_shuffle_ps::<{ mask(2, 3, 0, 1) }>(b, b)))
+    }
+
+    /// `a * adjugate(b)`
+    #[inline(always)]
+    unsafe fn mat2_mul_adj(a: __m128, b: __m128) -> __m128 {
+        _mm_sub_ps(
+            _mm_mul_ps(a, _mm_shuffle_ps::<{ mask(3, 0, 3, 0) }>(b, b)),
+            _mm_mul_ps(
+                _mm_shuffle_ps::<{ mask(1, 0, 3, 2) }>(a, a),
+                _mm_shuffle_ps::<{ mask(2, 1, 2, 1) }>(b, b)))
+    }
+
+    /// Block-wise inverse using 2x2 sub-matrices.
+    ///
+    /// Treats the columns as rows, which yields the transpose of the inverse
+    /// of the transpose, the inverse itself in column-major order.
+    pub(super) unsafe fn mat4_inverse(m: &Mat4) -> Option<Mat4> {
+        let [r0, r1, r2, r3] = load_columns(m);
+
+        let a = _mm_movelh_ps(r0, r1);
+        let b = _mm_movehl_ps(r1, r0);
+        let c = _mm_movelh_ps(r2, r3);
+        let d = _mm_movehl_ps(r3, r2);
+
+        // Determinants of the sub-matrices (|A|, |B|, |C|, |D|)
+        let det_sub = _mm_sub_ps(
+            _mm_mul_ps(
+                _mm_shuffle_ps::<{ mask(0, 2, 0, 2) }>(r0, r2),
+                _mm_shuffle_ps::<{ mask(1, 3, 1, 3) }>(r1, r3)),
+            _mm_mul_ps(
+                _mm_shuffle_ps::<{ mask(1, 3, 1, 3) }>(r0, r2),
+                _mm_shuffle_ps::<{ mask(0, 2, 0, 2) }>(r1, r3)));
+        let det_a = _mm_shuffle_ps::<{ mask(0, 0, 0, 0) }>(det_sub, det_sub);
+        let det_b = _mm_shuffle_ps::<{ mask(1, 1, 1, 1) }>(det_sub, det_sub);
+        let det_c = _mm_shuffle_ps::<{ mask(2, 2, 2, 2) }>(det_sub, det_sub);
+        let det_d = _mm_shuffle_ps::<{ mask(3, 3, 3, 3) }>(det_sub, det_sub);
+
+        let d_c = mat2_adj_mul(d, c);
+        let a_b = mat2_adj_mul(a, b);
+
+        let x = _mm_sub_ps(_mm_mul_ps(det_d, a), mat2_mul(b, d_c));
+        let w = _mm_sub_ps(_mm_mul_ps(det_a, d), mat2_mul(c, a_b));
+        let y = _mm_sub_ps(_mm_mul_ps(det_b, c), mat2_mul_adj(d, a_b));
+        let z = _mm_sub_ps(_mm_mul_ps(det_c, b), mat2_mul_adj(a, d_c));
+
+        // |M| = |A||D| + |B||C| - tr((A#B)(D#C))
+        let mut trace = [0.0f32; 4];
+        _mm_storeu_ps(
+            trace.as_mut_ptr(),
+            _mm_mul_ps(a_b, _mm_shuffle_ps::<{ mask(0, 2, 1, 3) }>(d_c, d_c)));
+        let mut dets = [0.0f32; 4];
+        _mm_storeu_ps(dets.as_mut_ptr(), det_sub);
+        let det = dets[0] * dets[3] + dets[1] * dets[2] - (trace[0] + trace[1] + trace[2] + trace[3]);
+        if det == 0.0 { return None; }
+
+        let inv_det = _mm_div_ps(_mm_setr_ps(1.0, -1.0, -1.0, 1.0), _mm_set1_ps(det));
+        let x = _mm_mul_ps(x, inv_det);
+        let y = _mm_mul_ps(y, inv_det);
+        let z = _mm_mul_ps(z, inv_det);
+        let w = _mm_mul_ps(w, inv_det);
+
+        Some(store_columns([
+            _mm_shuffle_ps::<{ mask(3, 1, 3, 1) }>(x, y),
+            _mm_shuffle_ps::<{ mask(2, 0, 2, 0) }>(x, y),
+            _mm_shuffle_ps::<{ mask(3, 1, 3, 1) }>(z, w),
+            _mm_shuffle_ps::<{ mask(2, 0, 2, 0) }>(z, w)
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn sample(seed: f32) -> Mat4 {
+        let mut res = [0.0; 16];
+        for (i, value) in res.iter_mut().enumerate() {
+            *value = ((i * i) as f32 * seed + 1.0).sin() * 4.0;
+        }
+        res
+    }
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < EPSILON, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn mul_matches_scalar() {
+        let (a, b) = (sample(0.7), sample(1.3));
+        assert_close(&mat4_mul(&a, &b), &scalar::mat4_mul(&a, &b));
+    }
+
+    #[test]
+    fn mul_vec4_matches_scalar() {
+        let m = sample(0.9);
+        let v = [1.0, -2.0, 0.5, 3.0];
+        assert_close(&mat4_mul_vec4(&m, &v), &scalar::mat4_mul_vec4(&m, &v));
+
+        let vectors = [v, [0.0, 1.0, 2.0, 1.0], [4.0, 4.0, -4.0, 0.0]];
+        let mut out = [[0.0; 4]; 3];
+        mat4_mul_vec4_batch(&m, &vectors, &mut out);
+        for (res, v) in out.iter().zip(&vectors) {
+            assert_close(res, &scalar::mat4_mul_vec4(&m, v));
+        }
+    }
+
+    #[test]
+    fn inverse_matches_scalar() {
+        for seed in [0.3, 0.7, 1.1, 2.9] {
+            let m = sample(seed);
+            let simd = mat4_inverse(&m).unwrap();
+            let scalar = scalar::mat4_inverse(&m).unwrap();
+            assert_close(&simd, &scalar);
+        }
+        assert!(mat4_inverse(&[0.0; 16]).is_none());
+    }
+}
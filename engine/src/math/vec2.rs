@@ -0,0 +1,503 @@
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::{vec3::Vec3, fixed32::Fixed32};
+
+/// A 2D Vector
+///
+/// Serialized as `[x, y]` with the `serde` feature
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(
+    from = "[T; 2]", into = "[T; 2]",
+    bound(serialize = "T: Copy + serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+#[repr(C)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T
+}
+
+/// A 2D Vector with f32 components
+pub type Vec2f = Vec2<f32>;
+/// A 2D Vector with f64 components
+pub type Vec2d = Vec2<f64>;
+/// A 2D Vector with i32 components
+pub type Vec2i = Vec2<i32>;
+/// A 2D Vector with u32 components
+pub type Vec2u = Vec2<u32>;
+/// A 2D Vector with Q16.16 fixed-point components
+pub type Vec2x = Vec2<Fixed32>;
+
+impl<T: Copy> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates vector: (v, v)
+    pub const fn splat(v: T) -> Self {
+        Self { x: v, y: v }
+    }
+
+    /// Creates a 3D vector from this vector and a z component
+    pub const fn extend(self, z: T) -> Vec3<T> {
+        Vec3 { x: self.x, y: self.y, z }
+    }
+
+    /// Apply a function to each component
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vec2<U> {
+        Vec2 { x: f(self.x), y: f(self.y) }
+    }
+}
+
+macro_rules! swizzle {
+    ($($name:ident: $a:ident $b:ident),* $(,)?) => {
+        impl<T: Copy> Vec2<T> {
+            $(
+                #[doc = concat!("Get the vector (", stringify!($a), ", ", stringify!($b), ")")]
+                pub const fn $name(self) -> Vec2<T> {
+                    Vec2 { x: self.$a, y: self.$b }
+                }
+            )*
+        }
+    };
+}
+
+swizzle!(xy: x y, yx: y x, xx: x x, yy: y y);
+
+impl<T> Index<usize> for Vec2<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vec2 index out of range: {}", index)
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec2<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vec2 index out of range: {}", index)
+        }
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2] {
+    fn from(v: Vec2<T>) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Implements methods and operators common to all scalar types
+macro_rules! impl_vec2 {
+    ($t:ty, $zero:literal, $one:literal) => {
+        impl Vec2<$t> {
+            /// Creates vector: (0, 0)
+            pub const fn zero() -> Self {
+                Vec2 { x: $zero, y: $zero }
+            }
+
+            /// Creates vector: (1, 1)
+            pub const fn one() -> Self {
+                Vec2 { x: $one, y: $one }
+            }
+
+            /// Calculate the dot product of two vectors
+            pub fn dot(lhs: Self, rhs: Self) -> $t {
+                lhs.x * rhs.x + lhs.y * rhs.y
+            }
+
+            /// Get the squared length of the vector
+            ///
+            /// Recommended when comparing lengths as it
+            /// avoids unnecessary square root operations
+            pub fn sqr_magnitude(self) -> $t {
+                self.x * self.x + self.y * self.y
+            }
+
+            /// Get the component-wise minimum of two vectors
+            pub fn min(lhs: Self, rhs: Self) -> Self {
+                Vec2 { x: lhs.x.min(rhs.x), y: lhs.y.min(rhs.y) }
+            }
+
+            /// Get the component-wise maximum of two vectors
+            pub fn max(lhs: Self, rhs: Self) -> Self {
+                Vec2 { x: lhs.x.max(rhs.x), y: lhs.y.max(rhs.y) }
+            }
+
+            /// Clamp each component between the components of `min` and `max`
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self::min(Self::max(self, min), max)
+            }
+
+            /// Get the smallest component
+            pub fn min_component(self) -> $t {
+                self.x.min(self.y)
+            }
+
+            /// Get the largest component
+            pub fn max_component(self) -> $t {
+                self.x.max(self.y)
+            }
+        }
+
+        impl_op_ex!(+ | a: &Vec2<$t>, b: &Vec2<$t> | -> Vec2<$t> {
+            Vec2 {
+                x: a.x + b.x,
+                y: a.y + b.y
+            }
+        });
+
+        impl_op_ex!(- | a: &Vec2<$t>, b: &Vec2<$t> | -> Vec2<$t> {
+            Vec2 {
+                x: a.x - b.x,
+                y: a.y - b.y
+            }
+        });
+
+        impl_op_ex!(* | a: &Vec2<$t>, b: &Vec2<$t> | -> Vec2<$t> {
+            Vec2 {
+                x: a.x * b.x,
+                y: a.y * b.y
+            }
+        });
+
+        impl_op_ex!(/ | a: &Vec2<$t>, b: &Vec2<$t> | -> Vec2<$t> {
+            Vec2 {
+                x: a.x / b.x,
+                y: a.y / b.y
+            }
+        });
+
+        impl_op_ex_commutative!(* | a: &Vec2<$t>, b: &$t | -> Vec2<$t> {
+            Vec2 {
+                x: a.x * b,
+                y: a.y * b
+            }
+        });
+
+        impl_op_ex!(/ | a: &Vec2<$t>, b: &$t | -> Vec2<$t> {
+            Vec2 {
+                x: a.x / b,
+                y: a.y / b
+            }
+        });
+
+        impl_op_ex!(+= | a: &mut Vec2<$t>, b: &Vec2<$t>| {
+            a.x += b.x;
+            a.y += b.y;
+        });
+
+        impl_op_ex!(-= | a: &mut Vec2<$t>, b: &Vec2<$t>| {
+            a.x -= b.x;
+            a.y -= b.y;
+        });
+
+        impl_op_ex!(*= | a: &mut Vec2<$t>, b: &Vec2<$t>| {
+            a.x *= b.x;
+            a.y *= b.y;
+        });
+
+        impl_op_ex!(/= | a: &mut Vec2<$t>, b: &Vec2<$t>| {
+            a.x /= b.x;
+            a.y /= b.y;
+        });
+
+        impl_op_ex!(*= | a: &mut Vec2<$t>, b: &$t| {
+            a.x *= b;
+            a.y *= b;
+        });
+
+        impl_op_ex!(/= | a: &mut Vec2<$t>, b: &$t| {
+            a.x /= b;
+            a.y /= b;
+        });
+
+        impl Vec2<$t> {
+            /// Convert to a vector with f32 components
+            pub fn as_f32(self) -> Vec2f {
+                Vec2 { x: self.x as f32, y: self.y as f32 }
+            }
+
+            /// Convert to a vector with f64 components
+            pub fn as_f64(self) -> Vec2d {
+                Vec2 { x: self.x as f64, y: self.y as f64 }
+            }
+
+            /// Convert to a vector with i32 components
+            ///
+            /// Float components are truncated towards zero
+            pub fn as_i32(self) -> Vec2i {
+                Vec2 { x: self.x as i32, y: self.y as i32 }
+            }
+
+            /// Convert to a vector with u32 components
+            ///
+            /// Float components are truncated towards zero,
+            /// negative components are clamped to zero
+            pub fn as_u32(self) -> Vec2u {
+                Vec2 { x: self.x.max($zero) as u32, y: self.y.max($zero) as u32 }
+            }
+        }
+    };
+}
+
+/// Implements methods and operators for signed scalar types
+macro_rules! impl_vec2_signed {
+    ($t:ty, $zero:literal, $one:literal) => {
+        impl Vec2<$t> {
+            /// Creates vector: (1, 0)
+            pub const fn right() -> Self {
+                Vec2 { x: $one, y: $zero }
+            }
+
+            /// Creates vector: (-1, 0)
+            pub const fn left() -> Self {
+                Vec2 { x: -$one, y: $zero }
+            }
+
+            /// Creates vector: (0, 1)
+            pub const fn up() -> Self {
+                Vec2 { x: $zero, y: $one }
+            }
+
+            /// Creates vector: (0, -1)
+            pub const fn down() -> Self {
+                Vec2 { x: $zero, y: -$one }
+            }
+
+            /// Get the absolute value of each component
+            pub fn abs(self) -> Self {
+                Vec2 { x: self.x.abs(), y: self.y.abs() }
+            }
+        }
+
+        impl_op_ex!(- | a: &Vec2<$t> | -> Vec2<$t> {
+            Vec2 {
+                x: -a.x,
+                y: -a.y
+            }
+        });
+    };
+}
+
+/// Implements methods for floating point scalar types
+macro_rules! impl_vec2_float {
+    ($t:ty) => {
+        impl Vec2<$t> {
+            /// Calculates the distance between two points in 2D space
+            pub fn distance(lhs: Self, rhs: Self) -> $t {
+                (lhs - rhs).magnitude()
+            }
+
+            /// Get the length of the vector
+            ///
+            /// If comparing lengths use sqr_magnitude
+            /// instead for improved performance
+            pub fn magnitude(self) -> $t {
+                self.sqr_magnitude().sqrt()
+            }
+
+            /// Normalize this vector (Scale of length 1)
+            pub fn normalize(&mut self) {
+                let mag = self.magnitude();
+                if mag == 0.0 { return; }
+
+                let scale = 1.0 / mag;
+                self.x *= scale;
+                self.y *= scale;
+            }
+
+            /// Get this vector normalized (Scale of length 1)
+            pub fn normalized(self) -> Self {
+                let mag = self.magnitude();
+                if mag == 0.0 { return Self::zero(); }
+
+                let scale = 1.0 / mag;
+                Vec2 {
+                    x: self.x * scale,
+                    y: self.y * scale
+                }
+            }
+
+            /// Linearly interpolate between two vectors
+            ///
+            /// # Arguments
+            ///
+            /// * `from` - The vector at `t = 0`
+            /// * `to` - The vector at `t = 1`
+            /// * `t` - The interpolation factor
+            pub fn lerp(from: Self, to: Self, t: $t) -> Self {
+                from + (to - from) * t
+            }
+
+            /// Round each component down
+            pub fn floor(self) -> Self {
+                Vec2 { x: self.x.floor(), y: self.y.floor() }
+            }
+
+            /// Round each component up
+            pub fn ceil(self) -> Self {
+                Vec2 { x: self.x.ceil(), y: self.y.ceil() }
+            }
+
+            /// Round each component to the nearest integer
+            pub fn round(self) -> Self {
+                Vec2 { x: self.x.round(), y: self.y.round() }
+            }
+        }
+    };
+}
+
+impl_vec2!(f32, 0.0, 1.0);
+impl_vec2!(f64, 0.0, 1.0);
+impl_vec2!(i32, 0, 1);
+impl_vec2!(u32, 0, 1);
+
+impl_vec2_signed!(f32, 0.0, 1.0);
+impl_vec2_signed!(f64, 0.0, 1.0);
+impl_vec2_signed!(i32, 0, 1);
+
+impl_vec2_float!(f32);
+impl_vec2_float!(f64);
+
+impl From<Vec2f> for Vec2d {
+    fn from(v: Vec2f) -> Self {
+        v.as_f64()
+    }
+}
+
+impl From<Vec2i> for Vec2d {
+    fn from(v: Vec2i) -> Self {
+        v.as_f64()
+    }
+}
+
+impl From<Vec2u> for Vec2d {
+    fn from(v: Vec2u) -> Self {
+        v.as_f64()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-6;
+
+    fn assert_vec_eq(a: Vec2f, b: Vec2f) {
+        assert!((a.x - b.x).abs() < EPSILON && (a.y - b.y).abs() < EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn operators_work_for_every_scalar() {
+        assert_eq!(Vec2f::new(1.0, 2.0) + Vec2f::new(3.0, 4.0), Vec2f::new(4.0, 6.0));
+        assert_eq!(Vec2d::new(1.0, 2.0) * 2.0, Vec2d::new(2.0, 4.0));
+        assert_eq!(Vec2i::new(7, -3) - Vec2i::new(2, 2), Vec2i::new(5, -5));
+        assert_eq!(Vec2u::new(6, 9) / Vec2u::new(2, 3), Vec2u::new(3, 3));
+        assert_eq!(Vec2i::dot(Vec2i::new(2, 3), Vec2i::new(4, -1)), 5);
+        assert_eq!(Vec2u::new(3, 4).sqr_magnitude(), 25);
+        assert_eq!(-Vec2i::right(), Vec2i::left());
+        assert_eq!(Vec2i::new(-2, 5).abs(), Vec2i::new(2, 5));
+
+        let mut v = Vec2u::one();
+        v *= 4;
+        v += Vec2u::new(1, 0);
+        assert_eq!(v, Vec2u::new(5, 4));
+    }
+
+    #[test]
+    fn swizzles_reorder_components() {
+        let v = Vec2i::new(1, 2);
+        assert_eq!(v.yx(), Vec2i::new(2, 1));
+        assert_eq!(v.xx(), Vec2i::new(1, 1));
+        assert_eq!(v.yy(), Vec2i::new(2, 2));
+        assert_eq!(v.xy(), v);
+    }
+
+    #[test]
+    fn index_reads_and_writes_components() {
+        let mut v = Vec2f::new(1.0, 2.0);
+        v[1] = 5.0;
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v.y, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec2 index out of range: 2")]
+    fn index_out_of_range_panics() {
+        let _ = Vec2i::zero()[2];
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let v: Vec2i = [3, -4].into();
+        assert_eq!(<[i32; 2]>::from(v), [3, -4]);
+        assert_eq!(Vec2i::from((3, -4)), v);
+        assert_eq!(<(i32, i32)>::from(v), (3, -4));
+
+        assert_eq!(Vec2d::from(v), Vec2d::new(3.0, -4.0));
+        assert_eq!(Vec2d::from(Vec2u::new(1, 2)), Vec2d::new(1.0, 2.0));
+        assert_eq!(Vec2d::from(Vec2f::new(0.5, 1.5)), Vec2d::new(0.5, 1.5));
+
+        assert_eq!(Vec2f::new(-1.7, 2.9).as_i32(), Vec2i::new(-1, 2));
+        assert_eq!(Vec2f::new(-1.7, 2.9).as_u32(), Vec2u::new(0, 2));
+        assert_eq!(Vec2i::new(-3, 3).as_u32(), Vec2u::new(0, 3));
+        assert_eq!(Vec2u::new(1, 2).as_f32(), Vec2f::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn min_max_and_clamp_are_componentwise() {
+        let a = Vec2i::new(1, 8);
+        let b = Vec2i::new(4, -2);
+        assert_eq!(Vec2i::min(a, b), Vec2i::new(1, -2));
+        assert_eq!(Vec2i::max(a, b), Vec2i::new(4, 8));
+        assert_eq!(Vec2i::new(-5, 5).clamp(Vec2i::zero(), Vec2i::new(3, 3)), Vec2i::new(0, 3));
+        assert_eq!(a.min_component(), 1);
+        assert_eq!(b.max_component(), 4);
+    }
+
+    #[test]
+    fn float_helpers() {
+        let a = Vec2f::new(0.0, 10.0);
+        let b = Vec2f::new(4.0, 2.0);
+        assert_vec_eq(Vec2f::lerp(a, b, 0.0), a);
+        assert_vec_eq(Vec2f::lerp(a, b, 1.0), b);
+        assert_vec_eq(Vec2f::lerp(a, b, 0.25), Vec2f::new(1.0, 8.0));
+        assert!((Vec2f::new(3.0, 4.0).magnitude() - 5.0).abs() < EPSILON);
+        assert!((Vec2f::new(3.0, 4.0).normalized().magnitude() - 1.0).abs() < EPSILON);
+        assert_eq!(Vec2d::new(-1.5, 1.5).floor(), Vec2d::new(-2.0, 1.0));
+        assert_eq!(Vec2d::new(-1.5, 1.2).ceil(), Vec2d::new(-1.0, 2.0));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,548 @@
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::{vec2::Vec2, vec4::Vec4, fixed32::Fixed32};
+
+/// A 3D Vector
+///
+/// Serialized as `[x, y, z]` with the `serde` feature
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(
+    from = "[T; 3]", into = "[T; 3]",
+    bound(serialize = "T: Copy + serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+#[repr(C)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+/// A 3D Vector with f32 components
+pub type Vec3f = Vec3<f32>;
+/// A 3D Vector with f64 components
+pub type Vec3d = Vec3<f64>;
+/// A 3D Vector with i32 components
+pub type Vec3i = Vec3<i32>;
+/// A 3D Vector with u32 components
+pub type Vec3u = Vec3<u32>;
+/// A 3D Vector with Q16.16 fixed-point components
+pub type Vec3x = Vec3<Fixed32>;
+
+impl<T: Copy> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates vector: (v, v, v)
+    pub const fn splat(v: T) -> Self {
+        Self { x: v, y: v, z: v }
+    }
+
+    /// Creates a 4D vector from this vector and a w component
+    pub const fn extend(self, w: T) -> Vec4<T> {
+        Vec4 { x: self.x, y: self.y, z: self.z, w }
+    }
+
+    /// Creates a 2D vector from the x and y components
+    pub const fn truncate(self) -> Vec2<T> {
+        Vec2 { x: self.x, y: self.y }
+    }
+
+    /// Apply a function to each component
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vec3<U> {
+        Vec3 { x: f(self.x), y: f(self.y), z: f(self.z) }
+    }
+}
+
+macro_rules! swizzle {
+    ($($name:ident: $a:ident $b:ident),* ; $($name3:ident: $c:ident $d:ident $e:ident),* $(,)?) => {
+        impl<T: Copy> Vec3<T> {
+            $(
+                #[doc = concat!("Get the vector (", stringify!($a), ", ", stringify!($b), ")")]
+                pub const fn $name(self) -> Vec2<T> {
+                    Vec2 { x: self.$a, y: self.$b }
+                }
+            )*
+            $(
+                #[doc = concat!("Get the vector (", stringify!($c), ", ", stringify!($d), ", ", stringify!($e), ")")]
+                pub const fn $name3(self) -> Vec3<T> {
+                    Vec3 { x: self.$c, y: self.$d, z: self.$e }
+                }
+            )*
+        }
+    };
+}
+
+swizzle!(
+    xy: x y, xz: x z, yx: y x, yz: y z, zx: z x, zy: z y;
+    xyz: x y z, xzy: x z y, yxz: y x z, yzx: y z x, zxy: z x y, zyx: z y x
+);
+
+impl<T> Index<usize> for Vec3<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vec3 index out of range: {}", index)
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vec3 index out of range: {}", index)
+        }
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3] {
+    fn from(v: Vec3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for (T, T, T) {
+    fn from(v: Vec3<T>) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Implements methods and operators common to all scalar types
+macro_rules! impl_vec3 {
+    ($t:ty, $zero:literal, $one:literal) => {
+        impl Vec3<$t> {
+            /// Creates vector: (0, 0, 0)
+            pub const fn zero() -> Self {
+                Vec3 { x: $zero, y: $zero, z: $zero }
+            }
+
+            /// Creates vector: (1, 1, 1)
+            pub const fn one() -> Self {
+                Vec3 { x: $one, y: $one, z: $one }
+            }
+
+            /// Calculate the dot product of two vectors
+            pub fn dot(lhs: Self, rhs: Self) -> $t {
+                lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z
+            }
+
+            /// Get the squared length of the vector
+            ///
+            /// Recommended when comparing lengths as it
+            /// avoids unnecessary square root operations
+            pub fn sqr_magnitude(self) -> $t {
+                self.x * self.x + self.y * self.y + self.z * self.z
+            }
+
+            /// Get the component-wise minimum of two vectors
+            pub fn min(lhs: Self, rhs: Self) -> Self {
+                Vec3 { x: lhs.x.min(rhs.x), y: lhs.y.min(rhs.y), z: lhs.z.min(rhs.z) }
+            }
+
+            /// Get the component-wise maximum of two vectors
+            pub fn max(lhs: Self, rhs: Self) -> Self {
+                Vec3 { x: lhs.x.max(rhs.x), y: lhs.y.max(rhs.y), z: lhs.z.max(rhs.z) }
+            }
+
+            /// Clamp each component between the components of `min` and `max`
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self::min(Self::max(self, min), max)
+            }
+
+            /// Get the smallest component
+            pub fn min_component(self) -> $t {
+                self.x.min(self.y).min(self.z)
+            }
+
+            /// Get the largest component
+            pub fn max_component(self) -> $t {
+                self.x.max(self.y).max(self.z)
+            }
+        }
+
+        impl_op_ex!(+ | a: &Vec3<$t>, b: &Vec3<$t> | -> Vec3<$t> {
+            Vec3 {
+                x: a.x + b.x,
+                y: a.y + b.y,
+                z: a.z + b.z
+            }
+        });
+
+        impl_op_ex!(- | a: &Vec3<$t>, b: &Vec3<$t> | -> Vec3<$t> {
+            Vec3 {
+                x: a.x - b.x,
+                y: a.y - b.y,
+                z: a.z - b.z
+            }
+        });
+
+        impl_op_ex!(* | a: &Vec3<$t>, b: &Vec3<$t> | -> Vec3<$t> {
+            Vec3 {
+                x: a.x * b.x,
+                y: a.y * b.y,
+                z: a.z * b.z
+            }
+        });
+
+        impl_op_ex!(/ | a: &Vec3<$t>, b: &Vec3<$t> | -> Vec3<$t> {
+            Vec3 {
+                x: a.x / b.x,
+                y: a.y / b.y,
+                z: a.z / b.z
+            }
+        });
+
+        impl_op_ex_commutative!(* | a: &Vec3<$t>, b: &$t | -> Vec3<$t> {
+            Vec3 {
+                x: a.x * b,
+                y: a.y * b,
+                z: a.z * b
+            }
+        });
+
+        impl_op_ex!(/ | a: &Vec3<$t>, b: &$t | -> Vec3<$t> {
+            Vec3 {
+                x: a.x / b,
+                y: a.y / b,
+                z: a.z / b
+            }
+        });
+
+        impl_op_ex!(+= | a: &mut Vec3<$t>, b: &Vec3<$t>| {
+            a.x += b.x;
+            a.y += b.y;
+            a.z += b.z;
+        });
+
+        impl_op_ex!(-= | a: &mut Vec3<$t>, b: &Vec3<$t>| {
+            a.x -= b.x;
+            a.y -= b.y;
+            a.z -= b.z;
+        });
+
+        impl_op_ex!(*= | a: &mut Vec3<$t>, b: &Vec3<$t>| {
+            a.x *= b.x;
+            a.y *= b.y;
+            a.z *= b.z;
+        });
+
+        impl_op_ex!(/= | a: &mut Vec3<$t>, b: &Vec3<$t>| {
+            a.x /= b.x;
+            a.y /= b.y;
+            a.z /= b.z;
+        });
+
+        impl_op_ex!(*= | a: &mut Vec3<$t>, b: &$t| {
+            a.x *= b;
+            a.y *= b;
+            a.z *= b;
+        });
+
+        impl_op_ex!(/= | a: &mut Vec3<$t>, b: &$t| {
+            a.x /= b;
+            a.y /= b;
+            a.z /= b;
+        });
+
+        impl Vec3<$t> {
+            /// Convert to a vector with f32 components
+            pub fn as_f32(self) -> Vec3f {
+                Vec3 { x: self.x as f32, y: self.y as f32, z: self.z as f32 }
+            }
+
+            /// Convert to a vector with f64 components
+            pub fn as_f64(self) -> Vec3d {
+                Vec3 { x: self.x as f64, y: self.y as f64, z: self.z as f64 }
+            }
+
+            /// Convert to a vector with i32 components
+            ///
+            /// Float components are truncated towards zero
+            pub fn as_i32(self) -> Vec3i {
+                Vec3 { x: self.x as i32, y: self.y as i32, z: self.z as i32 }
+            }
+
+            /// Convert to a vector with u32 components
+            ///
+            /// Float components are truncated towards zero,
+            /// negative components are clamped to zero
+            pub fn as_u32(self) -> Vec3u {
+                Vec3 { x: self.x.max($zero) as u32, y: self.y.max($zero) as u32, z: self.z.max($zero) as u32 }
+            }
+        }
+    };
+}
+
+/// Implements methods and operators for signed scalar types
+macro_rules! impl_vec3_signed {
+    ($t:ty, $zero:literal, $one:literal) => {
+        impl Vec3<$t> {
+            /// Creates vector: (1, 0, 0)
+            pub const fn right() -> Self {
+                Vec3 { x: $one, y: $zero, z: $zero }
+            }
+
+            /// Creates vector: (-1, 0, 0)
+            pub const fn left() -> Self {
+                Vec3 { x: -$one, y: $zero, z: $zero }
+            }
+
+            /// Creates vector: (0, 1, 0)
+            pub const fn up() -> Self {
+                Vec3 { x: $zero, y: $one, z: $zero }
+            }
+
+            /// Creates vector: (0, -1, 0)
+            pub const fn down() -> Self {
+                Vec3 { x: $zero, y: -$one, z: $zero }
+            }
+
+            /// Creates vector: (0, 0, 1)
+            pub const fn forward() -> Self {
+                Vec3 { x: $zero, y: $zero, z: $one }
+            }
+
+            /// Creates vector: (0, 0, -1)
+            pub const fn back() -> Self {
+                Vec3 { x: $zero, y: $zero, z: -$one }
+            }
+
+            /// Calculate the cross product of two vectors
+            pub fn cross(lhs: Self, rhs: Self) -> Self {
+                Vec3 {
+                    x: lhs.y * rhs.z - lhs.z * rhs.y,
+                    y: lhs.z * rhs.x - lhs.x * rhs.z,
+                    z: lhs.x * rhs.y - lhs.y * rhs.x
+                }
+            }
+
+            /// Get the absolute value of each component
+            pub fn abs(self) -> Self {
+                Vec3 { x: self.x.abs(), y: self.y.abs(), z: self.z.abs() }
+            }
+        }
+
+        impl_op_ex!(- | a: &Vec3<$t> | -> Vec3<$t> {
+            Vec3 {
+                x: -a.x,
+                y: -a.y,
+                z: -a.z
+            }
+        });
+    };
+}
+
+/// Implements methods for floating point scalar types
+macro_rules! impl_vec3_float {
+    ($t:ty) => {
+        impl Vec3<$t> {
+            /// Calculates the distance between two points in 3D space
+            pub fn distance(lhs: Self, rhs: Self) -> $t {
+                (lhs - rhs).magnitude()
+            }
+
+            /// Get the length of the vector
+            ///
+            /// If comparing lengths use sqr_magnitude
+            /// instead for improved performance
+            pub fn magnitude(self) -> $t {
+                self.sqr_magnitude().sqrt()
+            }
+
+            /// Normalize this vector (Scale of length 1)
+            pub fn normalize(&mut self) {
+                let mag = self.magnitude();
+                if mag == 0.0 { return; }
+
+                let scale = 1.0 / mag;
+                self.x *= scale;
+                self.y *= scale;
+                self.z *= scale;
+            }
+
+            /// Get this vector normalized (Scale of length 1)
+            pub fn normalized(self) -> Self {
+                let mag = self.magnitude();
+                if mag == 0.0 { return Self::zero(); }
+
+                let scale = 1.0 / mag;
+                Vec3 {
+                    x: self.x * scale,
+                    y: self.y * scale,
+                    z: self.z * scale
+                }
+            }
+
+            /// Linearly interpolate between two vectors
+            ///
+            /// # Arguments
+            ///
+            /// * `from` - The vector at `t = 0`
+            /// * `to` - The vector at `t = 1`
+            /// * `t` - The interpolation factor
+            pub fn lerp(from: Self, to: Self, t: $t) -> Self {
+                from + (to - from) * t
+            }
+
+            /// Round each component down
+            pub fn floor(self) -> Self {
+                Vec3 { x: self.x.floor(), y: self.y.floor(), z: self.z.floor() }
+            }
+
+            /// Round each component up
+            pub fn ceil(self) -> Self {
+                Vec3 { x: self.x.ceil(), y: self.y.ceil(), z: self.z.ceil() }
+            }
+
+            /// Round each component to the nearest integer
+            pub fn round(self) -> Self {
+                Vec3 { x: self.x.round(), y: self.y.round(), z: self.z.round() }
+            }
+        }
+    };
+}
+
+impl_vec3!(f32, 0.0, 1.0);
+impl_vec3!(f64, 0.0, 1.0);
+impl_vec3!(i32, 0, 1);
+impl_vec3!(u32, 0, 1);
+
+impl_vec3_signed!(f32, 0.0, 1.0);
+impl_vec3_signed!(f64, 0.0, 1.0);
+impl_vec3_signed!(i32, 0, 1);
+
+impl_vec3_float!(f32);
+impl_vec3_float!(f64);
+
+impl From<Vec3f> for Vec3d {
+    fn from(v: Vec3f) -> Self {
+        v.as_f64()
+    }
+}
+
+impl From<Vec3i> for Vec3d {
+    fn from(v: Vec3i) -> Self {
+        v.as_f64()
+    }
+}
+
+impl From<Vec3u> for Vec3d {
+    fn from(v: Vec3u) -> Self {
+        v.as_f64()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::vec2::Vec2i;
+
+    const EPSILON: f32 = 1e-6;
+
+    fn assert_vec_eq(a: Vec3f, b: Vec3f) {
+        assert!((a - b).magnitude() < EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn operators_work_for_every_scalar() {
+        assert_eq!(Vec3f::new(1.0, 2.0, 3.0) + Vec3f::one(), Vec3f::new(2.0, 3.0, 4.0));
+        assert_eq!(Vec3d::new(1.0, 2.0, 3.0) / 2.0, Vec3d::new(0.5, 1.0, 1.5));
+        assert_eq!(Vec3i::new(1, 2, 3) * Vec3i::new(-1, 0, 2), Vec3i::new(-1, 0, 6));
+        assert_eq!(Vec3u::new(2, 3, 4) * 3, Vec3u::new(6, 9, 12));
+        assert_eq!(Vec3u::dot(Vec3u::new(1, 2, 3), Vec3u::new(4, 5, 6)), 32);
+        assert_eq!(Vec3i::cross(Vec3i::right(), Vec3i::up()), Vec3i::new(0, 0, 1));
+        assert_eq!(Vec3i::new(-1, 2, -3).abs(), Vec3i::new(1, 2, 3));
+    }
+
+    #[test]
+    fn swizzles_reorder_components() {
+        let v = Vec3i::new(1, 2, 3);
+        assert_eq!(v.zyx(), Vec3i::new(3, 2, 1));
+        assert_eq!(v.yzx(), Vec3i::new(2, 3, 1));
+        assert_eq!(v.xz(), Vec2i::new(1, 3));
+        assert_eq!(v.truncate(), v.xy());
+        assert_eq!(v.extend(4).truncate(), v);
+    }
+
+    #[test]
+    fn index_reads_and_writes_components() {
+        let mut v = Vec3u::new(1, 2, 3);
+        v[2] = 9;
+        assert_eq!([v[0], v[1], v[2]], [1, 2, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3 index out of range: 3")]
+    fn index_out_of_range_panics() {
+        let mut v = Vec3f::zero();
+        v[3] = 1.0;
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let v: Vec3u = [1, 2, 3].into();
+        assert_eq!(<[u32; 3]>::from(v), [1, 2, 3]);
+        assert_eq!(Vec3u::from((1, 2, 3)), v);
+        assert_eq!(<(u32, u32, u32)>::from(v), (1, 2, 3));
+
+        assert_eq!(Vec3d::from(v), Vec3d::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3d::from(Vec3i::new(-1, 0, 1)), Vec3d::new(-1.0, 0.0, 1.0));
+        assert_eq!(Vec3d::from(Vec3f::new(0.5, 0.25, 2.0)), Vec3d::new(0.5, 0.25, 2.0));
+        assert_eq!(Vec3d::new(2.9, -2.9, 0.1).as_i32(), Vec3i::new(2, -2, 0));
+        assert_eq!(Vec3i::new(-4, 4, 0).as_f32(), Vec3f::new(-4.0, 4.0, 0.0));
+        assert_eq!(Vec3i::new(-4, 4, 0).as_u32(), Vec3u::new(0, 4, 0));
+        assert_eq!(Vec3f::new(-0.5, 2.9, -7.0).as_u32(), Vec3u::new(0, 2, 0));
+    }
+
+    #[test]
+    fn min_max_and_clamp_are_componentwise() {
+        let a = Vec3f::new(1.0, -1.0, 5.0);
+        let b = Vec3f::new(0.0, 2.0, 5.0);
+        assert_eq!(Vec3f::min(a, b), Vec3f::new(0.0, -1.0, 5.0));
+        assert_eq!(Vec3f::max(a, b), Vec3f::new(1.0, 2.0, 5.0));
+        assert_eq!(a.clamp(Vec3f::zero(), Vec3f::splat(2.0)), Vec3f::new(1.0, 0.0, 2.0));
+        assert_eq!(a.min_component(), -1.0);
+        assert_eq!(a.max_component(), 5.0);
+    }
+
+    #[test]
+    fn float_helpers() {
+        let a = Vec3f::new(0.0, 0.0, 0.0);
+        let b = Vec3f::new(2.0, -4.0, 8.0);
+        assert_vec_eq(Vec3f::lerp(a, b, 0.0), a);
+        assert_vec_eq(Vec3f::lerp(a, b, 1.0), b);
+        assert_vec_eq(Vec3f::lerp(a, b, 0.5), Vec3f::new(1.0, -2.0, 4.0));
+        assert!((Vec3f::distance(a, Vec3f::new(2.0, 3.0, 6.0)) - 7.0).abs() < EPSILON);
+        assert_vec_eq(Vec3f::new(0.0, 0.0, 3.0).normalized(), Vec3f::new(0.0, 0.0, 1.0));
+        assert_eq!(Vec3d::new(0.4, 0.5, -0.6).round(), Vec3d::new(0.0, 1.0, -1.0));
+    }
+}
\ No newline at end of file
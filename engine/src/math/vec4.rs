@@ -0,0 +1,540 @@
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use super::{vec2::Vec2, vec3::Vec3};
+
+/// A 4D Vector
+///
+/// Serialized as `[x, y, z, w]` with the `serde` feature
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(
+    from = "[T; 4]", into = "[T; 4]",
+    bound(serialize = "T: Copy + serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+#[repr(C)]
+pub struct Vec4<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T
+}
+
+/// A 4D Vector with f32 components
+pub type Vec4f = Vec4<f32>;
+/// A 4D Vector with f64 components
+pub type Vec4d = Vec4<f64>;
+/// A 4D Vector with i32 components
+pub type Vec4i = Vec4<i32>;
+/// A 4D Vector with u32 components
+pub type Vec4u = Vec4<u32>;
+
+impl<T: Copy> Vec4<T> {
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates vector: (v, v, v, v)
+    pub const fn splat(v: T) -> Self {
+        Self { x: v, y: v, z: v, w: v }
+    }
+
+    /// Creates a 3D vector from the x, y and z components
+    pub const fn truncate(self) -> Vec3<T> {
+        Vec3 { x: self.x, y: self.y, z: self.z }
+    }
+
+    /// Apply a function to each component
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vec4<U> {
+        Vec4 { x: f(self.x), y: f(self.y), z: f(self.z), w: f(self.w) }
+    }
+}
+
+macro_rules! swizzle {
+    (
+        $($name2:ident: $a2:ident $b2:ident),* ;
+        $($name3:ident: $a3:ident $b3:ident $c3:ident),* ;
+        $($name4:ident: $a4:ident $b4:ident $c4:ident $d4:ident),* $(,)?
+    ) => {
+        impl<T: Copy> Vec4<T> {
+            $(
+                #[doc = concat!("Get the vector (", stringify!($a2), ", ", stringify!($b2), ")")]
+                pub const fn $name2(self) -> Vec2<T> {
+                    Vec2 { x: self.$a2, y: self.$b2 }
+                }
+            )*
+            $(
+                #[doc = concat!("Get the vector (", stringify!($a3), ", ", stringify!($b3), ", ", stringify!($c3), ")")]
+                pub const fn $name3(self) -> Vec3<T> {
+                    Vec3 { x: self.$a3, y: self.$b3, z: self.$c3 }
+                }
+            )*
+            $(
+                #[doc = concat!("Get the vector (", stringify!($a4), ", ", stringify!($b4), ", ", stringify!($c4), ", ", stringify!($d4), ")")]
+                pub const fn $name4(self) -> Vec4<T> {
+                    Vec4 { x: self.$a4, y: self.$b4, z: self.$c4, w: self.$d4 }
+                }
+            )*
+        }
+    };
+}
+
+swizzle!(
+    xy: x y, xz: x z, xw: x w, yx: y x, yz: y z, yw: y w,
+    zx: z x, zy: z y, zw: z w, wx: w x, wy: w y, wz: w z;
+    xyz: x y z, xyw: x y w, xzw: x z w, yzw: y z w, zyx: z y x, wzy: w z y;
+    xyzw: x y z w, wzyx: w z y x, wxyz: w x y z, yzwx: y z w x, zwxy: z w x y
+);
+
+impl<T> Index<usize> for Vec4<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Vec4 index out of range: {}", index)
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec4<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Vec4 index out of range: {}", index)
+        }
+    }
+}
+
+impl<T> From<[T; 4]> for Vec4<T> {
+    fn from([x, y, z, w]: [T; 4]) -> Self {
+        Vec4 { x, y, z, w }
+    }
+}
+
+impl<T> From<Vec4<T>> for [T; 4] {
+    fn from(v: Vec4<T>) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl<T> From<(T, T, T, T)> for Vec4<T> {
+    fn from((x, y, z, w): (T, T, T, T)) -> Self {
+        Vec4 { x, y, z, w }
+    }
+}
+
+impl<T> From<Vec4<T>> for (T, T, T, T) {
+    fn from(v: Vec4<T>) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec4<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+/// Implements methods and operators common to all scalar types
+macro_rules! impl_vec4 {
+    ($t:ty, $zero:literal, $one:literal) => {
+        impl Vec4<$t> {
+            /// Creates vector: (0, 0, 0, 0)
+            pub const fn zero() -> Self {
+                Vec4 { x: $zero, y: $zero, z: $zero, w: $zero }
+            }
+
+            /// Creates vector: (1, 1, 1, 1)
+            pub const fn one() -> Self {
+                Vec4 { x: $one, y: $one, z: $one, w: $one }
+            }
+
+            /// Calculate the dot product of two vectors
+            pub fn dot(lhs: Self, rhs: Self) -> $t {
+                lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z + lhs.w * rhs.w
+            }
+
+            /// Get the squared length of the vector
+            ///
+            /// Recommended when comparing lengths as it
+            /// avoids unnecessary square root operations
+            pub fn sqr_magnitude(self) -> $t {
+                self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+            }
+
+            /// Get the component-wise minimum of two vectors
+            pub fn min(lhs: Self, rhs: Self) -> Self {
+                Vec4 {
+                    x: lhs.x.min(rhs.x),
+                    y: lhs.y.min(rhs.y),
+                    z: lhs.z.min(rhs.z),
+                    w: lhs.w.min(rhs.w)
+                }
+            }
+
+            /// Get the component-wise maximum of two vectors
+            pub fn max(lhs: Self, rhs: Self) -> Self {
+                Vec4 {
+                    x: lhs.x.max(rhs.x),
+                    y: lhs.y.max(rhs.y),
+                    z: lhs.z.max(rhs.z),
+                    w: lhs.w.max(rhs.w)
+                }
+            }
+
+            /// Clamp each component between the components of `min` and `max`
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self::min(Self::max(self, min), max)
+            }
+
+            /// Get the smallest component
+            pub fn min_component(self) -> $t {
+                self.x.min(self.y).min(self.z).min(self.w)
+            }
+
+            /// Get the largest component
+            pub fn max_component(self) -> $t {
+                self.x.max(self.y).max(self.z).max(self.w)
+            }
+        }
+
+        impl_op_ex!(+ | a: &Vec4<$t>, b: &Vec4<$t> | -> Vec4<$t> {
+            Vec4 {
+                x: a.x + b.x,
+                y: a.y + b.y,
+                z: a.z + b.z,
+                w: a.w + b.w
+            }
+        });
+
+        impl_op_ex!(- | a: &Vec4<$t>, b: &Vec4<$t> | -> Vec4<$t> {
+            Vec4 {
+                x: a.x - b.x,
+                y: a.y - b.y,
+                z: a.z - b.z,
+                w: a.w - b.w
+            }
+        });
+
+        impl_op_ex!(* | a: &Vec4<$t>, b: &Vec4<$t> | -> Vec4<$t> {
+            Vec4 {
+                x: a.x * b.x,
+                y: a.y * b.y,
+                z: a.z * b.z,
+                w: a.w * b.w
+            }
+        });
+
+        impl_op_ex!(/ | a: &Vec4<$t>, b: &Vec4<$t> | -> Vec4<$t> {
+            Vec4 {
+                x: a.x / b.x,
+                y: a.y / b.y,
+                z: a.z / b.z,
+                w: a.w / b.w
+            }
+        });
+
+        impl_op_ex_commutative!(* | a: &Vec4<$t>, b: &$t | -> Vec4<$t> {
+            Vec4 {
+                x: a.x * b,
+                y: a.y * b,
+                z: a.z * b,
+                w: a.w * b
+            }
+        });
+
+        impl_op_ex!(/ | a: &Vec4<$t>, b: &$t | -> Vec4<$t> {
+            Vec4 {
+                x: a.x / b,
+                y: a.y / b,
+                z: a.z / b,
+                w: a.w / b
+            }
+        });
+
+        impl_op_ex!(+= | a: &mut Vec4<$t>, b: &Vec4<$t>| {
+            a.x += b.x;
+            a.y += b.y;
+            a.z += b.z;
+            a.w += b.w;
+        });
+
+        impl_op_ex!(-= | a: &mut Vec4<$t>, b: &Vec4<$t>| {
+            a.x -= b.x;
+            a.y -= b.y;
+            a.z -= b.z;
+            a.w -= b.w;
+        });
+
+        impl_op_ex!(*= | a: &mut Vec4<$t>, b: &Vec4<$t>| {
+            a.x *= b.x;
+            a.y *= b.y;
+            a.z *= b.z;
+            a.w *= b.w;
+        });
+
+        impl_op_ex!(/= | a: &mut Vec4<$t>, b: &Vec4<$t>| {
+            a.x /= b.x;
+            a.y /= b.y;
+            a.z /= b.z;
+            a.w /= b.w;
+        });
+
+        impl_op_ex!(*= | a: &mut Vec4<$t>, b: &$t| {
+            a.x *= b;
+            a.y *= b;
+            a.z *= b;
+            a.w *= b;
+        });
+
+        impl_op_ex!(/= | a: &mut Vec4<$t>, b: &$t| {
+            a.x /= b;
+            a.y /= b;
+            a.z /= b;
+            a.w /= b;
+        });
+
+        impl Vec4<$t> {
+            /// Convert to a vector with f32 components
+            pub fn as_f32(self) -> Vec4f {
+                Vec4 { x: self.x as f32, y: self.y as f32, z: self.z as f32, w: self.w as f32 }
+            }
+
+            /// Convert to a vector with f64 components
+            pub fn as_f64(self) -> Vec4d {
+                Vec4 { x: self.x as f64, y: self.y as f64, z: self.z as f64, w: self.w as f64 }
+            }
+
+            /// Convert to a vector with i32 components
+            ///
+            /// Float components are truncated towards zero
+            pub fn as_i32(self) -> Vec4i {
+                Vec4 { x: self.x as i32, y: self.y as i32, z: self.z as i32, w: self.w as i32 }
+            }
+
+            /// Convert to a vector with u32 components
+            ///
+            /// Float components are truncated towards zero,
+            /// negative components are clamped to zero
+            pub fn as_u32(self) -> Vec4u {
+                Vec4 { x: self.x.max($zero) as u32, y: self.y.max($zero) as u32, z: self.z.max($zero) as u32, w: self.w.max($zero) as u32 }
+            }
+        }
+    };
+}
+
+/// Implements methods and operators for signed scalar types
+macro_rules! impl_vec4_signed {
+    ($t:ty) => {
+        impl Vec4<$t> {
+            /// Get the absolute value of each component
+            pub fn abs(self) -> Self {
+                Vec4 { x: self.x.abs(), y: self.y.abs(), z: self.z.abs(), w: self.w.abs() }
+            }
+        }
+
+        impl_op_ex!(- | a: &Vec4<$t> | -> Vec4<$t> {
+            Vec4 {
+                x: -a.x,
+                y: -a.y,
+                z: -a.z,
+                w: -a.w
+            }
+        });
+    };
+}
+
+/// Implements methods for floating point scalar types
+macro_rules! impl_vec4_float {
+    ($t:ty) => {
+        impl Vec4<$t> {
+            /// Calculates the distance between two points in 4D space
+            pub fn distance(lhs: Self, rhs: Self) -> $t {
+                (lhs - rhs).magnitude()
+            }
+
+            /// Get the length of the vector
+            ///
+            /// If comparing lengths use sqr_magnitude
+            /// instead for improved performance
+            pub fn magnitude(self) -> $t {
+                self.sqr_magnitude().sqrt()
+            }
+
+            /// Normalize this vector (Scale of length 1)
+            pub fn normalize(&mut self) {
+                let mag = self.magnitude();
+                if mag == 0.0 { return; }
+
+                let scale = 1.0 / mag;
+                self.x *= scale;
+                self.y *= scale;
+                self.z *= scale;
+                self.w *= scale;
+            }
+
+            /// Get this vector normalized (Scale of length 1)
+            pub fn normalized(self) -> Self {
+                let mag = self.magnitude();
+                if mag == 0.0 { return Self::zero(); }
+
+                let scale = 1.0 / mag;
+                Vec4 {
+                    x: self.x * scale,
+                    y: self.y * scale,
+                    z: self.z * scale,
+                    w: self.w * scale
+                }
+            }
+
+            /// Linearly interpolate between two vectors
+            ///
+            /// # Arguments
+            ///
+            /// * `from` - The vector at `t = 0`
+            /// * `to` - The vector at `t = 1`
+            /// * `t` - The interpolation factor
+            pub fn lerp(from: Self, to: Self, t: $t) -> Self {
+                from + (to - from) * t
+            }
+
+            /// Round each component down
+            pub fn floor(self) -> Self {
+                Vec4 { x: self.x.floor(), y: self.y.floor(), z: self.z.floor(), w: self.w.floor() }
+            }
+
+            /// Round each component up
+            pub fn ceil(self) -> Self {
+                Vec4 { x: self.x.ceil(), y: self.y.ceil(), z: self.z.ceil(), w: self.w.ceil() }
+            }
+
+            /// Round each component to the nearest integer
+            pub fn round(self) -> Self {
+                Vec4 { x: self.x.round(), y: self.y.round(), z: self.z.round(), w: self.w.round() }
+            }
+        }
+    };
+}
+
+impl_vec4!(f32, 0.0, 1.0);
+impl_vec4!(f64, 0.0, 1.0);
+impl_vec4!(i32, 0, 1);
+impl_vec4!(u32, 0, 1);
+
+impl_vec4_signed!(f32);
+impl_vec4_signed!(f64);
+impl_vec4_signed!(i32);
+
+impl_vec4_float!(f32);
+impl_vec4_float!(f64);
+
+impl From<Vec4f> for Vec4d {
+    fn from(v: Vec4f) -> Self {
+        v.as_f64()
+    }
+}
+
+impl From<Vec4i> for Vec4d {
+    fn from(v: Vec4i) -> Self {
+        v.as_f64()
+    }
+}
+
+impl From<Vec4u> for Vec4d {
+    fn from(v: Vec4u) -> Self {
+        v.as_f64()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{vec2::Vec2i, vec3::Vec3i};
+
+    const EPSILON: f32 = 1e-6;
+
+    fn assert_vec_eq(a: Vec4f, b: Vec4f) {
+        assert!((a - b).magnitude() < EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn operators_work_for_every_scalar() {
+        assert_eq!(Vec4f::splat(1.0) + Vec4f::new(1.0, 2.0, 3.0, 4.0), Vec4f::new(2.0, 3.0, 4.0, 5.0));
+        assert_eq!(Vec4d::new(2.0, 4.0, 6.0, 8.0) / Vec4d::splat(2.0), Vec4d::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vec4i::new(1, 2, 3, 4) - Vec4i::one(), Vec4i::new(0, 1, 2, 3));
+        assert_eq!(2 * Vec4u::new(1, 2, 3, 4), Vec4u::new(2, 4, 6, 8));
+        assert_eq!(Vec4i::dot(Vec4i::new(1, 2, 3, 4), Vec4i::new(1, -1, 1, -1)), -2);
+        assert_eq!(-Vec4i::new(1, -2, 3, -4), Vec4i::new(-1, 2, -3, 4));
+    }
+
+    #[test]
+    fn swizzles_reorder_components() {
+        let v = Vec4i::new(1, 2, 3, 4);
+        assert_eq!(v.wzyx(), Vec4i::new(4, 3, 2, 1));
+        assert_eq!(v.zwxy(), Vec4i::new(3, 4, 1, 2));
+        assert_eq!(v.wzy(), Vec3i::new(4, 3, 2));
+        assert_eq!(v.yw(), Vec2i::new(2, 4));
+        assert_eq!(v.truncate(), v.xyz());
+    }
+
+    #[test]
+    fn index_reads_and_writes_components() {
+        let mut v = Vec4i::zero();
+        for i in 0..4 {
+            v[i] = i as i32 * 10;
+        }
+        assert_eq!(v, Vec4i::new(0, 10, 20, 30));
+        assert_eq!(v[3], 30);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec4 index out of range: 4")]
+    fn index_out_of_range_panics() {
+        let _ = Vec4u::one()[4];
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let v: Vec4f = [1.0, 2.0, 3.0, 4.0].into();
+        assert_eq!(<[f32; 4]>::from(v), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(Vec4f::from((1.0, 2.0, 3.0, 4.0)), v);
+        assert_eq!(<(f32, f32, f32, f32)>::from(v), (1.0, 2.0, 3.0, 4.0));
+
+        assert_eq!(Vec4d::from(v), Vec4d::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vec4d::from(Vec4i::new(-1, 0, 1, 2)), Vec4d::new(-1.0, 0.0, 1.0, 2.0));
+        assert_eq!(Vec4d::from(Vec4u::new(5, 6, 7, 8)), Vec4d::new(5.0, 6.0, 7.0, 8.0));
+        assert_eq!(Vec4f::new(-0.5, 0.5, 1.9, -3.0).as_u32(), Vec4u::new(0, 0, 1, 0));
+        assert_eq!(Vec4i::new(-1, 1, i32::MIN, i32::MAX).as_u32(), Vec4u::new(0, 1, 0, i32::MAX as u32));
+    }
+
+    #[test]
+    fn min_max_and_clamp_are_componentwise() {
+        let a = Vec4u::new(1, 9, 3, 7);
+        let b = Vec4u::new(2, 8, 3, 0);
+        assert_eq!(Vec4u::min(a, b), Vec4u::new(1, 8, 3, 0));
+        assert_eq!(Vec4u::max(a, b), Vec4u::new(2, 9, 3, 7));
+        assert_eq!(a.clamp(Vec4u::splat(2), Vec4u::splat(6)), Vec4u::new(2, 6, 3, 6));
+        assert_eq!(a.min_component(), 1);
+        assert_eq!(a.max_component(), 9);
+    }
+
+    #[test]
+    fn float_helpers() {
+        let a = Vec4f::new(1.0, 0.0, 0.0, 1.0);
+        let b = Vec4f::new(0.0, 1.0, 0.0, 1.0);
+        assert_vec_eq(Vec4f::lerp(a, b, 0.0), a);
+        assert_vec_eq(Vec4f::lerp(a, b, 1.0), b);
+        assert_vec_eq(Vec4f::lerp(a, b, 0.5), Vec4f::new(0.5, 0.5, 0.0, 1.0));
+        assert!((Vec4f::new(1.0, 1.0, 1.0, 1.0).magnitude() - 2.0).abs() < EPSILON);
+        assert_vec_eq(Vec4f::new(0.0, 2.0, 0.0, 0.0).normalized(), Vec4f::new(0.0, 1.0, 0.0, 0.0));
+    }
+}
\ No newline at end of file
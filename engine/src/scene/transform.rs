@@ -0,0 +1,423 @@
+use std::cell::Cell;
+
+use crate::math::vec3::Vec3f;
+use crate::math::vec4::Vec4f;
+use crate::math::mat4f::Mat4f;
+use crate::math::quatf::Quatf;
+
+/// A position, rotation and scale
+/// 
+/// Missing fields take their default when deserialized
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Transform {
+    pub position: Vec3f,
+    pub rotation: Quatf,
+    pub scale: Vec3f
+}
+
+impl Transform {
+    /// Creates a new `Transform`
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position
+    /// * `rotation` - The rotation
+    /// * `scale` - The scale
+    pub const fn new(position: Vec3f, rotation: Quatf, scale: Vec3f) -> Self {
+        Transform { position, rotation, scale }
+    }
+
+    /// Creates a transform at a position with no rotation or scaling
+    pub fn from_position(position: Vec3f) -> Self {
+        Transform { position, ..Default::default() }
+    }
+
+    /// Creates a transform by decomposing a matrix
+    pub fn from_matrix(matrix: &Mat4f) -> Self {
+        let (position, rotation, scale) = matrix.decompose();
+        Transform { position, rotation, scale }
+    }
+
+    /// Get the transformation matrix
+    pub fn matrix(&self) -> Mat4f {
+        Mat4f::transformation(self.position, self.rotation, self.scale)
+    }
+
+    /// Get the forward direction (+z rotated)
+    pub fn forward(&self) -> Vec3f {
+        self.rotation.rotate(Vec3f::forward())
+    }
+
+    /// Get the right direction (+x rotated)
+    pub fn right(&self) -> Vec3f {
+        self.rotation.rotate(Vec3f::right())
+    }
+
+    /// Get the up direction (+y rotated)
+    pub fn up(&self) -> Vec3f {
+        self.rotation.rotate(Vec3f::up())
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform { position: Vec3f::zero(), rotation: Quatf::identity(), scale: Vec3f::one() }
+    }
+}
+
+/// A handle to a transform in a `TransformHierarchy`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TransformId {
+    index: u32,
+    generation: u32
+}
+
+struct Node {
+    transform: Transform,
+    parent: Option<TransformId>,
+    children: Vec<TransformId>,
+    local_matrix: Cell<Mat4f>,
+    world_matrix: Cell<Mat4f>,
+    local_dirty: Cell<bool>,
+    world_dirty: Cell<bool>
+}
+
+struct Slot {
+    generation: u32,
+    node: Option<Node>
+}
+
+/// A set of transforms with parent/child relationships
+///
+/// Local and world matrices are cached and only recomputed when
+/// a transform, or one of its ancestors, has changed
+#[derive(Default)]
+pub struct TransformHierarchy {
+    slots: Vec<Slot>,
+    free: Vec<u32>
+}
+
+impl TransformHierarchy {
+    /// Creates a new empty `TransformHierarchy`
+    pub fn new() -> Self {
+        TransformHierarchy { slots: Vec::new(), free: Vec::new() }
+    }
+
+    /// Add a transform
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The local transform
+    /// * `parent` - The parent to attach to, or `None` for a root
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is not in the hierarchy
+    pub fn insert(&mut self, transform: Transform, parent: Option<TransformId>) -> TransformId {
+        if let Some(parent) = parent {
+            assert!(self.contains(parent), "Parent transform is not in the hierarchy");
+        }
+
+        let node = Node {
+            transform,
+            parent,
+            children: Vec::new(),
+            local_matrix: Cell::new(Mat4f::identity()),
+            world_matrix: Cell::new(Mat4f::identity()),
+            local_dirty: Cell::new(true),
+            world_dirty: Cell::new(true)
+        };
+
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.node = Some(node);
+                TransformId { index, generation: slot.generation }
+            },
+            None => {
+                self.slots.push(Slot { generation: 0, node: Some(node) });
+                TransformId { index: self.slots.len() as u32 - 1, generation: 0 }
+            }
+        };
+
+        if let Some(parent) = parent {
+            self.node_mut(parent).children.push(id);
+        }
+        id
+    }
+
+    /// Remove a transform and all of its descendants
+    pub fn remove(&mut self, id: TransformId) {
+        if let Some(parent) = self.node(id).parent {
+            self.node_mut(parent).children.retain(|child| *child != id);
+        }
+
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let slot = &mut self.slots[id.index as usize];
+            let node = slot.node.take().unwrap();
+            slot.generation = slot.generation.wrapping_add(1);
+            self.free.push(id.index);
+            stack.extend(node.children);
+        }
+    }
+
+    /// Check if a transform is in the hierarchy
+    pub fn contains(&self, id: TransformId) -> bool {
+        self.slots.get(id.index as usize)
+            .is_some_and(|slot| slot.generation == id.generation && slot.node.is_some())
+    }
+
+    /// Get the number of transforms in the hierarchy
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Check if the hierarchy has no transforms
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the local transform
+    pub fn get(&self, id: TransformId) -> &Transform {
+        &self.node(id).transform
+    }
+
+    /// Replace the local transform
+    pub fn set(&mut self, id: TransformId, transform: Transform) {
+        self.node_mut(id).transform = transform;
+        self.mark_dirty(id);
+    }
+
+    /// Set the local position
+    pub fn set_position(&mut self, id: TransformId, position: Vec3f) {
+        self.node_mut(id).transform.position = position;
+        self.mark_dirty(id);
+    }
+
+    /// Set the local rotation
+    pub fn set_rotation(&mut self, id: TransformId, rotation: Quatf) {
+        self.node_mut(id).transform.rotation = rotation;
+        self.mark_dirty(id);
+    }
+
+    /// Set the local scale
+    pub fn set_scale(&mut self, id: TransformId, scale: Vec3f) {
+        self.node_mut(id).transform.scale = scale;
+        self.mark_dirty(id);
+    }
+
+    /// Get the parent of a transform
+    pub fn parent(&self, id: TransformId) -> Option<TransformId> {
+        self.node(id).parent
+    }
+
+    /// Get the children of a transform
+    pub fn children(&self, id: TransformId) -> &[TransformId] {
+        &self.node(id).children
+    }
+
+    /// Attach a transform to a new parent, keeping its local transform
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The transform to move
+    /// * `parent` - The new parent, or `None` to make it a root
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is `id` or one of its descendants
+    pub fn set_parent(&mut self, id: TransformId, parent: Option<TransformId>) {
+        if let Some(parent) = parent {
+            let mut ancestor = Some(parent);
+            while let Some(current) = ancestor {
+                assert!(current != id, "A transform cannot be parented to itself or its descendants");
+                ancestor = self.node(current).parent;
+            }
+        }
+
+        if let Some(old_parent) = self.node(id).parent {
+            self.node_mut(old_parent).children.retain(|child| *child != id);
+        }
+        if let Some(parent) = parent {
+            self.node_mut(parent).children.push(id);
+        }
+        self.node_mut(id).parent = parent;
+        self.mark_world_dirty(id);
+    }
+
+    /// Attach a transform to a new parent, keeping its world transform
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The transform to move
+    /// * `parent` - The new parent, or `None` to make it a root
+    pub fn set_parent_keep_world(&mut self, id: TransformId, parent: Option<TransformId>) {
+        let world = self.world_matrix(id);
+        let parent_inverse = parent
+            .and_then(|parent| self.world_matrix(parent).inverse())
+            .unwrap_or_else(Mat4f::identity);
+        self.set_parent(id, parent);
+        self.set(id, Transform::from_matrix(&(parent_inverse * world)));
+    }
+
+    /// Get the local transformation matrix
+    pub fn local_matrix(&self, id: TransformId) -> Mat4f {
+        let node = self.node(id);
+        if node.local_dirty.get() {
+            node.local_matrix.set(node.transform.matrix());
+            node.local_dirty.set(false);
+        }
+        node.local_matrix.get()
+    }
+
+    /// Get the world transformation matrix
+    pub fn world_matrix(&self, id: TransformId) -> Mat4f {
+        let node = self.node(id);
+        if node.world_dirty.get() {
+            let local = self.local_matrix(id);
+            let world = match node.parent {
+                Some(parent) => self.world_matrix(parent) * local,
+                None => local
+            };
+            node.world_matrix.set(world);
+            node.world_dirty.set(false);
+        }
+        node.world_matrix.get()
+    }
+
+    /// Get the position in world space
+    pub fn world_position(&self, id: TransformId) -> Vec3f {
+        let world = self.world_matrix(id);
+        Vec3f::new(world.get(0, 3), world.get(1, 3), world.get(2, 3))
+    }
+
+    /// Get the forward direction (+z) in world space
+    pub fn forward(&self, id: TransformId) -> Vec3f {
+        self.world_direction(id, Vec3f::forward())
+    }
+
+    /// Get the right direction (+x) in world space
+    pub fn right(&self, id: TransformId) -> Vec3f {
+        self.world_direction(id, Vec3f::right())
+    }
+
+    /// Get the up direction (+y) in world space
+    pub fn up(&self, id: TransformId) -> Vec3f {
+        self.world_direction(id, Vec3f::up())
+    }
+
+    fn world_direction(&self, id: TransformId, direction: Vec3f) -> Vec3f {
+        let world = self.world_matrix(id) * Vec4f::new(direction.x, direction.y, direction.z, 0.0);
+        world.truncate().normalized()
+    }
+
+    fn node(&self, id: TransformId) -> &Node {
+        assert!(self.contains(id), "Transform is not in the hierarchy");
+        self.slots[id.index as usize].node.as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, id: TransformId) -> &mut Node {
+        assert!(self.contains(id), "Transform is not in the hierarchy");
+        self.slots[id.index as usize].node.as_mut().unwrap()
+    }
+
+    fn mark_dirty(&mut self, id: TransformId) {
+        self.node(id).local_dirty.set(true);
+        self.mark_world_dirty(id);
+    }
+
+    /// Mark a subtree's world matrices dirty
+    ///
+    /// A dirty node's descendants are always dirty too,
+    /// so the walk stops at nodes that are already dirty
+    fn mark_world_dirty(&mut self, id: TransformId) {
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = self.node(id);
+            if node.world_dirty.get() {
+                continue;
+            }
+            node.world_dirty.set(true);
+            stack.extend_from_slice(&node.children);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: Vec3f, b: Vec3f) -> bool {
+        Vec3f::distance(a, b) < 1e-4
+    }
+
+    #[test]
+    fn child_follows_parent() {
+        let mut hierarchy = TransformHierarchy::new();
+        let body = hierarchy.insert(Transform::from_position(Vec3f::new(10.0, 0.0, 0.0)), None);
+        let hand = hierarchy.insert(Transform::from_position(Vec3f::new(0.0, 0.0, 1.0)), Some(body));
+        let weapon = hierarchy.insert(Transform::from_position(Vec3f::new(0.0, 1.0, 0.0)), Some(hand));
+
+        assert!(approx_eq(hierarchy.world_position(weapon), Vec3f::new(10.0, 1.0, 1.0)));
+
+        // Turn the body to face +x, the hand is now 1 unit to the right of it
+        hierarchy.set_rotation(body, Quatf::from_axis_angle(Vec3f::up(), f32::to_radians(90.0)));
+        assert!(approx_eq(hierarchy.world_position(hand), Vec3f::new(11.0, 0.0, 0.0)));
+        assert!(approx_eq(hierarchy.world_position(weapon), Vec3f::new(11.0, 1.0, 0.0)));
+        assert!(approx_eq(hierarchy.forward(weapon), Vec3f::right()));
+        assert!(approx_eq(hierarchy.right(weapon), Vec3f::back()));
+        assert!(approx_eq(hierarchy.up(weapon), Vec3f::up()));
+
+        hierarchy.set_scale(body, Vec3f::splat(2.0));
+        assert!(approx_eq(hierarchy.world_position(weapon), Vec3f::new(12.0, 2.0, 0.0)));
+        assert!(approx_eq(hierarchy.forward(weapon), Vec3f::right()));
+    }
+
+    #[test]
+    fn reparenting() {
+        let mut hierarchy = TransformHierarchy::new();
+        let a = hierarchy.insert(Transform::from_position(Vec3f::new(1.0, 0.0, 0.0)), None);
+        let b = hierarchy.insert(Transform::from_position(Vec3f::new(0.0, 5.0, 0.0)), None);
+        let label = hierarchy.insert(Transform::from_position(Vec3f::new(0.0, 0.0, 2.0)), Some(a));
+
+        hierarchy.set_parent(label, Some(b));
+        assert_eq!(hierarchy.children(a), &[]);
+        assert_eq!(hierarchy.children(b), &[label]);
+        assert!(approx_eq(hierarchy.world_position(label), Vec3f::new(0.0, 5.0, 2.0)));
+
+        hierarchy.set_parent_keep_world(label, Some(a));
+        assert_eq!(hierarchy.parent(label), Some(a));
+        assert!(approx_eq(hierarchy.world_position(label), Vec3f::new(0.0, 5.0, 2.0)));
+        assert!(approx_eq(hierarchy.get(label).position, Vec3f::new(-1.0, 5.0, 2.0)));
+    }
+
+    #[test]
+    fn removing_removes_descendants() {
+        let mut hierarchy = TransformHierarchy::new();
+        let root = hierarchy.insert(Transform::default(), None);
+        let child = hierarchy.insert(Transform::default(), Some(root));
+        let grandchild = hierarchy.insert(Transform::default(), Some(child));
+        assert_eq!(hierarchy.len(), 3);
+
+        hierarchy.remove(child);
+        assert_eq!(hierarchy.len(), 1);
+        assert!(!hierarchy.contains(grandchild));
+        assert_eq!(hierarchy.children(root), &[]);
+
+        // Slots are reused without reviving stale handles
+        let other = hierarchy.insert(Transform::default(), None);
+        assert!(hierarchy.contains(other));
+        assert!(!hierarchy.contains(child));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_cycles() {
+        let mut hierarchy = TransformHierarchy::new();
+        let root = hierarchy.insert(Transform::default(), None);
+        let child = hierarchy.insert(Transform::default(), Some(root));
+        hierarchy.set_parent(root, Some(child));
+    }
+}
\ No newline at end of file
@@ -8,18 +8,23 @@ use sdl2::image::{InitFlag, Sdl2ImageContext};
 use super::layer::Layer;
 use super::window::Window;
 
+use crate::{Error, Result};
 use crate::graphics::renderer_2d::{Renderer2D, Rect};
 use crate::graphics::texture::Texture;
-use crate::math::vec2f::Vec2f;
-use crate::math::vec3f::Vec3f;
-use crate::math::vec4f::Vec4f;
+use crate::graphics::color::Color;
+use crate::math::vec2::Vec2f;
+use crate::math::vec3::Vec3f;
 use crate::math::mat4f::Mat4f;
+use crate::math::quatf::Quatf;
+use crate::math::geometry::aabb::Aabb;
+use crate::scene::transform::{Transform, TransformHierarchy};
 
 use crate::graphics::vertex_array::VertexArray;
 use crate::graphics::array_buffer::{ArrayBuffer, BufferLayout, BufferAttribute, AttributeType};
 use crate::graphics::index_buffer::IndexBuffer;
 use crate::graphics::shader::Shader;
 use crate::graphics::renderer::Renderer;
+use crate::graphics::pipeline::PipelineState;
 
 /// Main application
 pub struct Application {
@@ -31,19 +36,23 @@ pub struct Application {
 
 impl Application {
     /// Creates a new `Application`
-    pub fn new() -> Self {
-        let sdl = sdl2::init().unwrap();
-        let sdl_image = sdl2::image::init(InitFlag::PNG).unwrap();
-        let window = Window::new(&sdl);
+    ///
+    /// Returns an error if SDL or the window can't be initialized
+    pub fn new() -> Result<Self> {
+        let sdl = sdl2::init().map_err(Error::Context)?;
+        let sdl_image = sdl2::image::init(InitFlag::PNG).map_err(Error::Context)?;
+        let window = Window::new(&sdl)?;
 
         Renderer::init();
-        Renderer::set_clear_color(Vec4f::new(0.0, 0.0, 0.0, 0.0));
+        Renderer::set_clear_color(Color::TRANSPARENT);
         
-        Application { sdl, sdl_image, window, layers: Vec::new() }
+        Ok(Application { sdl, sdl_image, window, layers: Vec::new() })
     }
 
     /// Start executing the application
-    pub fn execute(&mut self) {
+    ///
+    /// Returns an error if a resource fails to load
+    pub fn execute(&mut self) -> Result<()> {
         // Vertex Buffer
         type Vertex = [f32; 3];
         const VERTICES: [Vertex; 4*6] = [
@@ -100,19 +109,20 @@ impl Application {
         let shader = Shader::new(
             VERTEX_SHADER, 
             FRAGMENT_SHADER
-        );
+        )?;
         shader.bind();
     
         // Set uniforms
-        let model = Mat4f::transformation(
-            Vec3f::new(0.0, 0.0, 0.0), 
-            Vec3f::new(0.0, 0.0, 0.0),
-            Vec3f::new(1.0, 1.0, 1.0));
+        let mut transforms = TransformHierarchy::new();
+        let cube = transforms.insert(Transform::default(), None);
+        let model = transforms.world_matrix(cube);
         let view = Mat4f::translate(-Vec3f::new(0.0, 0.0, -3.0));
         let projection = Mat4f::persp_fov(f32::to_radians(90.0), 16.0 / 9.0, 0.1, 10.0);
     
         shader.set_mat4f(&CString::new("model").unwrap(), model);
         shader.set_mat4f(&CString::new("view_projection").unwrap(), projection * view);
+        Renderer::set_culling_view_projection(projection * view);
+        let cube_bounds = Aabb::from_center_extents(Vec3f::zero(), Vec3f::splat(0.5));
 
         // 2D Renderer
         let projection_2d = Mat4f::ortho_off_center(0.0, 1280.0, 720.0, 0.0, -1.0, 1.0);
@@ -121,14 +131,16 @@ impl Application {
         let mut renderer_2d = Renderer2D::new(projection_2d * view_2d);
 
         // Texture
-        let texture = Texture::new("res/trident.png");
+        let texture = Texture::new("res/trident.png")?;
 
-        let mut event_pump = self.sdl.event_pump().unwrap();
+        let mut event_pump = self.sdl.event_pump().map_err(Error::Context)?;
     
         let mut angle: f32 = 0.0;
     
         'running: loop {
             Renderer::clear();
+            Renderer::reset_culled_count();
+            renderer_2d.reset_stats();
     
             'event_loop: for event in event_pump.poll_iter() {
                 // Propagate event through layers
@@ -155,29 +167,30 @@ impl Application {
             // Rotate quad
             angle += 1.0;
     
-            let model = Mat4f::transformation(
-                Vec3f::new(0.0, 0.0, 0.0), 
-                Vec3f::new(0.0, angle.to_radians(), 0.0),
-                Vec3f::new(1.0, 1.0, 1.0));
+            transforms.set_rotation(cube, Quatf::from_axis_angle(Vec3f::up(), angle.to_radians()));
+            let model = transforms.world_matrix(cube);
     
+            Renderer::set_pipeline_state(&PipelineState::opaque_3d());
             shader.bind();
             shader.set_mat4f(&CString::new("model").unwrap(), model);
-            Renderer::draw_elements(&vertex_array, 6*6);
+            Renderer::draw_elements_culled(&vertex_array, 6*6, &cube_bounds.transformed(&model));
 
+            Renderer::set_pipeline_state(&PipelineState::alpha_2d());
             renderer_2d.begin_batch();
             renderer_2d.batch_rect(
                 Rect::new(Vec3f::zero(), Vec2f::new(200.0, 100.0), Vec2f::zero(), Vec2f::zero(), Vec2f::one()),
-                Vec4f::new(0.0, 1.0, 1.0, 1.0)
+                Color::CYAN
             );
 
             renderer_2d.batch_textured_rect(
                 Rect::new(Vec3f::zero(), Vec2f::new(100.0, 100.0), Vec2f::zero(), Vec2f::zero(), Vec2f::one()),
                 &texture,
-                Vec4f::one()
+                Color::WHITE
             );
             renderer_2d.end_batch();
     
             self.window.native().gl_swap_window();
         }
+        Ok(())
     }
 }
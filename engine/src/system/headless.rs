@@ -0,0 +1,122 @@
+use khronos_egl as egl;
+
+use crate::{Error, Result};
+
+/// Mesa's platform for rendering without any window system
+const PLATFORM_SURFACELESS_MESA: egl::Enum = 0x31DD;
+
+/// An offscreen OpenGL context that does not need a window or display.\
+/// Renders into a pixel buffer that can be read back with `Renderer::read_pixels`
+///
+/// Uses EGL, on machines without a GPU Mesa's software rasterizer (llvmpipe) works
+pub struct HeadlessContext {
+    egl: egl::DynamicInstance<egl::EGL1_4>,
+    display: egl::Display,
+    surface: egl::Surface,
+    context: egl::Context,
+    width: u32,
+    height: u32
+}
+
+impl HeadlessContext {
+    /// Creates a new `HeadlessContext`.\
+    /// Initializes OpenGL context and functions
+    ///
+    /// Returns an error if EGL is unavailable or no suitable
+    /// configuration exists, e.g. so tests can be skipped
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the pixel buffer
+    /// * `height` - The height of the pixel buffer
+    pub fn new(width: u32, height: u32) -> Result<Self> {
+        let egl = unsafe { egl::DynamicInstance::<egl::EGL1_4>::load_required() }
+            .map_err(|error| Error::Context(format!("Failed to load EGL: {}", error)))?;
+
+        let display = Self::open_display(&egl)?;
+        egl.initialize(display)
+            .map_err(|error| Error::Context(format!("Failed to initialize EGL: {}", error)))?;
+
+        let config_attributes = [
+            egl::SURFACE_TYPE, egl::PBUFFER_BIT,
+            egl::RENDERABLE_TYPE, egl::OPENGL_BIT,
+            egl::RED_SIZE, 8,
+            egl::GREEN_SIZE, 8,
+            egl::BLUE_SIZE, 8,
+            egl::ALPHA_SIZE, 8,
+            egl::DEPTH_SIZE, 24,
+            egl::STENCIL_SIZE, 8,
+            egl::NONE
+        ];
+        let config = egl.choose_first_config(display, &config_attributes)
+            .map_err(|error| Error::Context(format!("Failed to choose EGL config: {}", error)))?
+            .ok_or_else(|| Error::Context("No EGL config supports offscreen OpenGL rendering".to_string()))?;
+
+        egl.bind_api(egl::OPENGL_API)
+            .map_err(|error| Error::Context(format!("Failed to bind OpenGL API: {}", error)))?;
+
+        let context_attributes = [
+            egl::CONTEXT_MAJOR_VERSION, 3,
+            egl::CONTEXT_MINOR_VERSION, 3,
+            egl::CONTEXT_OPENGL_PROFILE_MASK, egl::CONTEXT_OPENGL_CORE_PROFILE_BIT,
+            egl::NONE
+        ];
+        let context = egl.create_context(display, config, None, &context_attributes)
+            .map_err(|error| Error::Context(format!("Failed to create OpenGL context: {}", error)))?;
+
+        let surface_attributes = [
+            egl::WIDTH, width as egl::Int,
+            egl::HEIGHT, height as egl::Int,
+            egl::NONE
+        ];
+        let surface = egl.create_pbuffer_surface(display, config, &surface_attributes)
+            .map_err(|error| Error::Context(format!("Failed to create pixel buffer: {}", error)))?;
+
+        egl.make_current(display, Some(surface), Some(surface), Some(context))
+            .map_err(|error| Error::Context(format!("Failed to make OpenGL context current: {}", error)))?;
+
+        gl::load_with(|fn_name| {
+            egl.get_proc_address(fn_name)
+                .map_or(std::ptr::null(), |function| function as *const _)
+        });
+        unsafe {
+            gl::Viewport(0, 0, width as i32, height as i32);
+        }
+
+        Ok(HeadlessContext { egl, display, surface, context, width, height })
+    }
+
+    /// Open the surfaceless platform when available, otherwise the default display
+    fn open_display(egl: &egl::DynamicInstance<egl::EGL1_4>) -> Result<egl::Display> {
+        if let Some(egl_1_5) = egl.upcast::<egl::EGL1_5>() {
+            let display = unsafe {
+                egl_1_5.get_platform_display(PLATFORM_SURFACELESS_MESA, egl::DEFAULT_DISPLAY, &[egl::ATTRIB_NONE])
+            };
+            if let Ok(display) = display {
+                return Ok(display);
+            }
+        }
+        unsafe { egl.get_display(egl::DEFAULT_DISPLAY) }
+            .ok_or_else(|| Error::Context("No EGL display available".to_string()))
+    }
+
+    /// Get the width of the pixel buffer
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the height of the pixel buffer
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+        // Errors are ignored, there is nothing useful to do with them while dropping
+        let _ = self.egl.make_current(self.display, None, None, None);
+        let _ = self.egl.destroy_surface(self.display, self.surface);
+        let _ = self.egl.destroy_context(self.display, self.context);
+        let _ = self.egl.terminate(self.display);
+    }
+}
\ No newline at end of file
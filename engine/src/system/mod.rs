@@ -1,3 +1,8 @@
+#[cfg(feature = "window")]
 pub mod application;
+#[cfg(feature = "window")]
 pub mod window;
-pub mod layer;
\ No newline at end of file
+#[cfg(feature = "window")]
+pub mod layer;
+#[cfg(feature = "headless")]
+pub mod headless;
\ No newline at end of file
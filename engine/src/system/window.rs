@@ -1,5 +1,7 @@
 use sdl2::{Sdl, video::{GLContext, GLProfile, SwapInterval}};
 
+use crate::{Error, Result};
+
 /// Holds window information
 pub struct Window {
     window: sdl2::video::Window,
@@ -13,24 +15,28 @@ impl Window {
     /// # Arguments
     /// 
     /// * `sdl` - Reference to sdl
-    pub fn new(sdl: &Sdl) -> Self {
-        let video = sdl.video().unwrap();
+    ///
+    /// Returns an error if the window or OpenGL context can't be created
+    pub fn new(sdl: &Sdl) -> Result<Self> {
+        let video = sdl.video().map_err(Error::Context)?;
     
         let gl_attr = video.gl_attr();
         gl_attr.set_context_version(3, 3);
         gl_attr.set_context_profile(GLProfile::Core);
+        gl_attr.set_depth_size(24);
+        gl_attr.set_stencil_size(8);
          
         let window = video.window("Poseidon Engine", 1280, 720)
             .opengl()
             .build()
-            .unwrap();
+            .map_err(|error| Error::Context(error.to_string()))?;
             
-        let gl_context = window.gl_create_context().unwrap();
-        window.gl_make_current(&gl_context).unwrap();
-        video.gl_set_swap_interval(SwapInterval::VSync).unwrap();
+        let gl_context = window.gl_create_context().map_err(Error::Context)?;
+        window.gl_make_current(&gl_context).map_err(Error::Context)?;
+        video.gl_set_swap_interval(SwapInterval::VSync).map_err(Error::Context)?;
     
         gl::load_with(|fn_name| video.gl_get_proc_address(fn_name) as *const _);
-        Window { window, gl_context }
+        Ok(Window { window, gl_context })
     }
 
     /// Get the native SDL window
@@ -0,0 +1,434 @@
+#![cfg(feature = "headless")]
+
+use poseidon::graphics::color::Color;
+use poseidon::graphics::renderer::Renderer;
+use poseidon::graphics::renderer_2d::{Renderer2D, Rect, RectTransform, SortLayer};
+use poseidon::graphics::framebuffer::{Framebuffer, FramebufferDescriptor};
+use poseidon::graphics::texture::{Texture, TextureDescriptor};
+use poseidon::graphics::shape_2d::Stroke;
+#[cfg(feature = "ttf")]
+use poseidon::graphics::font::{Font, FontDescriptor, TextStyle};
+use poseidon::graphics::texture_atlas::{TextureAtlas, AtlasImage, AtlasDescriptor};
+use poseidon::graphics::device::TextureFormat;
+use poseidon::graphics::shader::{Shader, ShaderStage};
+use poseidon::Error;
+use poseidon::graphics::pipeline::{PipelineState, DepthState, StencilState, CompareFunction, ColorMask, CullMode};
+use poseidon::math::{vec2::Vec2f, vec3::Vec3f, mat4f::Mat4f};
+use poseidon::system::headless::HeadlessContext;
+
+const WIDTH: u32 = 64;
+const HEIGHT: u32 = 32;
+
+/// Create a context, failing the test on machines without EGL.\
+/// Set `POSEIDON_SKIP_HEADLESS` to skip the test instead
+fn context() -> Option<HeadlessContext> {
+    match HeadlessContext::new(WIDTH, HEIGHT) {
+        Ok(context) => {
+            Renderer::init();
+            Some(context)
+        },
+        Err(error) if std::env::var_os("POSEIDON_SKIP_HEADLESS").is_some() => {
+            eprintln!("Skipping headless test: {}", error);
+            None
+        },
+        Err(error) => panic!("Can't create a headless context, set POSEIDON_SKIP_HEADLESS to skip: {}", error)
+    }
+}
+
+fn rect(x: f32, y: f32, z: f32, width: f32, height: f32) -> Rect {
+    Rect::new(Vec3f::new(x, y, z), Vec2f::new(width, height), Vec2f::zero(), Vec2f::zero(), Vec2f::one())
+}
+
+fn pixel(pixels: &[u8], x: u32, y: u32) -> [u8; 4] {
+    let i = ((y * WIDTH + x) * 4) as usize;
+    [pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+}
+
+#[test]
+fn clear_color_is_read_back() {
+    let Some(_context) = context() else { return; };
+
+    Renderer::set_clear_color(Color::from_rgba8(255, 128, 0, 255));
+    Renderer::clear();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixels.len(), (WIDTH * HEIGHT * 4) as usize);
+    assert!(pixels.chunks(4).all(|p| p == [255, 128, 0, 255]));
+}
+
+#[test]
+fn renderer_2d_draws_rects() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(
+        Rect::new(Vec3f::new(8.0, 8.0, 0.0), Vec2f::new(16.0, 16.0), Vec2f::zero(), Vec2f::zero(), Vec2f::one()),
+        Color::CYAN);
+    renderer_2d.batch_rect(
+        Rect::new(Vec3f::new(40.0, 0.0, 0.0), Vec2f::new(8.0, 8.0), Vec2f::zero(), Vec2f::zero(), Vec2f::one()),
+        Color::YELLOW);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, 16, 16), [0, 255, 255, 255]);
+    assert_eq!(pixel(&pixels, 44, 4), [255, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 4, 4), [0, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 30, 30), [0, 0, 0, 255]);
+}
+
+#[test]
+fn framebuffer_is_rendered_and_sampled() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    // Draw the left half green into a multisampled framebuffer
+    let mut descriptor = FramebufferDescriptor::new(WIDTH, HEIGHT);
+    descriptor.samples = 4;
+    let framebuffer = Framebuffer::new(descriptor).unwrap();
+    framebuffer.bind();
+    Renderer::set_clear_color(Color::BLUE);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(
+        Rect::new(Vec3f::zero(), Vec2f::new(WIDTH as f32 / 2.0, HEIGHT as f32), Vec2f::zero(), Vec2f::zero(), Vec2f::one()),
+        Color::GREEN);
+    renderer_2d.end_batch();
+    framebuffer.resolve();
+
+    // Draw the resolved texture to the default framebuffer
+    Framebuffer::unbind();
+    Renderer::set_viewport(0, 0, WIDTH, HEIGHT);
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_textured_rect(
+        Rect::new(Vec3f::zero(), Vec2f::new(WIDTH as f32, HEIGHT as f32), Vec2f::zero(), Vec2f::zero(), Vec2f::one()),
+        framebuffer.color_texture(0),
+        Color::WHITE);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, 8, 16), [0, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 56, 16), [0, 0, 255, 255]);
+}
+
+#[test]
+fn incomplete_framebuffers_are_errors() {
+    let Some(_context) = context() else { return; };
+
+    // Without attachments there is nothing to render to
+    let descriptor = FramebufferDescriptor { color_formats: Vec::new(), depth_format: None, ..FramebufferDescriptor::new(WIDTH, HEIGHT) };
+    assert!(matches!(Framebuffer::new(descriptor.clone()), Err(Error::Framebuffer(_))));
+    assert!(matches!(Framebuffer::new(FramebufferDescriptor { samples: 4, ..descriptor }), Err(Error::Framebuffer(_))));
+    assert!(Framebuffer::new(FramebufferDescriptor::new(WIDTH, HEIGHT)).is_ok());
+}
+
+#[test]
+fn depth_and_stencil_tests() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+    let opaque = PipelineState { cull_mode: CullMode::None, ..PipelineState::opaque_3d() };
+
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+
+    // The near rect is drawn first, the far rect is hidden behind it
+    Renderer::set_pipeline_state(&opaque);
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(0.0, 0.0, -0.5, 16.0, 16.0), Color::RED);
+    renderer_2d.batch_rect(rect(8.0, 0.0, 0.5, 16.0, 16.0), Color::GREEN);
+    renderer_2d.end_batch();
+
+    // Mark the right half in the stencil buffer without drawing color
+    Renderer::set_pipeline_state(&PipelineState {
+        depth: DepthState::DISABLED,
+        stencil: StencilState::write(1),
+        color_mask: ColorMask::NONE,
+        ..opaque
+    });
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(32.0, 0.0, 0.0, 32.0, 32.0), Color::WHITE);
+    renderer_2d.end_batch();
+
+    // Fill the screen where marked
+    Renderer::set_pipeline_state(&PipelineState {
+        depth: DepthState::DISABLED,
+        stencil: StencilState::test(CompareFunction::Equal, 1),
+        ..opaque
+    });
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(0.0, 0.0, 0.0, WIDTH as f32, HEIGHT as f32), Color::BLUE);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, 12, 8), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 20, 8), [0, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 28, 8), [0, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 48, 16), [0, 0, 255, 255]);
+
+    // Clearing resets depth and stencil even with writes masked
+    Renderer::clear();
+    Renderer::set_pipeline_state(&opaque);
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(0.0, 0.0, 0.5, 8.0, 8.0), Color::GREEN);
+    renderer_2d.end_batch();
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, 4, 4), [0, 255, 0, 255]);
+}
+
+#[test]
+fn texture_regions_are_updated_and_read_back() {
+    let Some(_context) = context() else { return; };
+
+    for descriptor in [
+        TextureDescriptor::new(TextureFormat::R8),
+        TextureDescriptor::pixel_art(TextureFormat::Rg8),
+        TextureDescriptor::mipmapped(TextureFormat::Rgba8, 16.0)
+    ] {
+        let size = descriptor.format.bytes_per_pixel();
+        let texture = Texture::from_data(&vec![0; 3 * 3 * size], 3, 3, descriptor);
+        texture.update_region(1, 1, 2, 1, &vec![200; 2 * size]).unwrap();
+
+        let pixels = texture.read_pixels();
+        assert_eq!(pixels.len(), 3 * 3 * size);
+        for (i, &value) in pixels.iter().enumerate() {
+            let (x, y) = ((i / size) % 3, (i / size) / 3);
+            assert_eq!(value, if y == 1 && x > 0 { 200 } else { 0 }, "{:?} at {}, {}", descriptor.format, x, y);
+        }
+    }
+}
+
+#[test]
+fn pixel_art_textures_are_not_interpolated() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    // A red and a blue texel stretched over the whole screen
+    let data = [255, 0, 0, 255, 0, 0, 255, 255];
+    let texture = Texture::from_data(&data, 2, 1, TextureDescriptor::pixel_art(TextureFormat::Rgba8));
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_textured_rect(rect(0.0, 0.0, 0.0, WIDTH as f32, HEIGHT as f32), &texture, Color::WHITE);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, WIDTH / 2 - 1, 16), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, WIDTH / 2, 16), [0, 0, 255, 255]);
+}
+
+#[test]
+fn shader_errors_report_stage_and_lines() {
+    let Some(_context) = context() else { return; };
+
+    const VERTEX_SHADER: &str = "#version 330 core
+layout (location = 0) in vec3 pos;
+void main() {
+    gl_Position = vec4(pos, 1.0);
+}";
+    const FRAGMENT_SHADER: &str = "#version 330 core
+out vec4 color;
+void main() {
+    color = vec4(1.0);
+    color += missing;
+}";
+
+    assert!(Shader::new(VERTEX_SHADER, "#version 330 core\nout vec4 color;\nvoid main() { color = vec4(1.0); }").is_ok());
+    match Shader::new(VERTEX_SHADER, FRAGMENT_SHADER) {
+        Err(Error::ShaderCompile { stage, log, lines }) => {
+            assert_eq!(stage, ShaderStage::Fragment);
+            assert!(!log.is_empty());
+            assert_eq!(lines, vec![5]);
+        },
+        Err(error) => panic!("Unexpected error: {}", error),
+        Ok(_) => panic!("Shader compiled")
+    }
+}
+
+#[test]
+fn atlas_images_are_packed_into_their_sprites() {
+    let Some(_context) = context() else { return; };
+
+    let images: Vec<AtlasImage> = (1..=20u8)
+        .map(|i| {
+            let (width, height) = (1 + i as u32 % 7, 1 + i as u32 % 5);
+            AtlasImage::new(format!("image {}", i), vec![i * 10; (width * height * 4) as usize], width, height)
+        })
+        .collect();
+    let atlas = TextureAtlas::pack(&images, &AtlasDescriptor::new()).unwrap();
+    let texture = atlas.texture();
+    let pixels = texture.read_pixels();
+
+    assert_eq!(atlas.len(), images.len());
+    for image in &images {
+        let sprite = atlas.sprite(&image.name).unwrap();
+        assert_eq!((sprite.width, sprite.height), (image.width, image.height));
+        for y in sprite.y..sprite.y + sprite.height {
+            for x in sprite.x..sprite.x + sprite.width {
+                let i = ((y * texture.width() + x) * 4) as usize;
+                assert_eq!(&pixels[i..i + 4], &image.data[..4], "{} at {}, {}", image.name, x, y);
+            }
+        }
+    }
+}
+
+#[test]
+fn batches_past_the_texture_slots_are_drawn() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    // A column per texture, more than fit in one batch's slots
+    let textures: Vec<Texture> = (0..40u8).map(|i| Texture::with_data(&[i * 6, 255 - i * 6, i, 255], 1, 1)).collect();
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    for (i, texture) in textures.iter().enumerate() {
+        renderer_2d.batch_textured_rect(rect(i as f32, 0.0, 0.0, 1.0, HEIGHT as f32), texture, Color::WHITE);
+    }
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    for i in 0..40u8 {
+        assert_eq!(pixel(&pixels, i as u32, 16), [i * 6, 255 - i * 6, i, 255], "column {}", i);
+    }
+    assert_eq!(renderer_2d.stats().draw_calls, 2);
+}
+
+#[test]
+fn transformed_rects_are_rotated_and_flipped() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+    let texture = Texture::from_data(&[255, 0, 0, 255, 0, 0, 255, 255], 2, 1, TextureDescriptor::pixel_art(TextureFormat::Rgba8));
+    let centered = |x: f32, width: f32, height: f32| {
+        Rect::new(Vec3f::new(x, 16.0, 0.0), Vec2f::new(width, height), Vec2f::splat(0.5), Vec2f::zero(), Vec2f::one())
+    };
+
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    // Red then blue, mirrored to blue then red
+    let flipped = RectTransform { flip_x: true, ..RectTransform::IDENTITY };
+    renderer_2d.batch_transformed_textured_rect(centered(16.0, 32.0, 16.0), &flipped, &texture, Color::WHITE);
+    // A wide rect turned upright around its center
+    let rotated = RectTransform::rotated(std::f32::consts::FRAC_PI_2);
+    renderer_2d.batch_transformed_rect(centered(48.0, 32.0, 8.0), &rotated, Color::GREEN);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, 8, 16), [0, 0, 255, 255]);
+    assert_eq!(pixel(&pixels, 24, 16), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 48, 2), [0, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 48, 29), [0, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 38, 16), [0, 0, 0, 255]);
+    assert_eq!(renderer_2d.stats().draw_calls, 1);
+}
+
+#[test]
+fn shapes_are_filled_and_stroked() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_circle(Vec2f::new(16.0, 16.0), 12.0, 0.0, Color::RED);
+    renderer_2d.batch_line(Vec2f::new(33.0, 4.0), Vec2f::new(33.0, 28.0), 0.0, &Stroke::new(4.0), Color::GREEN);
+    renderer_2d.batch_circle_outline(Vec2f::new(48.0, 16.0), 8.0, 0.0, 2.0, Color::BLUE);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    // Inside the circle, but outside its bounding box corners
+    assert_eq!(pixel(&pixels, 16, 16), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 26, 16), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 6, 6), [0, 0, 0, 255]);
+    // The line has butt caps
+    assert_eq!(pixel(&pixels, 33, 16), [0, 255, 0, 255]);
+    assert_eq!(pixel(&pixels, 33, 2), [0, 0, 0, 255]);
+    // The outline is hollow
+    assert_eq!(pixel(&pixels, 56, 16), [0, 0, 255, 255]);
+    assert_eq!(pixel(&pixels, 48, 16), [0, 0, 0, 255]);
+    assert_eq!(renderer_2d.stats().draw_calls, 1);
+}
+
+#[cfg(feature = "ttf")]
+#[test]
+fn ttf_text_is_drawn_with_coverage_and_distance_fields() {
+    const FONT_PATH: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+    let Some(_context) = context() else { return; };
+    if !std::path::Path::new(FONT_PATH).exists() {
+        eprintln!("Skipping TrueType test: {} is missing", FONT_PATH);
+        return;
+    }
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+    let coverage = Font::load_ttf(FONT_PATH, &FontDescriptor::new(16.0)).unwrap();
+    let sdf = Font::load_ttf(FONT_PATH, &FontDescriptor { sdf_spread: Some(4), ..FontDescriptor::new(16.0) }).unwrap();
+    assert!(sdf.is_sdf());
+    let text_size = coverage.measure("Hi", &TextStyle::new());
+    assert!(text_size.x > 10.0 && text_size.y >= 16.0);
+
+    let scaled = TextStyle { scale: 1.5, ..TextStyle::new() };
+    let coverage_position = Vec3f::new(4.0, 28.0, 0.0);
+    let sdf_position = Vec3f::new(36.0, 28.0, 0.0);
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_text(&coverage, "I", coverage_position, &TextStyle::new(), Color::WHITE);
+    renderer_2d.batch_text(&sdf, "I", sdf_position, &scaled, Color::GREEN);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    let center = |font: &Font, position: Vec3f, style: &TextStyle| {
+        let bounds = font.layout("I", position.truncate(), style)[0].bounds;
+        let center = bounds.center();
+        pixel(&pixels, center.x as u32, center.y as u32)
+    };
+    assert!(center(&coverage, coverage_position, &TextStyle::new())[0] > 200);
+    assert!(center(&sdf, sdf_position, &scaled)[1] > 200);
+    // The distance field's spread is transparent
+    assert_eq!(pixel(&pixels, sdf_position.x as u32, 2), [0, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 1, 1), [0, 0, 0, 255]);
+    assert_eq!(renderer_2d.stats().draw_calls, 1);
+}
+
+#[test]
+fn sort_layers_decide_overlap() {
+    let Some(_context) = context() else { return; };
+
+    let projection = Mat4f::ortho_off_center(0.0, WIDTH as f32, HEIGHT as f32, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    Renderer::set_clear_color(Color::BLACK);
+    Renderer::clear();
+    renderer_2d.begin_batch();
+    renderer_2d.set_sort_layer(SortLayer::new(1, 0));
+    renderer_2d.batch_rect(rect(0.0, 0.0, 0.0, 32.0, 32.0), Color::RED);
+    renderer_2d.set_sort_layer(SortLayer::new(0, 0));
+    renderer_2d.batch_rect(rect(16.0, 0.0, 0.0, 32.0, 32.0), Color::GREEN);
+    renderer_2d.end_batch();
+
+    let pixels = Renderer::read_pixels(0, 0, WIDTH, HEIGHT);
+    assert_eq!(pixel(&pixels, 8, 16), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 24, 16), [255, 0, 0, 255]);
+    assert_eq!(pixel(&pixels, 40, 16), [0, 255, 0, 255]);
+    assert_eq!(renderer_2d.stats().draw_calls, 1);
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+#![cfg(feature = "json")]
+
+use poseidon::graphics::device::{self, recording::RecordingDevice};
+use poseidon::graphics::texture::{Texture, TextureDescriptor};
+use poseidon::graphics::texture_atlas::TextureAtlas;
+use poseidon::math::vec2::Vec2f;
+use poseidon::Error;
+
+#[test]
+fn sprite_sheets_load_from_json() {
+    device::set_device(Box::new(RecordingDevice::new()));
+    let sheet = || Texture::empty(64, 32, TextureDescriptor::default());
+
+    // Aseprite's default hash layout, kept in file order
+    let hash = r#"{
+        "frames": {
+            "walk 10": { "frame": { "x": 48, "y": 0, "w": 16, "h": 16 }, "rotated": false, "trimmed": false },
+            "walk 2": { "frame": { "x": 0, "y": 16, "w": 16, "h": 16 }, "duration": 100 }
+        },
+        "meta": { "image": "walk.png", "size": { "w": 64, "h": 32 } }
+    }"#;
+    let atlas = TextureAtlas::from_json(sheet(), hash).unwrap();
+    assert_eq!(atlas.index_of("walk 10"), Some(0));
+    assert_eq!(atlas.index_of("walk 2"), Some(1));
+    let sprite = atlas.sprite("walk 10").unwrap();
+    assert_eq!((sprite.x, sprite.y, sprite.width, sprite.height), (48, 16, 16, 16));
+    assert_eq!((sprite.uv_min, sprite.uv_max), (Vec2f::new(0.75, 0.5), Vec2f::one()));
+    assert_eq!(atlas.sprite("walk 2").unwrap().uv_min, Vec2f::zero());
+
+    // TexturePacker's array layout
+    let array = r#"{ "frames": [
+        { "filename": "coin", "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "rotated": false }
+    ] }"#;
+    let atlas = TextureAtlas::from_json(sheet(), array).unwrap();
+    assert_eq!(atlas.len(), 1);
+    assert_eq!(atlas.sprite("coin").unwrap().y, 24);
+
+    let rotated = r#"{ "frames": [{ "filename": "a", "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "rotated": true }] }"#;
+    assert!(matches!(TextureAtlas::from_json(sheet(), rotated), Err(Error::SpriteSheet(_))));
+    let outside = r#"{ "frames": [{ "filename": "a", "frame": { "x": 60, "y": 0, "w": 8, "h": 8 } }] }"#;
+    assert!(matches!(TextureAtlas::from_json(sheet(), outside), Err(Error::SpriteSheet(_))));
+    let overflowing = r#"{ "frames": [{ "filename": "a", "frame": { "x": 4294967295, "y": 0, "w": 8, "h": 8 } }] }"#;
+    assert!(matches!(TextureAtlas::from_json(sheet(), overflowing), Err(Error::SpriteSheet(_))));
+    let overflowing = r#"{ "frames": [{ "filename": "a", "frame": { "x": 0, "y": 8, "w": 8, "h": 4294967295 } }] }"#;
+    assert!(matches!(TextureAtlas::from_json(sheet(), overflowing), Err(Error::SpriteSheet(_))));
+    assert!(matches!(TextureAtlas::from_json(sheet(), "{}"), Err(Error::SpriteSheet(_))));
+}
\ No newline at end of file
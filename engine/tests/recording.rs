@@ -0,0 +1,649 @@
+use std::ffi::CString;
+
+use poseidon::graphics::color::Color;
+use poseidon::graphics::device::{self, UniformValue, TextureFormat, FramebufferHandle, ClearMask};
+use poseidon::graphics::pipeline::{PipelineState, BlendMode, DepthState, CullMode, ScissorRect};
+use poseidon::graphics::device::recording::{Command, RecordingDevice, CommandLog};
+use poseidon::graphics::renderer::Renderer;
+use poseidon::graphics::renderer_2d::{Renderer2D, Renderer2DStats, Rect, RectTransform, SortLayer};
+use poseidon::graphics::texture::{Texture, TextureDescriptor, TextureFilter};
+use poseidon::graphics::shader::Shader;
+use poseidon::graphics::shape_2d::Stroke;
+use poseidon::graphics::font::{Font, TextStyle, TextAlign};
+use poseidon::graphics::texture_atlas::{TextureAtlas, AtlasImage, AtlasDescriptor};
+use poseidon::Error;
+use poseidon::graphics::framebuffer::{Framebuffer, FramebufferDescriptor};
+use poseidon::graphics::vertex_array::VertexArray;
+use poseidon::math::{vec2::Vec2f, vec3::Vec3f, vec4::Vec4f, mat3f::Mat3f, mat4f::Mat4f};
+use poseidon::math::geometry::aabb::Aabb;
+
+/// Install a recording device on this test's thread
+fn record() -> CommandLog {
+    let device = RecordingDevice::new();
+    let log = device.log();
+    device::set_device(Box::new(device));
+    log
+}
+
+fn rect(x: f32, y: f32) -> Rect {
+    Rect::new(Vec3f::new(x, y, 0.0), Vec2f::splat(16.0), Vec2f::zero(), Vec2f::zero(), Vec2f::one())
+}
+
+#[test]
+fn renderer_commands_are_recorded() {
+    let log = record();
+
+    Renderer::set_viewport(0, 0, 640, 480);
+    Renderer::set_clear_color(Color::RED);
+    Renderer::clear();
+
+    assert_eq!(log.commands(), vec![
+        Command::SetViewport { x: 0, y: 0, width: 640, height: 480 },
+        Command::SetClearColor(Vec4f::new(1.0, 0.0, 0.0, 1.0)),
+        Command::Clear(ClearMask::ALL)
+    ]);
+}
+
+#[test]
+fn unchanged_int_arrays_are_not_uploaded_again() {
+    let log = record();
+    let shader = Shader::new("", "").unwrap();
+    let name = CString::new("u_textures").unwrap();
+    log.clear();
+
+    shader.set_int_array(&name, &vec![0, 1, 2]);
+    shader.set_int_array(&name, &vec![0, 1, 2]);
+    shader.set_int_array(&CString::new("u_other").unwrap(), &vec![0, 1, 2]);
+    shader.set_int_array(&name, &vec![2, 1, 0]);
+
+    let uploads: Vec<(String, UniformValue)> = log.commands().into_iter().filter_map(|command| match command {
+        Command::SetUniform { name, value, .. } => Some((name, value)),
+        _ => None
+    }).collect();
+    assert_eq!(uploads, vec![
+        ("u_textures".to_string(), UniformValue::IntArray(vec![0, 1, 2])),
+        ("u_other".to_string(), UniformValue::IntArray(vec![0, 1, 2])),
+        ("u_textures".to_string(), UniformValue::IntArray(vec![2, 1, 0]))
+    ]);
+}
+
+#[test]
+fn batch_is_one_draw() {
+    let log = record();
+    let projection = Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0);
+    let mut renderer_2d = Renderer2D::new(projection);
+
+    let uploaded_projection = log.commands().into_iter().any(|command| matches!(
+        command,
+        Command::SetUniform { name, value: UniformValue::Mat4f(matrix), .. }
+            if name == "u_view_projection" && matrix == projection));
+    assert!(uploaded_projection);
+
+    log.clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(0.0, 0.0), Color::WHITE);
+    renderer_2d.batch_rect(rect(32.0, 0.0), Color::BLUE);
+    renderer_2d.batch_rect(rect(-100.0, 0.0), Color::BLUE);
+    renderer_2d.end_batch();
+
+    assert_eq!(renderer_2d.culled_count(), 1);
+    assert_eq!(log.draws().len(), 1);
+    assert!(matches!(log.draws()[0], Command::DrawIndexed { count: 12, .. }));
+
+    // Four vertices per rect are uploaded
+    let uploaded = log.commands().into_iter().find_map(|command| match command {
+        Command::UpdateBuffer { data, .. } => Some(data.len()),
+        _ => None
+    });
+    assert_eq!(uploaded, Some(2 * 4 * 40));
+    assert_eq!(renderer_2d.stats(), Renderer2DStats { draw_calls: 1, quads: 2 });
+}
+
+#[test]
+fn dropped_resources_are_destroyed() {
+    let log = record();
+
+    let texture = Texture::with_data(&[255; 16], 2, 2);
+    let handle = texture.handle();
+    texture.bind_to_slot(3);
+    drop(texture);
+
+    assert_eq!(log.commands(), vec![
+        Command::CreateTexture { texture: handle, width: 2, height: 2, descriptor: TextureDescriptor::default() },
+        Command::BindTexture { slot: 3, texture: Some(handle) },
+        Command::DestroyTexture(handle)
+    ]);
+}
+
+
+#[test]
+fn multisampled_framebuffer_resolves_into_textures() {
+    let log = record();
+
+    let mut descriptor = FramebufferDescriptor::new(320, 240);
+    descriptor.color_formats.push(TextureFormat::Rgba16f);
+    descriptor.samples = 4;
+    let framebuffer = Framebuffer::new(descriptor).unwrap();
+
+    let commands = log.commands();
+    let renderbuffers = commands.iter()
+        .filter(|command| matches!(command, Command::CreateRenderbuffer { samples: 4, .. }))
+        .count();
+    let framebuffers: Vec<FramebufferHandle> = commands.iter()
+        .filter_map(|command| match command {
+            Command::CreateFramebuffer { framebuffer, .. } => Some(*framebuffer),
+            _ => None
+        })
+        .collect();
+    assert_eq!(renderbuffers, 3);
+    assert_eq!(framebuffers.len(), 2);
+    // The textures' framebuffer is created first and rendering goes to the multisampled one
+    assert_eq!(framebuffer.handle(), framebuffers[1]);
+    assert_eq!(framebuffer.color_count(), 2);
+    assert!(framebuffer.depth_texture().is_some());
+
+    log.clear();
+    framebuffer.bind();
+    framebuffer.resolve();
+    assert_eq!(log.commands(), vec![
+        Command::BindFramebuffer(Some(framebuffer.handle())),
+        Command::SetViewport { x: 0, y: 0, width: 320, height: 240 },
+        Command::BlitFramebuffer {
+            source: framebuffer.handle(),
+            destination: framebuffers[0],
+            width: 320,
+            height: 240,
+            color_count: 2,
+            depth: true
+        }
+    ]);
+}
+
+#[test]
+fn resizing_recreates_attachments() {
+    let log = record();
+
+    let mut framebuffer = Framebuffer::new(FramebufferDescriptor::new(64, 64)).unwrap();
+    let old_texture = framebuffer.color_texture(0).handle();
+    log.clear();
+
+    framebuffer.resize(64, 64).unwrap();
+    assert!(log.commands().is_empty());
+
+    // A failed resize keeps the old attachments
+    assert!(matches!(framebuffer.resize(0, 32), Err(Error::Framebuffer(_))));
+    assert_eq!(framebuffer.color_texture(0).handle(), old_texture);
+    assert!(log.commands().is_empty());
+
+    framebuffer.resize(128, 32).unwrap();
+    assert_eq!((framebuffer.width(), framebuffer.height()), (128, 32));
+    assert_ne!(framebuffer.color_texture(0).handle(), old_texture);
+    assert!(log.commands().contains(&Command::DestroyTexture(old_texture)));
+}
+
+#[test]
+fn invalid_framebuffers_are_errors() {
+    let _log = record();
+    let invalid = [
+        FramebufferDescriptor::new(0, 64),
+        FramebufferDescriptor { samples: 0, ..FramebufferDescriptor::new(64, 64) },
+        FramebufferDescriptor { color_formats: vec![TextureFormat::Depth24Stencil8], ..FramebufferDescriptor::new(64, 64) },
+        FramebufferDescriptor { depth_format: Some(TextureFormat::Rgba8), ..FramebufferDescriptor::new(64, 64) }
+    ];
+    for descriptor in invalid {
+        assert!(matches!(Framebuffer::new(descriptor), Err(Error::Framebuffer(_))));
+    }
+}
+
+
+#[test]
+fn only_changed_pipeline_state_is_applied() {
+    let log = record();
+
+    Renderer::init();
+    assert_eq!(log.commands().len(), 7);
+
+    log.clear();
+    Renderer::set_pipeline_state(&PipelineState::default());
+    assert!(log.commands().is_empty());
+
+    Renderer::set_pipeline_state(&PipelineState::opaque_3d());
+    assert_eq!(log.commands(), vec![
+        Command::SetBlendMode(BlendMode::Opaque),
+        Command::SetDepthState(DepthState::LESS),
+        Command::SetCullMode(CullMode::Back)
+    ]);
+
+    log.clear();
+    let scissor = ScissorRect::new(10, 20, 30, 40);
+    Renderer::set_pipeline_state(&PipelineState { scissor: Some(scissor), ..PipelineState::opaque_3d() });
+    assert_eq!(log.commands(), vec![Command::SetScissor(Some(scissor))]);
+    assert_eq!(Renderer::pipeline_state().unwrap().scissor, Some(scissor));
+
+    // A new device gets the full state
+    let log = record();
+    Renderer::set_pipeline_state(&PipelineState::opaque_3d());
+    assert_eq!(log.commands().len(), 7);
+}
+
+#[test]
+fn draws_outside_the_culling_view_are_skipped() {
+    let log = record();
+    let vertex_array = VertexArray::new();
+    let inside = Aabb::from_center_extents(Vec3f::zero(), Vec3f::splat(0.5));
+    let outside = Aabb::from_center_extents(Vec3f::new(10.0, 0.0, 0.0), Vec3f::splat(0.5));
+
+    // Nothing is culled until a view is set
+    log.clear();
+    assert!(Renderer::draw_elements_culled(&vertex_array, 36, &outside));
+    assert_eq!(log.draws().len(), 1);
+
+    log.clear();
+    Renderer::reset_culled_count();
+    Renderer::set_culling_view_projection(Mat4f::identity());
+    assert!(!Renderer::draw_elements_culled(&vertex_array, 36, &outside));
+    assert!(!Renderer::draw_elements_culled(&vertex_array, 36, &outside));
+    assert!(Renderer::draw_elements_culled(&vertex_array, 36, &inside));
+    assert_eq!(log.draws().len(), 1);
+    assert_eq!(Renderer::culled_count(), 2);
+
+    Renderer::reset_culled_count();
+    assert_eq!(Renderer::culled_count(), 0);
+
+    // Culling state is per thread
+    std::thread::spawn(|| assert_eq!(Renderer::culled_count(), 0)).join().unwrap();
+}
+
+#[test]
+fn updating_mipmapped_texture_regenerates_mipmaps() {
+    let log = record();
+    let texture = Texture::empty(4, 4, TextureDescriptor::mipmapped(TextureFormat::Rg8, 8.0));
+    let handle = texture.handle();
+
+    assert!(matches!(
+        log.commands()[0],
+        Command::CreateTexture { descriptor: TextureDescriptor { mipmaps: true, min_filter: TextureFilter::Linear, .. }, .. }));
+
+    log.clear();
+    texture.update_region(1, 2, 2, 1, &[1, 2, 3, 4]).unwrap();
+    assert_eq!(texture.read_pixels().len(), 4 * 4 * 2);
+
+    assert_eq!(log.commands(), vec![
+        Command::UpdateTexture { texture: handle, x: 1, y: 2, width: 2, height: 1, data: vec![1, 2, 3, 4] },
+        Command::GenerateMipmaps(handle),
+        Command::ReadTexture(handle)
+    ]);
+}
+
+#[test]
+fn invalid_texture_regions_are_errors() {
+    let log = record();
+    let texture = Texture::empty(4, 4, TextureDescriptor::new(TextureFormat::R8));
+    log.clear();
+
+    for (x, y, width, height) in [(3, 0, 2, 1), (0, 4, 1, 1), (u32::MAX, 0, 2, 1), (0, 1, 1, u32::MAX)] {
+        assert!(matches!(
+            texture.update_region(x, y, width, height, &[0; 2]),
+            Err(Error::TextureRegion(_))));
+    }
+    assert!(matches!(texture.update_region(0, 0, 2, 2, &[0; 3]), Err(Error::TextureRegion(_))));
+    assert_eq!(log.commands(), vec![]);
+
+    // Touching the far edges is still inside
+    texture.update_region(2, 3, 2, 1, &[0; 2]).unwrap();
+    assert_eq!(log.commands().len(), 1);
+}
+
+#[test]
+fn grid_sprites_are_numbered_from_the_top_left() {
+    record();
+    let atlas = TextureAtlas::from_grid(Texture::empty(64, 32, TextureDescriptor::default()), 16, 16);
+
+    assert_eq!(atlas.len(), 8);
+    let first = atlas.sprite("0").unwrap();
+    assert_eq!((first.x, first.y), (0, 16));
+    assert_eq!((first.uv_min, first.uv_max), (Vec2f::new(0.0, 0.5), Vec2f::new(0.25, 1.0)));
+    let last = atlas.sprites()[7];
+    assert_eq!((last.x, last.y), (48, 0));
+
+    let rect = last.rect(Vec3f::zero(), Vec2f::zero());
+    assert_eq!((rect.size, rect.uv_min, rect.uv_max), (Vec2f::splat(16.0), last.uv_min, last.uv_max));
+}
+
+#[test]
+fn packing_fails_when_images_dont_fit() {
+    record();
+    let images = vec![
+        AtlasImage::new("small", vec![0; 8 * 8 * 4], 8, 8),
+        AtlasImage::new("large", vec![0; 40 * 40 * 4], 40, 40)
+    ];
+    let descriptor = AtlasDescriptor { max_size: 32, ..AtlasDescriptor::new() };
+
+    assert!(matches!(
+        TextureAtlas::pack(&images, &descriptor),
+        Err(Error::AtlasFull { name, max_size: 32 }) if name == "large"));
+    assert_eq!(TextureAtlas::pack(&images[..1], &descriptor).unwrap().index_of("small"), Some(0));
+}
+
+#[test]
+fn packing_fails_when_image_data_doesnt_match() {
+    record();
+    let images = vec![
+        AtlasImage::new("good", vec![0; 4 * 4 * 4], 4, 4),
+        AtlasImage::new("short", vec![0; 4 * 4 * 3], 4, 4)
+    ];
+
+    assert_eq!(
+        TextureAtlas::pack(&images, &AtlasDescriptor::new()).err(),
+        Some(Error::ImageData { name: "short".to_string(), expected: 64, actual: 48 }));
+}
+
+#[test]
+fn full_batches_are_flushed() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+
+    log.clear();
+    renderer_2d.begin_batch();
+    for i in 0..600 {
+        renderer_2d.batch_rect(rect((i % 40) as f32, 0.0), Color::WHITE);
+    }
+    renderer_2d.end_batch();
+
+    let counts: Vec<u32> = log.draws().into_iter().map(|command| match command {
+        Command::DrawIndexed { count, .. } => count / 6,
+        _ => unreachable!()
+    }).collect();
+    assert_eq!(counts, vec![512, 88]);
+    assert_eq!(renderer_2d.stats(), Renderer2DStats { draw_calls: 2, quads: 600 });
+
+    renderer_2d.reset_stats();
+    renderer_2d.begin_batch();
+    renderer_2d.end_batch();
+    assert_eq!(renderer_2d.stats(), Renderer2DStats::default());
+}
+
+#[test]
+fn texture_slots_are_reused_and_flushed_when_full() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+    let textures: Vec<Texture> = (0..40).map(|_| Texture::empty(1, 1, TextureDescriptor::default())).collect();
+
+    // Repeated textures keep their slot
+    log.clear();
+    renderer_2d.begin_batch();
+    for i in 0..10 {
+        renderer_2d.batch_textured_rect(rect(0.0, 0.0), &textures[i % 2], Color::WHITE);
+    }
+    renderer_2d.end_batch();
+    let binds: Vec<Command> = log.commands().into_iter()
+        .filter(|command| matches!(command, Command::BindTexture { .. }))
+        .collect();
+    assert!(matches!(binds[0], Command::BindTexture { slot: 0, .. }));
+    assert_eq!(binds[1..], [
+        Command::BindTexture { slot: 1, texture: Some(textures[0].handle()) },
+        Command::BindTexture { slot: 2, texture: Some(textures[1].handle()) }
+    ]);
+    assert_eq!(log.draws().len(), 1);
+
+    // Slot 0 holds the default texture, leaving 31 for the rest
+    log.clear();
+    renderer_2d.reset_stats();
+    renderer_2d.begin_batch();
+    for texture in &textures {
+        renderer_2d.batch_textured_rect(rect(0.0, 0.0), texture, Color::WHITE);
+    }
+    renderer_2d.end_batch();
+    let slots: Vec<u32> = log.commands().into_iter().filter_map(|command| match command {
+        Command::BindTexture { slot, .. } => Some(slot),
+        _ => None
+    }).collect();
+    assert_eq!(slots.iter().max(), Some(&31));
+    assert_eq!(slots.len(), 1 + 40);
+    assert_eq!(renderer_2d.stats(), Renderer2DStats { draw_calls: 2, quads: 40 });
+}
+
+#[test]
+fn rect_transforms_apply_around_the_pivot() {
+    let rect = Rect::new(Vec3f::new(10.0, 10.0, 0.0), Vec2f::new(4.0, 2.0), Vec2f::zero(), Vec2f::zero(), Vec2f::one());
+    assert_eq!(RectTransform::IDENTITY.corners(&rect), rect.corners());
+
+    let scaled = RectTransform::new(0.0, Vec2f::new(2.0, -1.0));
+    assert_eq!(scaled.corners(&rect), [
+        Vec2f::new(10.0, 10.0), Vec2f::new(18.0, 10.0), Vec2f::new(18.0, 8.0), Vec2f::new(10.0, 8.0)
+    ]);
+
+    let skewed = RectTransform::from_matrix(Mat3f::shear_2d(Vec2f::new(1.0, 0.0)));
+    assert_eq!(skewed.corners(&rect)[3], Vec2f::new(12.0, 12.0));
+
+    let flipped = RectTransform { flip_x: true, flip_y: true, ..RectTransform::IDENTITY };
+    assert_eq!(flipped.uvs(&rect), [Vec2f::one(), Vec2f::new(0.0, 1.0), Vec2f::zero(), Vec2f::new(1.0, 0.0)]);
+}
+
+#[test]
+fn rotated_rects_are_culled_by_their_corners() {
+    record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+
+    // Entirely left of the view until rotated into it around its right edge
+    let rect = Rect::new(Vec3f::new(-1.0, 100.0, 0.0), Vec2f::new(50.0, 4.0), Vec2f::new(1.0, 0.5), Vec2f::zero(), Vec2f::one());
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect, Color::WHITE);
+    renderer_2d.batch_transformed_rect(rect, &RectTransform::rotated(std::f32::consts::PI), Color::WHITE);
+    renderer_2d.end_batch();
+
+    assert_eq!(renderer_2d.culled_count(), 1);
+    assert_eq!(renderer_2d.stats().quads, 1);
+}
+
+#[test]
+fn shapes_share_the_batch_with_sprites() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+    let texture = Texture::empty(1, 1, TextureDescriptor::default());
+
+    log.clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_textured_rect(rect(0.0, 0.0), &texture, Color::WHITE);
+    renderer_2d.batch_line(Vec2f::new(0.0, 0.0), Vec2f::new(100.0, 0.0), 0.0, &Stroke::new(2.0), Color::RED);
+    renderer_2d.batch_triangle([Vec2f::new(0.0, 0.0), Vec2f::new(10.0, 0.0), Vec2f::new(0.0, 10.0)], 0.0, Color::GREEN);
+    renderer_2d.batch_polygon(&[Vec2f::new(0.0, 0.0), Vec2f::new(10.0, 0.0), Vec2f::new(10.0, 10.0), Vec2f::new(0.0, 10.0)], 0.0, Color::BLUE);
+    renderer_2d.batch_circle(Vec2f::new(50.0, 50.0), 20.0, 0.0, Color::WHITE);
+    // Entirely off screen
+    renderer_2d.batch_circle(Vec2f::new(-500.0, -500.0), 20.0, 0.0, Color::WHITE);
+    renderer_2d.end_batch();
+
+    assert_eq!(log.draws().len(), 1);
+    let stats = renderer_2d.stats();
+    assert_eq!(stats.draw_calls, 1);
+    // Sprite, line and triangle are a quad each, the square is one quad and the circle a fan of several
+    assert!(stats.quads > 4);
+    assert_eq!(renderer_2d.culled_count(), 1);
+}
+
+const BMFONT: &str = r#"info face="Test" size=8 bold=0 italic=0
+common lineHeight=10 base=8 scaleW=32 scaleH=32 pages=1
+page id=0 file="test.png"
+chars count=4
+char id=65 x=0 y=0 width=6 height=8 xoffset=0 yoffset=0 xadvance=7 page=0 chnl=15
+char id=66 x=8 y=0 width=6 height=8 xoffset=1 yoffset=0 xadvance=7 page=0 chnl=15
+char id=32 x=0 y=0 width=0 height=0 xoffset=0 yoffset=8 xadvance=4 page=0 chnl=15
+char id=63 x=16 y=0 width=5 height=6 xoffset=0 yoffset=2 xadvance=6 page=0 chnl=15
+kernings count=1
+kerning first=65 second=66 amount=-2"#;
+
+fn bmfont() -> Font {
+    Font::from_bmfont(BMFONT, vec![Texture::empty(32, 32, TextureDescriptor::default())]).unwrap()
+}
+
+#[test]
+fn bmfont_text_is_kerned_and_measured() {
+    let _log = record();
+    let font = bmfont();
+    assert_eq!((font.size(), font.line_height(), font.ascent()), (8.0, 10.0, 8.0));
+    // Rows are from the top of the page
+    assert_eq!(font.glyph('A').unwrap().sprite.y, 24);
+
+    let style = TextStyle::new();
+    assert_eq!(font.measure("AB", &style), Vec2f::new(12.0, 10.0));
+    assert_eq!(font.measure("A\nB", &style), Vec2f::new(7.0, 20.0));
+    assert_eq!(font.measure("AB", &TextStyle { scale: 2.0, ..style }), Vec2f::new(24.0, 20.0));
+
+    let glyphs = font.layout("AB", Vec2f::new(0.0, 100.0), &style);
+    assert_eq!(glyphs.len(), 2);
+    assert_eq!((glyphs[0].bounds.min, glyphs[0].bounds.max), (Vec2f::new(0.0, 92.0), Vec2f::new(6.0, 100.0)));
+    assert_eq!(glyphs[1].bounds.min, Vec2f::new(6.0, 92.0));
+
+    // Unknown characters use the fallback
+    let glyphs = font.layout("A\u{e9}", Vec2f::zero(), &style);
+    assert_eq!(glyphs[1].character, '\u{e9}');
+    assert_eq!(glyphs[1].uv_min, font.glyph('?').unwrap().sprite.uv_min);
+}
+
+#[test]
+fn text_is_wrapped_and_aligned() {
+    let _log = record();
+    let font = bmfont();
+
+    // "AB " is 16 wide, so the second word moves down
+    let wrapped = TextStyle { max_width: Some(15.0), ..TextStyle::new() };
+    assert_eq!(font.measure("AB AB", &wrapped), Vec2f::new(12.0, 20.0));
+    let glyphs = font.layout("AB AB", Vec2f::zero(), &wrapped);
+    assert_eq!(glyphs[2].bounds.min, Vec2f::new(0.0, -18.0));
+    // Words wider than the line are broken between characters
+    assert_eq!(font.measure("ABAB", &TextStyle { max_width: Some(10.0), ..TextStyle::new() }).y, 40.0);
+
+    // Lines align within the widest
+    let right = TextStyle { align: TextAlign::Right, ..TextStyle::new() };
+    let glyphs = font.layout("A\nAB", Vec2f::zero(), &right);
+    assert_eq!(glyphs[0].bounds.min.x, 5.0);
+    assert_eq!(glyphs[1].bounds.min.x, 0.0);
+    let centered = TextStyle { align: TextAlign::Center, max_width: Some(27.0), ..TextStyle::new() };
+    assert_eq!(font.layout("A", Vec2f::zero(), &centered)[0].bounds.min.x, 10.0);
+}
+
+#[test]
+fn text_is_batched_with_rects() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+    let font = bmfont();
+
+    log.clear();
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(0.0, 0.0), Color::WHITE);
+    renderer_2d.batch_text(&font, "A B", Vec3f::new(10.0, 100.0, 0.0), &TextStyle::new(), Color::BLACK);
+    renderer_2d.end_batch();
+    // Spaces have no quad
+    assert_eq!(renderer_2d.stats(), Renderer2DStats { draw_calls: 1, quads: 3 });
+
+    renderer_2d.draw_text(&font, "AB", Vec3f::new(10.0, 100.0, 0.0), &TextStyle::new(), Color::BLACK);
+    assert_eq!(renderer_2d.stats(), Renderer2DStats { draw_calls: 2, quads: 5 });
+}
+
+#[test]
+fn malformed_bmfonts_are_errors() {
+    let _log = record();
+    let pages = || vec![Texture::empty(32, 32, TextureDescriptor::default())];
+    assert!(matches!(Font::from_bmfont("info size=8", pages()), Err(Error::Font(_))));
+    assert!(matches!(Font::from_bmfont(&BMFONT.replace("pages=1", "pages=2"), pages()), Err(Error::Font(_))));
+    assert!(matches!(Font::from_bmfont(&BMFONT.replace("x=16", "x=30"), pages()), Err(Error::Font(_))));
+    assert!(matches!(Font::from_bmfont(&BMFONT.replace("x=16", "x=4294967295"), pages()), Err(Error::Font(_))));
+    assert!(matches!(Font::from_bmfont(&BMFONT.replace("x=8 y=0", "x=8 y=4294967295"), pages()), Err(Error::Font(_))));
+    assert!(matches!(Font::from_bmfont(&BMFONT.replace("xadvance=7", "xadvance=seven"), pages()), Err(Error::Font(_))));
+}
+
+/// Get the color of each quad in the last batch uploaded
+fn uploaded_quad_colors(log: &CommandLog) -> Vec<Vec4f> {
+    // Each vertex is a position, uv, color and texture slot
+    const VERTEX_SIZE: usize = 4 * (3 + 2 + 4 + 1);
+    let data = log.commands().into_iter().rev().find_map(|command| match command {
+        Command::UpdateBuffer { data, .. } => Some(data),
+        _ => None
+    }).unwrap();
+    data.chunks(VERTEX_SIZE * 4).map(|quad| {
+        let channel = |i: usize| f32::from_ne_bytes(quad[20 + i * 4..24 + i * 4].try_into().unwrap());
+        Vec4f::new(channel(0), channel(1), channel(2), channel(3))
+    }).collect()
+}
+
+#[test]
+fn batches_are_sorted_by_layer_and_order() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+    let textures = [Texture::empty(1, 1, TextureDescriptor::default()), Texture::empty(1, 1, TextureDescriptor::default())];
+
+    renderer_2d.begin_batch();
+    renderer_2d.set_sort_layer(SortLayer::new(1, 0));
+    renderer_2d.batch_rect(rect(0.0, 0.0), Color::RED);
+    renderer_2d.set_sort_layer(SortLayer::new(0, 0));
+    renderer_2d.batch_circle(Vec2f::new(10.0, 10.0), 1.0, 0.0, Color::GREEN);
+    renderer_2d.set_sort_layer(SortLayer::new(1, -1));
+    renderer_2d.batch_rect(rect(0.0, 0.0), Color::BLUE);
+    renderer_2d.end_batch();
+    let colors = uploaded_quad_colors(&log);
+    let circle_quads = colors.len() - 2;
+    assert!(colors[..circle_quads].iter().all(|&color| color == Color::GREEN.into()));
+    assert_eq!(colors[circle_quads..], [Color::BLUE.into(), Color::RED.into()]);
+    assert_eq!(renderer_2d.sort_layer(), SortLayer::new(1, -1));
+
+    // Alternating textures are grouped, keeping their submission order otherwise
+    log.clear();
+    renderer_2d.begin_batch();
+    assert_eq!(renderer_2d.sort_layer(), SortLayer::default());
+    let tints = [Color::RED, Color::GREEN, Color::BLUE, Color::WHITE];
+    for (i, tint) in tints.into_iter().enumerate() {
+        renderer_2d.batch_textured_rect(rect(0.0, 0.0), &textures[i % 2], tint);
+    }
+    renderer_2d.end_batch();
+    assert_eq!(uploaded_quad_colors(&log), [Color::RED, Color::BLUE, Color::GREEN, Color::WHITE].map(Vec4f::from));
+    assert_eq!(log.draws().len(), 1);
+}
+
+#[test]
+fn y_sorting_draws_higher_rects_first() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+
+    renderer_2d.set_y_sort(true);
+    renderer_2d.begin_batch();
+    renderer_2d.batch_rect(rect(0.0, 10.0), Color::RED);
+    renderer_2d.batch_rect(rect(0.0, 50.0), Color::GREEN);
+    renderer_2d.batch_rect(rect(0.0, 30.0), Color::BLUE);
+    // Layers still come first
+    renderer_2d.set_sort_layer(SortLayer::new(-1, 0));
+    renderer_2d.batch_rect(rect(0.0, 0.0), Color::WHITE);
+    renderer_2d.end_batch();
+    assert_eq!(uploaded_quad_colors(&log), [Color::WHITE, Color::GREEN, Color::BLUE, Color::RED].map(Vec4f::from));
+    // Then textures, y only orders draws sharing a texture
+    log.clear();
+    let texture = Texture::empty(1, 1, TextureDescriptor::default());
+    renderer_2d.begin_batch();
+    renderer_2d.batch_textured_rect(rect(0.0, 50.0), &texture, Color::RED);
+    renderer_2d.batch_rect(rect(0.0, 10.0), Color::GREEN);
+    renderer_2d.batch_textured_rect(rect(0.0, 90.0), &texture, Color::BLUE);
+    renderer_2d.batch_rect(rect(0.0, 30.0), Color::WHITE);
+    renderer_2d.end_batch();
+    assert_eq!(uploaded_quad_colors(&log), [Color::WHITE, Color::GREEN, Color::BLUE, Color::RED].map(Vec4f::from));
+}
+
+#[test]
+fn batched_textures_live_until_the_flush() {
+    let log = record();
+    let mut renderer_2d = Renderer2D::new(Mat4f::ortho_off_center(0.0, 640.0, 480.0, 0.0, -1.0, 1.0));
+
+    renderer_2d.begin_batch();
+    let texture = Texture::empty(1, 1, TextureDescriptor::default());
+    let handle = texture.handle();
+    renderer_2d.batch_textured_rect(rect(0.0, 0.0), &texture, Color::WHITE);
+    drop(texture);
+    assert!(!log.commands().contains(&Command::DestroyTexture(handle)));
+
+    renderer_2d.end_batch();
+    let commands = log.commands();
+    let bound = commands.iter()
+        .position(|command| *command == Command::BindTexture { slot: 1, texture: Some(handle) })
+        .unwrap();
+    let drawn = commands.iter().rposition(|command| matches!(command, Command::DrawIndexed { .. })).unwrap();
+    let destroyed = commands.iter().position(|command| *command == Command::DestroyTexture(handle)).unwrap();
+    assert!(bound < drawn && drawn < destroyed);
+}
\ No newline at end of file
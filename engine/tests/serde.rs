@@ -0,0 +1,86 @@
+#![cfg(feature = "serde")]
+
+use std::fmt::Debug;
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use poseidon::graphics::color::Color;
+use poseidon::graphics::renderer_2d::Rect;
+use poseidon::math::{vec2::{Vec2f, Vec2i}, vec3::Vec3f, vec4::{Vec4f, Vec4u}};
+use poseidon::math::{mat2f::Mat2f, mat3f::Mat3f, mat4f::Mat4f, quatf::Quatf};
+use poseidon::math::geometry::{aabb::Aabb, sphere::Sphere, ray::Ray};
+use poseidon::scene::transform::Transform;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "JSON: {}", json);
+
+    let ron = ron::to_string(&value).unwrap();
+    assert_eq!(ron::from_str::<T>(&ron).unwrap(), value, "RON: {}", ron);
+
+    // Not self-describing, so deserializers can't inspect the input to pick a form
+    let bytes = bincode::serialize(&value).unwrap();
+    assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value, "bincode: {:?}", bytes);
+}
+
+#[test]
+fn vectors_are_compact() {
+    assert_eq!(serde_json::to_string(&Vec2f::new(1.0, 2.5)).unwrap(), "[1.0,2.5]");
+    assert_eq!(serde_json::to_string(&Vec3f::new(1.0, 2.0, 3.0)).unwrap(), "[1.0,2.0,3.0]");
+    assert_eq!(ron::to_string(&Vec4f::new(1.0, 2.0, 3.0, 4.0)).unwrap(), "(1.0,2.0,3.0,4.0)");
+    assert_eq!(serde_json::from_str::<Vec2i>("[-1, 7]").unwrap(), Vec2i::new(-1, 7));
+    assert!(serde_json::from_str::<Vec3f>("[1.0, 2.0]").is_err());
+
+    round_trip(Vec2f::new(0.1, -2.0));
+    round_trip(Vec3f::new(0.1, 0.2, 0.3));
+    round_trip(Vec4f::new(-1.5, 0.0, 1e-7, 1e7));
+    round_trip(Vec4u::new(1, 2, 3, u32::MAX));
+}
+
+#[test]
+fn matrices_are_rows() {
+    let translation = Mat4f::translate(Vec3f::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        serde_json::to_string(&translation).unwrap(),
+        "[[1.0,0.0,0.0,1.0],[0.0,1.0,0.0,2.0],[0.0,0.0,1.0,3.0],[0.0,0.0,0.0,1.0]]");
+
+    round_trip(Mat4f::transformation(
+        Vec3f::new(1.0, -2.0, 3.0),
+        Quatf::from_axis_angle(Vec3f::up(), 0.5),
+        Vec3f::new(1.0, 2.0, 0.5)));
+    round_trip(Mat3f::rotate_2d(0.3));
+    round_trip(Mat2f::rotate(1.2));
+    round_trip(Quatf::from_yaw_pitch_roll(0.1, 0.2, 0.3));
+}
+
+#[test]
+fn colors_accept_hex() {
+    assert_eq!(serde_json::to_string(&Color::RED).unwrap(), "[1.0,0.0,0.0,1.0]");
+    assert_eq!(serde_json::from_str::<Color>("\"#00FFFF\"").unwrap(), Color::CYAN);
+    assert_eq!(ron::from_str::<Color>("\"#FFFFFFFF\"").unwrap(), Color::WHITE);
+    assert!(serde_json::from_str::<Color>("\"#nope\"").is_err());
+    assert!(serde_json::from_str::<Color>("[1.0, 0.0, 0.0]").is_err());
+
+    round_trip(Color::new(0.1, 0.2, 0.3, 0.4));
+}
+
+#[test]
+fn composite_types() {
+    round_trip(Rect::new(
+        Vec3f::new(10.0, 20.0, 0.5),
+        Vec2f::new(32.0, 16.0),
+        Vec2f::splat(0.5),
+        Vec2f::zero(),
+        Vec2f::one()));
+    round_trip(Aabb::new(Vec3f::splat(-1.0), Vec3f::splat(2.0)));
+    round_trip(Sphere::new(Vec3f::new(1.0, 2.0, 3.0), 4.0));
+    round_trip(Ray::new(Vec3f::zero(), Vec3f::forward()));
+    round_trip(Transform::new(
+        Vec3f::new(1.0, 2.0, 3.0),
+        Quatf::from_axis_angle(Vec3f::right(), 0.25),
+        Vec3f::splat(2.0)));
+
+    // Omitted transform fields use their defaults
+    let transform: Transform = ron::from_str("(position: (1.0, 2.0, 3.0))").unwrap();
+    assert_eq!(transform, Transform::from_position(Vec3f::new(1.0, 2.0, 3.0)));
+}
\ No newline at end of file